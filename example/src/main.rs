@@ -7,6 +7,7 @@ use k210_lcd::{
     camera::Camera,
     hal::utils::usleep, 
     constant::*,
+    font::FONT_8X16,
     hal::gpiohs::Gpiohs,
     k210_hal::{
         self, 
@@ -20,6 +21,10 @@ use k210_lcd::{
 
 use panic_halt as _;
 
+extern crate alloc;
+use alloc::vec;
+use alloc::vec::Vec;
+
 use buddy_system_allocator::LockedHeap;
 
 #[global_allocator]
@@ -76,17 +81,37 @@ fn main() -> ! {
     // let mut blue = Gpiohs::new(1);
     // blue.set_output();
 
-    let mut lcd = Lcd::new_lcd(320, 240, Dir::YxRlud);
-    lcd.lcd_draw_string(120, 116, "Hello CWYZ", BLUE);
+    let mut lcd = match Lcd::new_lcd(320, 240, Dir::YxRlud) {
+        Ok(lcd) => lcd,
+        Err(e) => {
+            writeln!(stdout, "lcd: {:?}", e).ok();
+            loop {}
+        }
+    };
+    lcd.lcd_draw_string(120, 116, "Hello CWYZ", &FONT_8X16, BLUE, WHITE).ok();
+    // on modules that break out the TE pin, start each frame on V-blank
+    // let _te = fpioa.io35.into_function(k210_hal::fpioa::GPIOHS23);
+    // lcd.enable_tearing_effect(23).ok();
 
     let mut dvp = Camera::new_camera();
 
     writeln!(stdout, "display out addr at {}", dvp.buf.as_ptr() as u32);
 
-    let mut i = 0;
+    // the camera fills one buffer while the other one goes out over DMA
+    let mut spare: Vec<u32> = vec![0; CAM_PIXELS / 2];
 
+    dvp.dvp.get_image();
     loop {
+        core::mem::swap(&mut dvp.buf, &mut spare);
+        dvp.dvp.set_display_addr(dvp.buf.as_ptr() as u32);
+        let transfer = lcd.lcd_draw_picture_async(0, 0, 320, 240, spare);
         dvp.dvp.get_image();
-        lcd.lcd_draw_picture(0, 0, 320, 240, &dvp.buf);
+        spare = match transfer {
+            Ok(transfer) => transfer.wait(),
+            Err((e, buf)) => {
+                writeln!(stdout, "lcd: {:?}", e).ok();
+                buf
+            }
+        };
     }
 }
\ No newline at end of file
@@ -0,0 +1,97 @@
+use crate::hal::pwm::{Pwm, Timer};
+use crate::hal::utils::usleep;
+
+const PWM_FREQ: u32 = 20_000;
+
+// perceived brightness 0..=100 to duty 0..=65535, gamma 2.2
+const BRIGHTNESS: [u16; 101] = [
+    0, 3, 12, 29, 55, 90, 134, 189, 253, 328,
+    413, 510, 618, 736, 867, 1009, 1163, 1329, 1507, 1697,
+    1900, 2115, 2343, 2584, 2838, 3104, 3384, 3677, 3983, 4303,
+    4636, 4983, 5343, 5717, 6106, 6508, 6924, 7354, 7798, 8257,
+    8730, 9217, 9719, 10235, 10766, 11312, 11872, 12448, 13038, 13643,
+    14263, 14898, 15548, 16214, 16894, 17590, 18302, 19028, 19770, 20528,
+    21301, 22090, 22895, 23715, 24551, 25403, 26271, 27154, 28054, 28970,
+    29901, 30849, 31813, 32793, 33790, 34802, 35831, 36877, 37939, 39017,
+    40112, 41223, 42351, 43496, 44657, 45835, 47029, 48241, 49469, 50714,
+    51976, 53255, 54551, 55864, 57195, 58542, 59906, 61287, 62686, 64102,
+    65535,
+];
+
+/// PWM dimmed panel backlight.
+///
+/// The timer output has to be routed to the backlight pin first, e.g.
+/// `fpioa.io17.into_function(fpioa::TIMER0_TOGGLE1)` for `Timer0` channel 0
+/// on pad 17. Brightness 0 and 100 hold the pin at a constant level.
+pub struct Backlight {
+    pwm: Pwm,
+    /// Pin drives the LED when low.
+    active_low: bool,
+    brightness: u8,
+    /// Level `fade_in` goes back to.
+    saved: u8,
+}
+
+impl Backlight {
+    pub fn new(timer: Timer, channel: usize, io: usize, active_low: bool) -> Self {
+        let pwm = Pwm::new_pwm(timer, channel, io, PWM_FREQ);
+        let mut backlight = Backlight { pwm, active_low, brightness: 0, saved: 100 };
+        backlight.apply(0);
+        backlight
+    }
+
+    pub fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    /// Perceptual brightness in percent, 0 is off.
+    pub fn set_brightness(&mut self, level: u8) {
+        self.apply(level);
+        if self.brightness > 0 {
+            self.saved = self.brightness;
+        }
+    }
+
+    /// Step to `level` one percent at a time over `duration_ms`.
+    pub fn fade_to(&mut self, level: u8, duration_ms: u32) {
+        let level = level.min(100);
+        let steps = (level as i32 - self.brightness as i32).unsigned_abs();
+        if steps == 0 {
+            return;
+        }
+        let step_us = duration_ms as usize * 1000 / steps as usize;
+        while self.brightness != level {
+            let next = if self.brightness < level { self.brightness + 1 } else { self.brightness - 1 };
+            self.apply(next);
+            usleep(step_us);
+        }
+        if level > 0 {
+            self.saved = level;
+        }
+    }
+
+    /// Fade back to the last non zero brightness.
+    pub fn fade_in(&mut self, duration_ms: u32) {
+        let level = self.saved;
+        self.fade_to(level, duration_ms);
+    }
+
+    pub fn fade_out(&mut self, duration_ms: u32) {
+        self.fade_to(0, duration_ms);
+    }
+
+    fn apply(&mut self, level: u8) {
+        let level = level.min(100);
+        let mut duty = BRIGHTNESS[level as usize];
+        // the lowest steps are finer than one timer tick, keep them lit
+        let tick = (0xFFFF / self.pwm.periods()) as u16 + 1;
+        if level > 0 && duty < tick {
+            duty = tick;
+        }
+        if self.active_low {
+            duty = 0xFFFF - duty;
+        }
+        self.pwm.set_duty(duty);
+        self.brightness = level;
+    }
+}
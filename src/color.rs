@@ -0,0 +1,210 @@
+/// 16 bit colour as the panel stores it: 5 bits red in the top bits, 6 bits
+/// green, 5 bits blue. The named colours live in `constant`.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Rgb565(pub u16);
+
+impl Rgb565 {
+    /// From channels already at 5, 6 and 5 bits; higher bits are dropped.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Rgb565(((r as u16 & 0x1F) << 11) | ((g as u16 & 0x3F) << 5) | (b as u16 & 0x1F))
+    }
+
+    /// From 8 bit channels, rounded to the nearest step.
+    pub const fn from_rgb888(r: u8, g: u8, b: u8) -> Self {
+        Rgb565::new(
+            ((r as u16 * 31 + 127) / 255) as u8,
+            ((g as u16 * 63 + 127) / 255) as u8,
+            ((b as u16 * 31 + 127) / 255) as u8,
+        )
+    }
+
+    /// Hue in degrees, saturation and value in 0..=255.
+    pub fn from_hsv(hue: u16, saturation: u8, value: u8) -> Self {
+        let hue = (hue % 360) as i32;
+        let value = value as i32;
+        let chroma = value * saturation as i32 / 255;
+        // second largest channel, rising and falling across each 60 degrees
+        let x = chroma * (60 - ((hue % 120) - 60).abs()) / 60;
+        let (r, g, b) = match hue / 60 {
+            0 => (chroma, x, 0),
+            1 => (x, chroma, 0),
+            2 => (0, chroma, x),
+            3 => (0, x, chroma),
+            4 => (x, 0, chroma),
+            _ => (chroma, 0, x),
+        };
+        let m = value - chroma;
+        Rgb565::from_rgb888((r + m) as u8, (g + m) as u8, (b + m) as u8)
+    }
+
+    /// Red, 0..=31.
+    pub const fn r(self) -> u8 {
+        (self.0 >> 11) as u8
+    }
+
+    /// Green, 0..=63.
+    pub const fn g(self) -> u8 {
+        (self.0 >> 5) as u8 & 0x3F
+    }
+
+    /// Blue, 0..=31.
+    pub const fn b(self) -> u8 {
+        self.0 as u8 & 0x1F
+    }
+
+    /// 8 bit channels, with the top bits repeated into the low ones so
+    /// white stays 255.
+    pub const fn to_rgb888(self) -> (u8, u8, u8) {
+        let (r, g, b) = (self.r(), self.g(), self.b());
+        (r << 3 | r >> 2, g << 2 | g >> 4, b << 3 | b >> 2)
+    }
+
+    /// Per channel mix: `t` = 0 gives `self`, 255 gives `other`.
+    pub fn lerp(self, other: Rgb565, t: u8) -> Self {
+        let t = t as i32;
+        let mix = |a: u8, b: u8| (a as i32 + (b as i32 - a as i32) * t / 255) as u8;
+        Rgb565::new(mix(self.r(), other.r()), mix(self.g(), other.g()), mix(self.b(), other.b()))
+    }
+
+    /// `self` drawn over `background` with opacity `alpha`.
+    pub fn blend(self, background: Rgb565, alpha: u8) -> Self {
+        background.lerp(self, alpha)
+    }
+
+    /// Bytes in the order they go out on the wire.
+    pub const fn to_be_bytes(self) -> [u8; 2] {
+        self.0.to_be_bytes()
+    }
+
+    pub const fn from_be_bytes(bytes: [u8; 2]) -> Self {
+        Rgb565(u16::from_be_bytes(bytes))
+    }
+
+    /// For buffers filled from little endian memory, e.g. camera frames,
+    /// that have to reach the panel high byte first.
+    pub const fn swap_bytes(self) -> Self {
+        Rgb565(self.0.swap_bytes())
+    }
+
+    /// Two pixels in one DMA word, left pixel in the high half.
+    pub const fn pack(left: Rgb565, right: Rgb565) -> u32 {
+        (left.0 as u32) << 16 | right.0 as u32
+    }
+
+    /// One 18 bit RGB666 pixel in the low 24 bits, a byte per channel with
+    /// the value in its top six bits.
+    pub const fn pack666(self) -> u32 {
+        let (r, g, b) = self.to_rgb888();
+        (r as u32 & 0xFC) << 16 | (g as u32 & 0xFC) << 8 | (b as u32 & 0xFC)
+    }
+
+    /// Two 12 bit RGB444 pixels in the low 24 bits, left pixel first.
+    pub const fn pack444(left: Rgb565, right: Rgb565) -> u32 {
+        left.to_rgb444() << 12 | right.to_rgb444()
+    }
+
+    const fn to_rgb444(self) -> u32 {
+        ((self.r() >> 1) as u32) << 8 | ((self.g() >> 2) as u32) << 4 | (self.b() >> 1) as u32
+    }
+}
+
+impl From<u16> for Rgb565 {
+    fn from(raw: u16) -> Self {
+        Rgb565(raw)
+    }
+}
+
+impl From<Rgb565> for u16 {
+    fn from(color: Rgb565) -> Self {
+        color.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rgb565;
+    use crate::constant::*;
+
+    #[test]
+    fn hsv_primaries_and_wrap() {
+        assert_eq!(Rgb565::from_hsv(0, 255, 255), RED);
+        assert_eq!(Rgb565::from_hsv(60, 255, 255), YELLOW);
+        assert_eq!(Rgb565::from_hsv(120, 255, 255), GREEN);
+        assert_eq!(Rgb565::from_hsv(240, 255, 255), BLUE);
+        assert_eq!(Rgb565::from_hsv(360, 255, 255), RED);
+        assert_eq!(Rgb565::from_hsv(720 + 120, 255, 255), GREEN);
+        // one degree short of red again, the blue left over rounds away
+        assert_eq!(Rgb565::from_hsv(359, 255, 255), RED);
+        // half way to yellow, 127 green rounds to 31 of 63
+        assert_eq!(Rgb565::from_hsv(30, 255, 255), Rgb565::new(31, 31, 0));
+    }
+
+    #[test]
+    fn hsv_without_saturation_or_value() {
+        for hue in [0, 90, 200, 359] {
+            assert_eq!(Rgb565::from_hsv(hue, 0, 255), WHITE);
+            assert_eq!(Rgb565::from_hsv(hue, 255, 0), BLACK);
+            assert_eq!(Rgb565::from_hsv(hue, 0, 128), Rgb565::new(16, 32, 16));
+        }
+    }
+
+    #[test]
+    fn lerp_ends_and_rounding() {
+        for (a, b) in [(BLACK, WHITE), (RED, BLUE), (Rgb565(0x1234), Rgb565(0xFEDC))] {
+            assert_eq!(a.lerp(b, 0), a);
+            assert_eq!(a.lerp(b, 255), b);
+        }
+        // the step is cut short, so half way lands a little nearer the
+        // starting colour whichever way round
+        assert_eq!(BLACK.lerp(WHITE, 128), Rgb565::new(15, 31, 15));
+        assert_eq!(WHITE.lerp(BLACK, 128), Rgb565::new(16, 32, 16));
+        assert_eq!(BLACK.lerp(WHITE, 1), BLACK);
+        assert_eq!(BLACK.lerp(WHITE, 254), Rgb565::new(30, 62, 30));
+    }
+
+    #[test]
+    fn blend_is_opacity() {
+        assert_eq!(RED.blend(BLUE, 255), RED);
+        assert_eq!(RED.blend(BLUE, 0), BLUE);
+        assert_eq!(WHITE.blend(BLACK, 128), BLACK.lerp(WHITE, 128));
+    }
+
+    #[test]
+    fn rgb888_round_trip() {
+        assert_eq!(WHITE.to_rgb888(), (255, 255, 255));
+        assert_eq!(BLACK.to_rgb888(), (0, 0, 0));
+        assert_eq!(Rgb565::new(16, 32, 16).to_rgb888(), (132, 130, 132));
+        for raw in 0..=u16::MAX {
+            let (r, g, b) = Rgb565(raw).to_rgb888();
+            assert_eq!(Rgb565::from_rgb888(r, g, b), Rgb565(raw));
+        }
+        // 8 bit values round to the nearest step
+        assert_eq!(Rgb565::from_rgb888(4, 2, 3), BLACK);
+        assert_eq!(Rgb565::from_rgb888(5, 3, 5), Rgb565::new(1, 1, 1));
+    }
+
+    #[test]
+    fn pack666_keeps_six_bits_at_the_top() {
+        assert_eq!(WHITE.pack666(), 0xFC_FC_FC);
+        assert_eq!(BLACK.pack666(), 0);
+        assert_eq!(RED.pack666(), 0xFC_00_00);
+        assert_eq!(Rgb565::new(16, 32, 16).pack666(), 0x84_80_84);
+        for g in 0..64 {
+            assert_eq!(Rgb565::new(0, g, 0).pack666(), (g as u32) << 10);
+        }
+        // 5 bit channels repeat their top bit at the bottom
+        assert_eq!(Rgb565::new(1, 0, 0).pack666(), 0x08_00_00);
+        assert_eq!(Rgb565::new(0x10, 0, 0).pack666(), 0x84_00_00);
+    }
+
+    #[test]
+    fn pack444_left_pixel_first() {
+        assert_eq!(Rgb565::pack444(WHITE, BLACK), 0xFFF_000);
+        assert_eq!(Rgb565::pack444(BLACK, WHITE), 0x000_FFF);
+        assert_eq!(Rgb565::pack444(RED, BLUE), 0xF00_00F);
+        assert_eq!(Rgb565::pack444(Rgb565::new(16, 32, 16), GREEN), 0x888_0F0);
+        // the lowest steps vanish
+        assert_eq!(Rgb565::pack444(Rgb565::new(1, 3, 1), Rgb565::new(2, 4, 2)), 0x000_111);
+    }
+}
@@ -0,0 +1,231 @@
+use core::fmt;
+
+use alloc::string::String;
+
+use crate::color::Rgb565;
+use crate::font::FONT_8X16;
+use crate::lcd::{Lcd, LcdError, LcdInterface, SpiInterface};
+
+const LINE_HEIGHT: u16 = 16;
+const CHAR_WIDTH: u16 = 8;
+const TAB_WIDTH: u16 = 8;
+
+/// What a character shows as: itself if it is printable ASCII, `?` if not.
+fn printable(c: char) -> char {
+    if c.is_ascii() && !c.is_ascii_control() { c } else { '?' }
+}
+
+/// Text terminal on top of `Lcd` using the 8x16 font, `FONT_8X16`.
+///
+/// Implements `fmt::Write`, so `write!`/`writeln!` work directly. Keeps a
+/// cursor in character cells, understands `\n`, `\r` and `\t`, wraps at the
+/// right edge and scrolls once the bottom row is full. Characters outside
+/// printable ASCII show as `?`.
+///
+/// Scrolling uses the controller's hardware vertical scrolling, so nothing
+/// already on screen has to be redrawn. That moves along the native rows,
+/// so it is only used when the orientation neither swaps nor mirrors them;
+/// otherwise the console clears itself and starts again from the top.
+///
+/// Drawing fails with `LcdError::Asleep` while the panel is
+/// asleep; through `fmt::Write` that shows up as `fmt::Error`.
+pub struct Console<'a, I: LcdInterface = SpiInterface> {
+    lcd: &'a mut Lcd<I>,
+    pub fg: Rgb565,
+    pub bg: Rgb565,
+    scale: u16,
+    cols: u16,
+    rows: u16,
+    /// Cursor in character cells. `row == rows` means the next character
+    /// scrolls first.
+    col: u16,
+    row: u16,
+    /// Screen slot currently shown as the top row.
+    top: u16,
+    hardware_scroll: bool,
+}
+
+impl<'a, I: LcdInterface> Console<'a, I> {
+    pub fn new(lcd: &'a mut Lcd<I>, fg: Rgb565, bg: Rgb565) -> Result<Self, LcdError> {
+        let hardware_scroll = !lcd.dir.is_swapped() && lcd.dir as u8 & 0x80 == 0;
+        let mut console = Console {
+            lcd, fg, bg,
+            scale: 1, cols: 0, rows: 0,
+            col: 0, row: 0, top: 0,
+            hardware_scroll,
+        };
+        console.layout()?;
+        Ok(console)
+    }
+
+    pub fn set_colors(&mut self, fg: Rgb565, bg: Rgb565) {
+        self.fg = fg;
+        self.bg = bg;
+    }
+
+    pub fn scale(&self) -> u16 {
+        self.scale
+    }
+
+    /// Draw every font pixel as a `scale` x `scale` block. Clears the screen.
+    pub fn set_scale(&mut self, scale: u16) -> Result<(), LcdError> {
+        self.scale = scale.max(1);
+        self.layout()
+    }
+
+    /// Columns and rows of character cells.
+    pub fn size(&self) -> (u16, u16) {
+        (self.cols, self.rows)
+    }
+
+    pub fn cursor(&self) -> (u16, u16) {
+        (self.col, self.row.min(self.rows.saturating_sub(1)))
+    }
+
+    pub fn set_cursor(&mut self, col: u16, row: u16) {
+        self.col = col.min(self.cols);
+        self.row = row.min(self.rows.saturating_sub(1));
+    }
+
+    pub fn clear(&mut self) -> Result<(), LcdError> {
+        let (max_x, max_y) = (self.lcd.max_x, self.lcd.max_y);
+        self.lcd.fill_rectangle(0, 0, max_x, max_y, self.bg)?;
+        self.col = 0;
+        self.row = 0;
+        self.top = 0;
+        if self.hardware_scroll {
+            self.lcd.scroll_to(self.lcd.panel.row_offset)?;
+        }
+        Ok(())
+    }
+
+    /// Print `s` on a line of its own, cut off at the right edge. Control
+    /// characters aren't interpreted here, they show as `?` like anything
+    /// else outside printable ASCII.
+    pub fn write_line(&mut self, s: &str) -> Result<(), LcdError> {
+        if self.rows == 0 {
+            return Ok(());
+        }
+        if self.col > 0 {
+            self.newline()?;
+        }
+        let line: String = s.chars().take(self.cols as usize).map(printable).collect();
+        self.put(&line)?;
+        // whatever an earlier `\r` left behind on this line goes
+        self.ensure_row()?;
+        let y = self.line_y(self.row);
+        let x = self.col * self.char_width();
+        let (max_x, bg) = (self.lcd.max_x, self.bg);
+        if x < max_x {
+            self.lcd.fill_rectangle(x, y, max_x, y + self.line_height(), bg)?;
+        }
+        self.newline()
+    }
+
+    fn char_width(&self) -> u16 {
+        CHAR_WIDTH * self.scale
+    }
+
+    fn line_height(&self) -> u16 {
+        LINE_HEIGHT * self.scale
+    }
+
+    fn layout(&mut self) -> Result<(), LcdError> {
+        self.cols = self.lcd.max_x / self.char_width();
+        self.rows = self.lcd.max_y / self.line_height();
+        if self.hardware_scroll {
+            // the visible rows start at the panel's row offset inside the RAM
+            let top = self.lcd.panel.row_offset;
+            let scroll = self.rows * self.line_height();
+            let bottom = self.lcd.panel.ram_height - top - scroll;
+            self.lcd.define_scroll_area(top, scroll, bottom)?;
+        }
+        self.clear()
+    }
+
+    /// Screen y of cursor row `row`, after hardware scrolling.
+    fn line_y(&self, row: u16) -> u16 {
+        let slot = if self.hardware_scroll { (self.top + row) % self.rows } else { row };
+        slot * self.line_height()
+    }
+
+    /// Scroll if the cursor has run off the bottom.
+    fn ensure_row(&mut self) -> Result<(), LcdError> {
+        if self.row < self.rows {
+            return Ok(());
+        }
+        if self.hardware_scroll {
+            // the oldest line leaves at the top and its slot comes back
+            // blank at the bottom
+            let y = self.top * self.line_height();
+            let (max_x, bg) = (self.lcd.max_x, self.bg);
+            self.lcd.fill_rectangle(0, y, max_x, y + self.line_height(), bg)?;
+            self.top = (self.top + 1) % self.rows;
+            let line = self.lcd.panel.row_offset + self.top * self.line_height();
+            self.lcd.scroll_to(line)?;
+            self.row = self.rows - 1;
+            Ok(())
+        } else {
+            self.clear()
+        }
+    }
+
+    fn newline(&mut self) -> Result<(), LcdError> {
+        self.ensure_row()?;
+        self.col = 0;
+        self.row += 1;
+        Ok(())
+    }
+
+    fn tab(&mut self) -> Result<(), LcdError> {
+        let spaces = (TAB_WIDTH - self.col % TAB_WIDTH).min(self.cols - self.col);
+        self.put(&"        "[..spaces as usize])
+    }
+
+    /// Draw `text` at the cursor in one go. The caller makes sure it is
+    /// printable ASCII, one cell per byte, and fits on the current line.
+    fn put(&mut self, text: &str) -> Result<(), LcdError> {
+        if text.is_empty() || self.rows == 0 {
+            return Ok(());
+        }
+        self.ensure_row()?;
+        let x = self.col * self.char_width();
+        let y = self.line_y(self.row);
+        let (fg, bg, scale) = (self.fg, self.bg, self.scale);
+        self.lcd.draw_text(x, y, text, &FONT_8X16, fg, bg, scale)?;
+        self.col += text.len() as u16;
+        Ok(())
+    }
+}
+
+impl<'a, I: LcdInterface> fmt::Write for Console<'a, I> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.rows == 0 || self.cols == 0 {
+            return Ok(());
+        }
+        // printable characters are collected and drawn a line at a time
+        let mut run = String::new();
+        for c in s.chars() {
+            match c {
+                '\n' | '\r' | '\t' => {
+                    self.put(&run).map_err(|_| fmt::Error)?;
+                    run.clear();
+                    match c {
+                        '\n' => self.newline().map_err(|_| fmt::Error)?,
+                        '\r' => self.col = 0,
+                        _ => self.tab().map_err(|_| fmt::Error)?,
+                    }
+                }
+                _ => {
+                    if self.col + run.len() as u16 >= self.cols {
+                        self.put(&run).map_err(|_| fmt::Error)?;
+                        run.clear();
+                        self.newline().map_err(|_| fmt::Error)?;
+                    }
+                    run.push(printable(c));
+                }
+            }
+        }
+        self.put(&run).map_err(|_| fmt::Error)
+    }
+}
@@ -1,23 +1,46 @@
-pub const BLACK: u16       = 0x0000;
-pub const NAVY: u16        = 0x000F;
-pub const DARKGREEN: u16   = 0x03E0;
-pub const DARKCYAN: u16    = 0x03EF;
-pub const MAROON: u16      = 0x7800;
-pub const PURPLE: u16      = 0x780F;
-pub const OLIVE: u16       = 0x7BE0;
-pub const LIGHTGREY: u16   = 0xC618;
-pub const DARKGREY: u16    = 0x7BEF;
-pub const BLUE: u16        = 0x001F;
-pub const GREEN: u16       = 0x07E0;
-pub const CYAN: u16        = 0x07FF;
-pub const RED: u16         = 0xF800;
-pub const MAGENTA: u16     = 0xF81F;
-pub const YELLOW: u16      = 0xFFE0;
-pub const WHITE: u16       = 0xFFFF;
-pub const ORANGE: u16      = 0xFD20;
-pub const GREENYELLOW: u16 = 0xAFE5;
-pub const PINK: u16        = 0xF81F;
-pub const USER_COLOR: u16  = 0xAA55;
+use crate::color::Rgb565;
+
+pub const BLACK: Rgb565       = Rgb565(0x0000);
+pub const NAVY: Rgb565        = Rgb565(0x000F);
+pub const DARKGREEN: Rgb565   = Rgb565(0x03E0);
+pub const DARKCYAN: Rgb565    = Rgb565(0x03EF);
+pub const MAROON: Rgb565      = Rgb565(0x7800);
+pub const PURPLE: Rgb565      = Rgb565(0x780F);
+pub const OLIVE: Rgb565       = Rgb565(0x7BE0);
+pub const LIGHTGREY: Rgb565   = Rgb565(0xC618);
+pub const DARKGREY: Rgb565    = Rgb565(0x7BEF);
+pub const BLUE: Rgb565        = Rgb565(0x001F);
+pub const GREEN: Rgb565       = Rgb565(0x07E0);
+pub const CYAN: Rgb565        = Rgb565(0x07FF);
+pub const RED: Rgb565         = Rgb565(0xF800);
+pub const MAGENTA: Rgb565     = Rgb565(0xF81F);
+pub const YELLOW: Rgb565      = Rgb565(0xFFE0);
+pub const WHITE: Rgb565       = Rgb565(0xFFFF);
+pub const ORANGE: Rgb565      = Rgb565(0xFD20);
+pub const GREENYELLOW: Rgb565 = Rgb565(0xAFE5);
+pub const PINK: Rgb565        = Rgb565::from_rgb888(255, 192, 203);
+pub const USER_COLOR: Rgb565  = Rgb565(0xAA55);
+
+pub const BROWN: Rgb565       = Rgb565::from_rgb888(165, 42, 42);
+pub const CORAL: Rgb565       = Rgb565::from_rgb888(255, 127, 80);
+pub const CRIMSON: Rgb565     = Rgb565::from_rgb888(220, 20, 60);
+pub const GOLD: Rgb565        = Rgb565::from_rgb888(255, 215, 0);
+pub const INDIGO: Rgb565      = Rgb565::from_rgb888(75, 0, 130);
+pub const IVORY: Rgb565       = Rgb565::from_rgb888(255, 255, 240);
+pub const KHAKI: Rgb565       = Rgb565::from_rgb888(240, 230, 140);
+pub const LAVENDER: Rgb565    = Rgb565::from_rgb888(230, 230, 250);
+pub const LIME: Rgb565        = Rgb565::from_rgb888(50, 205, 50);
+pub const MINT: Rgb565        = Rgb565::from_rgb888(189, 252, 201);
+pub const SALMON: Rgb565      = Rgb565::from_rgb888(250, 128, 114);
+pub const SILVER: Rgb565      = Rgb565::from_rgb888(192, 192, 192);
+pub const SKYBLUE: Rgb565     = Rgb565::from_rgb888(135, 206, 235);
+pub const STEELBLUE: Rgb565   = Rgb565::from_rgb888(70, 130, 180);
+pub const TEAL: Rgb565        = Rgb565::from_rgb888(0, 128, 128);
+pub const TOMATO: Rgb565      = Rgb565::from_rgb888(255, 99, 71);
+pub const TURQUOISE: Rgb565   = Rgb565::from_rgb888(64, 224, 208);
+pub const VIOLET: Rgb565      = Rgb565::from_rgb888(238, 130, 238);
+pub const BEIGE: Rgb565       = Rgb565::from_rgb888(245, 245, 220);
+pub const CHOCOLATE: Rgb565   = Rgb565::from_rgb888(210, 105, 30);
 
 pub const ASCII0816: [u8; 4096] = [
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -364,6 +387,7 @@ pub const ASCII0816: [u8; 4096] = [
     0x00, 0x00, 0x00, 0x00,
 ];
 
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Dir {
     XyRlud = 0x00,
     YxRlud = 0x20,
@@ -375,6 +399,14 @@ pub enum Dir {
     YxLrdu = 0xE0,
 }
 
+impl Dir {
+    /// Rows and columns exchanged (MADCTL MV), i.e. landscape on a
+    /// portrait panel.
+    pub fn is_swapped(self) -> bool {
+        self as u8 & 0x20 != 0
+    }
+}
+
 pub const CAM_WIDTH: u32 = 320;
 pub const CAM_HEIGHT: u32 = 240;
 pub const CAM_PIXELS: usize = (CAM_WIDTH * CAM_HEIGHT) as usize;
\ No newline at end of file
@@ -0,0 +1,248 @@
+pub mod cjk16;
+pub mod font6x8;
+pub mod font12x24;
+pub mod font16x32;
+pub mod sans16;
+pub mod sans24;
+pub mod sans24aa;
+
+pub use cjk16::CJK_16;
+pub use font6x8::FONT_6X8;
+pub use font12x24::FONT_12X24;
+pub use font16x32::FONT_16X32;
+pub use sans16::SANS_16;
+pub use sans24::SANS_24;
+pub use sans24aa::SANS_24_AA;
+
+use crate::constant::ASCII0816;
+
+/// Bitmap font for the text methods of `Lcd` and `FrameBuffer`.
+pub trait Font {
+    /// Rows in every glyph.
+    fn height(&self) -> u8;
+
+    /// Rows from the top of a glyph down to the baseline, e.g. for lining
+    /// up text set in different fonts.
+    fn baseline(&self) -> u8;
+
+    /// `None` if the font has nothing for `c`.
+    fn glyph(&self, c: char) -> Option<Glyph<'_>>;
+
+    /// Drawn for characters `glyph` has nothing for, `?` unless the font
+    /// brings its own.
+    fn fallback(&self) -> Option<Glyph<'_>> {
+        self.glyph('?')
+    }
+
+    /// Width of `s` in pixels as the drawing methods lay it out.
+    fn text_width(&self, s: &str) -> u32 {
+        let fallback = self.fallback();
+        s.chars().filter_map(|c| self.glyph(c).or(fallback)).map(|g| g.width as u32).sum()
+    }
+}
+
+impl<F: Font + ?Sized> Font for &F {
+    fn height(&self) -> u8 {
+        (**self).height()
+    }
+
+    fn baseline(&self) -> u8 {
+        (**self).baseline()
+    }
+
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        (**self).glyph(c)
+    }
+
+    fn fallback(&self) -> Option<Glyph<'_>> {
+        (**self).fallback()
+    }
+}
+
+/// One character: `height` rows of `width` pixels at `bpp` bits each,
+/// rows padded to whole bytes, leftmost pixel in the most significant
+/// bits. With 2 or 4 bits a pixel holds coverage for anti-aliased text.
+#[derive(Clone, Copy, Debug)]
+pub struct Glyph<'a> {
+    pub width: u8,
+    pub bpp: u8,
+    pub bitmap: &'a [u8],
+}
+
+impl<'a> Glyph<'a> {
+    /// Coverage of a pixel, 0 for background to 255 for fully inked. Rows
+    /// past the end of `bitmap` read as 0, so a glyph from a shorter font
+    /// can be drawn in a taller line.
+    pub fn alpha(&self, x: u8, y: u8) -> u8 {
+        let bpp = self.bpp.max(1) as usize;
+        let stride = (self.width as usize * bpp).div_ceil(8);
+        let bit = x as usize * bpp;
+        let byte = self.bitmap.get(y as usize * stride + bit / 8).copied().unwrap_or(0);
+        let max = (1u16 << bpp) - 1;
+        let level = (byte >> (8 - bpp - bit % 8)) as u16 & max;
+        (level * 255 / max) as u8
+    }
+
+    /// Whether a pixel is at least half covered.
+    pub fn pixel(&self, x: u8, y: u8) -> bool {
+        self.alpha(x, y) >= 128
+    }
+}
+
+/// Glyph number `index` out of the tables both font types share: a single
+/// width and no offsets for monospace, otherwise one of each per glyph.
+fn glyph_at<'a>(height: u8, bpp: u8, widths: &[u8], offset: Option<usize>, data: &'a [u8], index: usize) -> Option<Glyph<'a>> {
+    let width = if widths.len() == 1 { widths[0] } else { *widths.get(index)? };
+    let size = (width as usize * bpp as usize).div_ceil(8) * height as usize;
+    let start = offset.unwrap_or(index * size);
+    let bitmap = data.get(start..start + size)?;
+    Some(Glyph { width, bpp, bitmap })
+}
+
+/// Font made of consecutive character codes, as written by
+/// `tools/bdf2rust`.
+///
+/// A monospace font has a single entry in `widths` and no `offsets`; the
+/// glyphs simply follow each other in `data`. A proportional one has a
+/// width and a start offset into `data` for every glyph.
+#[derive(Clone, Copy, Debug)]
+pub struct BitmapFont {
+    pub height: u8,
+    pub baseline: u8,
+    /// Code of the first glyph.
+    pub first: u32,
+    pub count: u32,
+    /// Bits per pixel: 1, or 2 or 4 for anti-aliased fonts.
+    pub bpp: u8,
+    pub widths: &'static [u8],
+    pub offsets: &'static [u16],
+    pub data: &'static [u8],
+}
+
+impl Font for BitmapFont {
+    fn height(&self) -> u8 {
+        self.height
+    }
+
+    fn baseline(&self) -> u8 {
+        self.baseline
+    }
+
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        let index = (c as u32).checked_sub(self.first).filter(|&i| i < self.count)? as usize;
+        let offset = if self.offsets.is_empty() { None } else { Some(*self.offsets.get(index)? as usize) };
+        glyph_at(self.height, self.bpp, self.widths, offset, self.data, index)
+    }
+}
+
+/// Run of consecutive code points in a `UnicodeFont`.
+#[derive(Clone, Copy, Debug)]
+pub struct GlyphRange {
+    pub first: u32,
+    pub count: u16,
+    /// Glyph index of `first`.
+    pub start: u16,
+}
+
+/// Font covering scattered parts of Unicode, e.g. a 16x16 CJK font cut
+/// down to the characters a UI actually uses.
+///
+/// `ranges` is sorted by code point and looked up with a binary search;
+/// the tables behind it work like those of `BitmapFont`, with 32 bit
+/// offsets as CJK fonts easily pass 64 KiB.
+#[derive(Clone, Copy, Debug)]
+pub struct UnicodeFont {
+    pub height: u8,
+    pub baseline: u8,
+    pub ranges: &'static [GlyphRange],
+    /// Bits per pixel: 1, or 2 or 4 for anti-aliased fonts.
+    pub bpp: u8,
+    pub widths: &'static [u8],
+    pub offsets: &'static [u32],
+    pub data: &'static [u8],
+    /// Glyph index drawn for missing characters.
+    pub fallback: Option<u16>,
+}
+
+impl UnicodeFont {
+    fn glyph_index(&self, index: usize) -> Option<Glyph<'static>> {
+        let offset = if self.offsets.is_empty() { None } else { Some(*self.offsets.get(index)? as usize) };
+        glyph_at(self.height, self.bpp, self.widths, offset, self.data, index)
+    }
+}
+
+impl Font for UnicodeFont {
+    fn height(&self) -> u8 {
+        self.height
+    }
+
+    fn baseline(&self) -> u8 {
+        self.baseline
+    }
+
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        let code = c as u32;
+        // last range starting at or before `code`
+        let i = self.ranges.partition_point(|r| r.first <= code).checked_sub(1)?;
+        let range = &self.ranges[i];
+        let offset = code - range.first;
+        if offset >= range.count as u32 {
+            return None;
+        }
+        self.glyph_index(range.start as usize + offset as usize)
+    }
+
+    fn fallback(&self) -> Option<Glyph<'_>> {
+        match self.fallback {
+            Some(index) => self.glyph_index(index as usize),
+            None => self.glyph('?'),
+        }
+    }
+}
+
+/// Two fonts used as one: characters `primary` lacks come from
+/// `secondary`, e.g. `FONT_8X16` for Latin text backed by a 16x16 CJK
+/// font. Meant for fonts of the same height; glyphs are top aligned and the
+/// line is as tall as the taller font.
+#[derive(Clone, Copy, Debug)]
+pub struct FontStack<A, B> {
+    pub primary: A,
+    pub secondary: B,
+}
+
+impl<A: Font, B: Font> FontStack<A, B> {
+    pub fn new(primary: A, secondary: B) -> Self {
+        FontStack { primary, secondary }
+    }
+}
+
+impl<A: Font, B: Font> Font for FontStack<A, B> {
+    fn height(&self) -> u8 {
+        self.primary.height().max(self.secondary.height())
+    }
+
+    fn baseline(&self) -> u8 {
+        self.primary.baseline()
+    }
+
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        self.primary.glyph(c).or_else(|| self.secondary.glyph(c))
+    }
+
+    fn fallback(&self) -> Option<Glyph<'_>> {
+        self.secondary.fallback().or_else(|| self.primary.fallback())
+    }
+}
+
+/// The original 8x16 font, the lower half of `ASCII0816`. The upper half
+/// is code page 437 and doesn't line up with Unicode.
+pub const FONT_8X16: BitmapFont = BitmapFont {
+    height: 16,
+    baseline: 12,
+    first: 0,
+    count: 128,
+    bpp: 1,
+    widths: &[8],
+    offsets: &[],
+    data: &ASCII0816,
+};
@@ -0,0 +1,87 @@
+// Generated by tools/bdf2rust from cjk-demo-16.bdf, do not edit.
+
+use super::{GlyphRange, UnicodeFont};
+
+pub const CJK_16: UnicodeFont = UnicodeFont {
+    height: 16,
+    baseline: 14,
+    ranges: &CJK_16_RANGES,
+    bpp: 1,
+    widths: &CJK_16_WIDTHS,
+    offsets: &CJK_16_OFFSETS,
+    data: &CJK_16_DATA,
+    fallback: Some(16),
+};
+
+pub const CJK_16_RANGES: [GlyphRange; 13] = [
+    GlyphRange { first: 0x4E00, count: 2, start: 0 },
+    GlyphRange { first: 0x4E09, count: 3, start: 2 },
+    GlyphRange { first: 0x4E2D, count: 1, start: 5 },
+    GlyphRange { first: 0x4E8C, count: 1, start: 6 },
+    GlyphRange { first: 0x4EBA, count: 1, start: 7 },
+    GlyphRange { first: 0x5341, count: 1, start: 8 },
+    GlyphRange { first: 0x53E3, count: 1, start: 9 },
+    GlyphRange { first: 0x5927, count: 1, start: 10 },
+    GlyphRange { first: 0x5C0F, count: 1, start: 11 },
+    GlyphRange { first: 0x6587, count: 1, start: 12 },
+    GlyphRange { first: 0x65E5, count: 1, start: 13 },
+    GlyphRange { first: 0x6708, count: 1, start: 14 },
+    GlyphRange { first: 0x7530, count: 1, start: 15 },
+];
+
+pub const CJK_16_WIDTHS: [u8; 17] = [
+    16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+    16, 16, 16, 16, 8,
+];
+
+pub const CJK_16_OFFSETS: [u32; 17] = [
+    0, 32, 64, 96, 128, 160, 192, 224, 256, 288, 320, 352,
+    384, 416, 448, 480, 512,
+];
+
+pub const CJK_16_DATA: [u8; 528] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x3F, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xFE,
+    0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80,
+    0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x09, 0x80,
+    0x07, 0x80, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xF8, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x7F, 0xFE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0xFC,
+    0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80,
+    0x7F, 0xFE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xFE,
+    0x01, 0x80, 0x01, 0x80, 0x01, 0xC0, 0x01, 0xB0, 0x01, 0x98, 0x01, 0x88,
+    0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x80, 0x01, 0x80, 0x7F, 0xFE,
+    0x61, 0x86, 0x61, 0x86, 0x61, 0x86, 0x7F, 0xFE, 0x01, 0x80, 0x01, 0x80,
+    0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x1F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xFE,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x80,
+    0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x03, 0xC0, 0x02, 0x40,
+    0x06, 0x60, 0x04, 0x20, 0x0C, 0x30, 0x18, 0x18, 0x30, 0x0C, 0x60, 0x06,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80,
+    0x01, 0x80, 0x01, 0x80, 0x7F, 0xFE, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80,
+    0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x3F, 0xFC, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C,
+    0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x3F, 0xFC,
+    0x30, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x80,
+    0x01, 0x80, 0x01, 0x80, 0x7F, 0xFE, 0x01, 0x80, 0x01, 0x80, 0x03, 0xC0,
+    0x02, 0x40, 0x06, 0x60, 0x0C, 0x30, 0x18, 0x18, 0x30, 0x0C, 0x60, 0x06,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80,
+    0x11, 0x88, 0x11, 0x88, 0x31, 0x8C, 0x21, 0x84, 0x61, 0x86, 0x41, 0x82,
+    0x01, 0x80, 0x01, 0x80, 0x09, 0x80, 0x07, 0x80, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x01, 0x80, 0x00, 0xC0, 0x7F, 0xFE, 0x18, 0x18, 0x0C, 0x30,
+    0x06, 0x60, 0x03, 0xC0, 0x01, 0x80, 0x03, 0xC0, 0x06, 0x60, 0x1C, 0x38,
+    0x70, 0x0E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xF8,
+    0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x1F, 0xF8, 0x18, 0x18,
+    0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x1F, 0xF8, 0x18, 0x18, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xF8, 0x18, 0x18, 0x18, 0x18,
+    0x1F, 0xF8, 0x18, 0x18, 0x18, 0x18, 0x1F, 0xF8, 0x18, 0x18, 0x18, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x60, 0x98, 0x40, 0x78, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x7F, 0xFE, 0x61, 0x86, 0x61, 0x86, 0x61, 0x86, 0x61, 0x86,
+    0x7F, 0xFE, 0x61, 0x86, 0x61, 0x86, 0x61, 0x86, 0x61, 0x86, 0x7F, 0xFE,
+    0x60, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7E, 0x42, 0x42,
+    0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x7E, 0x00, 0x00,
+];
@@ -0,0 +1,449 @@
+// Generated by tools/bdf2rust from dejavu-sans-16.bdf, do not edit.
+// The font's license is in LICENSE-DejaVu.
+
+use super::{GlyphRange, UnicodeFont};
+
+pub const SANS_16: UnicodeFont = UnicodeFont {
+    height: 16,
+    baseline: 13,
+    ranges: &SANS_16_RANGES,
+    bpp: 1,
+    widths: &SANS_16_WIDTHS,
+    offsets: &SANS_16_OFFSETS,
+    data: &SANS_16_DATA,
+    fallback: Some(191),
+};
+
+pub const SANS_16_RANGES: [GlyphRange; 2] = [
+    GlyphRange { first: 0x0020, count: 95, start: 0 },
+    GlyphRange { first: 0x00A0, count: 96, start: 95 },
+];
+
+pub const SANS_16_WIDTHS: [u8; 192] = [
+    4, 6, 6, 12, 9, 13, 11, 4, 5, 5, 7, 12,
+    4, 5, 4, 5, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 5, 5, 12, 12, 12, 7, 14, 9, 9, 10,
+    11, 9, 8, 11, 10, 4, 4, 9, 8, 12, 10, 11,
+    8, 11, 10, 9, 8, 10, 9, 14, 9, 8, 9, 5,
+    5, 5, 12, 7, 7, 8, 9, 8, 9, 8, 5, 9,
+    9, 4, 4, 8, 4, 13, 9, 8, 9, 9, 6, 7,
+    5, 9, 8, 11, 8, 8, 7, 9, 5, 9, 12, 4,
+    6, 9, 9, 9, 9, 5, 7, 7, 14, 6, 8, 12,
+    5, 14, 7, 7, 12, 6, 6, 7, 9, 9, 4, 7,
+    6, 6, 8, 13, 13, 13, 7, 9, 9, 9, 9, 9,
+    9, 13, 10, 9, 9, 9, 9, 4, 4, 4, 4, 11,
+    10, 11, 11, 11, 11, 11, 12, 11, 10, 10, 10, 10,
+    8, 8, 9, 8, 8, 8, 8, 8, 8, 13, 8, 8,
+    8, 8, 8, 4, 4, 4, 4, 8, 9, 8, 8, 8,
+    8, 8, 12, 8, 9, 9, 9, 9, 8, 9, 8, 8,
+];
+
+pub const SANS_16_OFFSETS: [u32; 192] = [
+    0, 16, 32, 48, 80, 112, 144, 176, 192, 208, 224, 240,
+    272, 288, 304, 320, 336, 368, 400, 432, 464, 496, 528, 560,
+    592, 624, 656, 672, 688, 720, 752, 784, 800, 832, 864, 896,
+    928, 960, 992, 1008, 1040, 1072, 1088, 1104, 1136, 1152, 1184, 1216,
+    1248, 1264, 1296, 1328, 1360, 1376, 1408, 1440, 1472, 1504, 1520, 1552,
+    1568, 1584, 1600, 1632, 1648, 1664, 1680, 1712, 1728, 1760, 1776, 1792,
+    1824, 1856, 1872, 1888, 1904, 1920, 1952, 1984, 2000, 2032, 2064, 2080,
+    2096, 2112, 2144, 2160, 2192, 2208, 2224, 2240, 2272, 2288, 2320, 2352,
+    2368, 2384, 2416, 2448, 2480, 2512, 2528, 2544, 2560, 2592, 2608, 2624,
+    2656, 2672, 2704, 2720, 2736, 2768, 2784, 2800, 2816, 2848, 2880, 2896,
+    2912, 2928, 2944, 2960, 2992, 3024, 3056, 3072, 3104, 3136, 3168, 3200,
+    3232, 3264, 3296, 3328, 3360, 3392, 3424, 3456, 3472, 3488, 3504, 3520,
+    3552, 3584, 3616, 3648, 3680, 3712, 3744, 3776, 3808, 3840, 3872, 3904,
+    3936, 3952, 3968, 4000, 4016, 4032, 4048, 4064, 4080, 4096, 4128, 4144,
+    4160, 4176, 4192, 4208, 4224, 4240, 4256, 4272, 4288, 4320, 4336, 4352,
+    4368, 4384, 4400, 4432, 4448, 4480, 4512, 4544, 4576, 4592, 4624, 4640,
+];
+
+pub const SANS_16_DATA: [u8; 4656] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x20, 0x20, 0x00, 0x20, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x68,
+    0x68, 0x68, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x80, 0x0C, 0x80, 0x0D, 0x80,
+    0x3F, 0xE0, 0x09, 0x00, 0x19, 0x00, 0x7F, 0xC0, 0x12, 0x00, 0x12, 0x00,
+    0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x08, 0x00, 0x3E, 0x00, 0x6A, 0x00, 0x68, 0x00, 0x68, 0x00,
+    0x3E, 0x00, 0x0E, 0x00, 0x0B, 0x00, 0x4A, 0x00, 0x7E, 0x00, 0x08, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x78, 0x40,
+    0x48, 0x80, 0x49, 0x80, 0x49, 0x00, 0x7A, 0x00, 0x02, 0xF0, 0x04, 0x90,
+    0x0C, 0x90, 0x08, 0x90, 0x10, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x00, 0x30, 0x00, 0x20, 0x00,
+    0x30, 0x00, 0x38, 0x00, 0x6C, 0xC0, 0x46, 0x80, 0x63, 0x80, 0x63, 0x80,
+    0x3E, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60,
+    0x60, 0x60, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x10, 0x30, 0x20, 0x60, 0x60, 0x60, 0x60, 0x60, 0x20,
+    0x20, 0x30, 0x10, 0x00, 0x00, 0x00, 0x00, 0x60, 0x20, 0x30, 0x30, 0x10,
+    0x10, 0x10, 0x10, 0x30, 0x20, 0x20, 0x40, 0x00, 0x00, 0x00, 0x00, 0x10,
+    0x54, 0x38, 0x38, 0x54, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x04, 0x00,
+    0x04, 0x00, 0x04, 0x00, 0x7F, 0xC0, 0x06, 0x00, 0x04, 0x00, 0x04, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x60, 0x60, 0x40, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x20, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18,
+    0x10, 0x10, 0x30, 0x20, 0x20, 0x60, 0x40, 0x40, 0xC0, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x26, 0x00, 0x63, 0x00,
+    0x43, 0x00, 0x43, 0x00, 0x43, 0x00, 0x43, 0x00, 0x63, 0x00, 0x26, 0x00,
+    0x3C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x38, 0x00, 0x28, 0x00, 0x08, 0x00, 0x08, 0x00, 0x08, 0x00,
+    0x08, 0x00, 0x08, 0x00, 0x08, 0x00, 0x08, 0x00, 0x3F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7C, 0x00,
+    0x46, 0x00, 0x02, 0x00, 0x02, 0x00, 0x06, 0x00, 0x0C, 0x00, 0x18, 0x00,
+    0x30, 0x00, 0x60, 0x00, 0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7E, 0x00, 0x06, 0x00, 0x02, 0x00,
+    0x06, 0x00, 0x1C, 0x00, 0x06, 0x00, 0x03, 0x00, 0x03, 0x00, 0x06, 0x00,
+    0x7C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0E, 0x00, 0x0E, 0x00, 0x16, 0x00, 0x36, 0x00, 0x26, 0x00,
+    0x66, 0x00, 0x7F, 0x00, 0x7F, 0x00, 0x06, 0x00, 0x06, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7E, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x78, 0x00, 0x7E, 0x00, 0x02, 0x00, 0x03, 0x00,
+    0x03, 0x00, 0x06, 0x00, 0x7C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x00, 0x30, 0x00, 0x60, 0x00,
+    0x6C, 0x00, 0x7E, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x23, 0x00,
+    0x3E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x7F, 0x00, 0x02, 0x00, 0x06, 0x00, 0x06, 0x00, 0x04, 0x00,
+    0x0C, 0x00, 0x08, 0x00, 0x18, 0x00, 0x18, 0x00, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3E, 0x00,
+    0x62, 0x00, 0x63, 0x00, 0x62, 0x00, 0x3C, 0x00, 0x36, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x63, 0x00, 0x3E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x66, 0x00, 0x63, 0x00,
+    0x43, 0x00, 0x63, 0x00, 0x3F, 0x00, 0x1B, 0x00, 0x02, 0x00, 0x06, 0x00,
+    0x7C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x20, 0x20, 0x00, 0x00, 0x00, 0x20, 0x20, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x20, 0x00, 0x00, 0x00, 0x20,
+    0x60, 0x60, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0xC0, 0x03, 0xC0, 0x1E, 0x00, 0x70, 0x00, 0x3C, 0x00,
+    0x07, 0x80, 0x01, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x7F, 0xC0, 0x00, 0x00, 0x3F, 0xC0, 0x3F, 0xC0, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x3C, 0x00, 0x07, 0x80,
+    0x01, 0xC0, 0x03, 0xC0, 0x1E, 0x00, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7C, 0x4C, 0x04, 0x0C, 0x18,
+    0x10, 0x10, 0x00, 0x10, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x03, 0x00, 0x1C, 0xE0, 0x30, 0x30, 0x23, 0x10, 0x47, 0xC8,
+    0x4C, 0xC8, 0x48, 0xD8, 0x4C, 0xD0, 0x47, 0xF0, 0x20, 0x00, 0x30, 0x00,
+    0x0F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00,
+    0x1C, 0x00, 0x16, 0x00, 0x36, 0x00, 0x32, 0x00, 0x23, 0x00, 0x7F, 0x00,
+    0x7F, 0x00, 0x41, 0x80, 0xC0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7E, 0x00, 0x63, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x7E, 0x00, 0x63, 0x00, 0x61, 0x00, 0x61, 0x00, 0x63, 0x00,
+    0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x1F, 0x00, 0x30, 0x80, 0x60, 0x00, 0x60, 0x00, 0x40, 0x00,
+    0x40, 0x00, 0x60, 0x00, 0x60, 0x00, 0x30, 0x80, 0x1F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7E, 0x00,
+    0x63, 0x80, 0x60, 0x80, 0x60, 0xC0, 0x60, 0xC0, 0x60, 0xC0, 0x60, 0xC0,
+    0x60, 0x80, 0x63, 0x80, 0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F, 0x00, 0x60, 0x00, 0x60, 0x00,
+    0x60, 0x00, 0x7F, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00,
+    0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7E,
+    0x60, 0x60, 0x60, 0x7E, 0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x80, 0x30, 0x80, 0x60, 0x00,
+    0x60, 0x00, 0x40, 0x00, 0x43, 0xC0, 0x60, 0xC0, 0x60, 0xC0, 0x30, 0xC0,
+    0x1F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x60, 0x80, 0x60, 0x80, 0x60, 0x80, 0x60, 0x80, 0x7F, 0x80,
+    0x61, 0x80, 0x60, 0x80, 0x60, 0x80, 0x60, 0x80, 0x60, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x60, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0xC0, 0x80,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x61, 0x80, 0x66, 0x00, 0x6C, 0x00,
+    0x78, 0x00, 0x70, 0x00, 0x78, 0x00, 0x6C, 0x00, 0x66, 0x00, 0x63, 0x00,
+    0x61, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x7F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70, 0xE0, 0x70, 0xE0, 0x71, 0xE0,
+    0x79, 0x60, 0x69, 0x60, 0x6F, 0x60, 0x66, 0x60, 0x66, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x70, 0x80, 0x70, 0x80, 0x78, 0x80, 0x68, 0x80, 0x6C, 0x80,
+    0x64, 0x80, 0x66, 0x80, 0x63, 0x80, 0x63, 0x80, 0x61, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x00,
+    0x31, 0x80, 0x60, 0xC0, 0x60, 0xC0, 0x40, 0xC0, 0x40, 0xC0, 0x60, 0xC0,
+    0x60, 0xC0, 0x31, 0x80, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x7E, 0x67, 0x63, 0x63, 0x67, 0x7E, 0x60, 0x60, 0x60,
+    0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x00,
+    0x31, 0x80, 0x60, 0xC0, 0x60, 0xC0, 0x40, 0xC0, 0x40, 0xC0, 0x60, 0xC0,
+    0x60, 0xC0, 0x31, 0x80, 0x1F, 0x00, 0x03, 0x00, 0x01, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7E, 0x00, 0x67, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x6E, 0x00, 0x7E, 0x00, 0x62, 0x00, 0x63, 0x00, 0x61, 0x80,
+    0x61, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x3E, 0x00, 0x60, 0x00, 0x40, 0x00, 0x60, 0x00, 0x3C, 0x00,
+    0x0E, 0x00, 0x03, 0x00, 0x03, 0x00, 0x43, 0x00, 0x7E, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x18, 0x18, 0x18, 0x18,
+    0x18, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80,
+    0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x21, 0x00, 0x1F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x80,
+    0x41, 0x80, 0x61, 0x00, 0x63, 0x00, 0x23, 0x00, 0x32, 0x00, 0x36, 0x00,
+    0x16, 0x00, 0x1C, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x08, 0x43, 0x18, 0x67, 0x18,
+    0x65, 0x90, 0x65, 0x90, 0x24, 0xB0, 0x3C, 0xB0, 0x38, 0xE0, 0x38, 0xE0,
+    0x18, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x61, 0x80, 0x23, 0x00, 0x32, 0x00, 0x1E, 0x00, 0x0C, 0x00,
+    0x1C, 0x00, 0x16, 0x00, 0x32, 0x00, 0x63, 0x00, 0x41, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC1, 0x62, 0x26, 0x3C, 0x18,
+    0x18, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x7F, 0x80, 0x03, 0x00, 0x03, 0x00, 0x06, 0x00, 0x0C, 0x00,
+    0x18, 0x00, 0x10, 0x00, 0x30, 0x00, 0x60, 0x00, 0x7F, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x70, 0x60, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x70, 0x00, 0x00, 0x00, 0x00, 0x80,
+    0xC0, 0x40, 0x40, 0x60, 0x20, 0x20, 0x30, 0x10, 0x10, 0x10, 0x00, 0x00,
+    0x00, 0x00, 0x30, 0x30, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10,
+    0x10, 0x10, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0E, 0x00,
+    0x0B, 0x00, 0x11, 0x80, 0x20, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0xFE, 0x00, 0x00, 0x60, 0x30, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x38, 0x6E, 0x02, 0x3E, 0x62, 0x42, 0x66, 0x7A, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x60, 0x00, 0x6C, 0x00,
+    0x76, 0x00, 0x63, 0x00, 0x61, 0x00, 0x61, 0x00, 0x63, 0x00, 0x63, 0x00,
+    0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x1C, 0x36, 0x60, 0x40, 0x40, 0x40, 0x60, 0x3E, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, 0x1B, 0x00,
+    0x3F, 0x00, 0x63, 0x00, 0x43, 0x00, 0x43, 0x00, 0x43, 0x00, 0x67, 0x00,
+    0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x1C, 0x3E, 0x63, 0x7F, 0x7F, 0x40, 0x60, 0x3E, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x08, 0x38, 0x60, 0x70, 0x70, 0x60, 0x60, 0x60, 0x60, 0x60,
+    0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x18, 0x00, 0x7F, 0x00, 0x43, 0x00, 0x43, 0x00, 0x43, 0x00,
+    0x43, 0x00, 0x67, 0x00, 0x3F, 0x00, 0x02, 0x00, 0x26, 0x00, 0x3C, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x60, 0x00, 0x6C, 0x00,
+    0x7E, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60,
+    0x00, 0x00, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x60, 0x00, 0x00, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x40, 0xC0, 0x00, 0x00, 0x00, 0x60, 0x60, 0x62, 0x66, 0x6C,
+    0x70, 0x70, 0x68, 0x64, 0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x60,
+    0x7F, 0xF0, 0x63, 0x10, 0x63, 0x10, 0x62, 0x10, 0x62, 0x10, 0x62, 0x10,
+    0x62, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x7E, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x3E, 0x62,
+    0x43, 0x43, 0x43, 0x66, 0x3C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x76, 0x00, 0x63, 0x00,
+    0x61, 0x00, 0x61, 0x00, 0x63, 0x00, 0x63, 0x00, 0x7E, 0x00, 0x60, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x18, 0x00, 0x3F, 0x00, 0x63, 0x00, 0x43, 0x00, 0x43, 0x00,
+    0x43, 0x00, 0x67, 0x00, 0x3F, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x78, 0x60, 0x60, 0x60, 0x60, 0x60,
+    0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0x7C, 0x40,
+    0x70, 0x3C, 0x04, 0x04, 0x7C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+    0x60, 0x78, 0x78, 0x60, 0x60, 0x60, 0x60, 0x60, 0x38, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00,
+    0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x67, 0x00,
+    0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x40, 0x42, 0x62, 0x66, 0x24, 0x3C, 0x1C, 0x18, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x00,
+    0x46, 0x40, 0x4E, 0x40, 0x6A, 0x40, 0x2B, 0xC0, 0x3B, 0x80, 0x31, 0x80,
+    0x31, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x42, 0x66, 0x3C, 0x18, 0x18, 0x3C, 0x66, 0x42, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x42, 0x62, 0x26, 0x24, 0x3C, 0x18,
+    0x18, 0x18, 0x30, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7C, 0x7E, 0x0C,
+    0x18, 0x10, 0x20, 0x60, 0xFE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x00, 0x0E, 0x00, 0x08, 0x00, 0x08, 0x00, 0x08, 0x00, 0x18, 0x00,
+    0x30, 0x00, 0x38, 0x00, 0x08, 0x00, 0x08, 0x00, 0x08, 0x00, 0x08, 0x00,
+    0x0E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x00, 0x38, 0x00, 0x18, 0x00, 0x08, 0x00, 0x08, 0x00, 0x08, 0x00,
+    0x0E, 0x00, 0x0E, 0x00, 0x08, 0x00, 0x08, 0x00, 0x08, 0x00, 0x18, 0x00,
+    0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x03, 0x80,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x30, 0x00,
+    0x00, 0x20, 0x30, 0x30, 0x30, 0x30, 0x30, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x1E, 0x00, 0x3E, 0x00, 0x68, 0x00,
+    0x68, 0x00, 0x68, 0x00, 0x68, 0x00, 0x38, 0x00, 0x1E, 0x00, 0x08, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x00,
+    0x10, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x7C, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x30, 0x00, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x41, 0x00,
+    0x7E, 0x00, 0x26, 0x00, 0x62, 0x00, 0x22, 0x00, 0x3E, 0x00, 0x4B, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x41, 0x00, 0x62, 0x00, 0x26, 0x00, 0x34, 0x00, 0x7F, 0x00,
+    0x18, 0x00, 0x7F, 0x00, 0x08, 0x00, 0x08, 0x00, 0x08, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x20, 0x20, 0x20, 0x20,
+    0x20, 0x00, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00, 0x00, 0x00, 0x3C,
+    0x60, 0x60, 0x70, 0x4C, 0x44, 0x64, 0x38, 0x0C, 0x0C, 0x78, 0x00, 0x00,
+    0x00, 0x00, 0x08, 0x6C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x80,
+    0x18, 0x40, 0x17, 0xA0, 0x2C, 0x10, 0x28, 0x10, 0x28, 0x10, 0x2C, 0x10,
+    0x17, 0xA0, 0x18, 0x40, 0x07, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x78, 0x0C, 0x7C, 0x4C, 0x7C, 0x00, 0x7C, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x36,
+    0x6C, 0x6C, 0x36, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xC0,
+    0x00, 0xC0, 0x00, 0x40, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x07, 0x80, 0x18, 0x40, 0x17, 0x20, 0x24, 0x90, 0x25, 0x90,
+    0x27, 0x10, 0x24, 0x90, 0x14, 0xA0, 0x18, 0x40, 0x07, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x78, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38,
+    0x4C, 0x68, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x04, 0x00,
+    0x04, 0x00, 0x7F, 0xC0, 0x06, 0x00, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x7F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70,
+    0x18, 0x10, 0x20, 0x60, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x70, 0x18, 0x30, 0x18, 0x18, 0x60, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x18, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x63, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x67, 0x00, 0x7F, 0x00, 0x60, 0x00,
+    0x60, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3E, 0x00,
+    0x7A, 0x00, 0x7A, 0x00, 0x7A, 0x00, 0x3A, 0x00, 0x1A, 0x00, 0x0A, 0x00,
+    0x0A, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x60, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x18, 0x30, 0x00, 0x00, 0x00, 0x60,
+    0x20, 0x20, 0x20, 0x30, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x78, 0x4C, 0x44, 0x4C, 0x78, 0x00, 0x7C, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0x2C,
+    0x12, 0x12, 0x2C, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x60, 0x40, 0x20, 0x80, 0x20, 0x80, 0x21, 0x00, 0x33, 0x10,
+    0x72, 0x30, 0x04, 0x50, 0x04, 0x90, 0x08, 0xF8, 0x18, 0x10, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x40,
+    0x20, 0x80, 0x20, 0x80, 0x21, 0x00, 0x33, 0x60, 0x72, 0x10, 0x04, 0x10,
+    0x04, 0x30, 0x08, 0x60, 0x18, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70, 0x40, 0x18, 0x80, 0x30, 0x80,
+    0x19, 0x00, 0x1B, 0x10, 0x62, 0x30, 0x04, 0x50, 0x04, 0x90, 0x08, 0xF8,
+    0x18, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x18, 0x00, 0x18, 0x18, 0x10, 0x30, 0x60, 0x60, 0x7C, 0x38,
+    0x18, 0x00, 0x08, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x1C, 0x00, 0x16, 0x00,
+    0x36, 0x00, 0x32, 0x00, 0x23, 0x00, 0x7F, 0x00, 0x7F, 0x00, 0x41, 0x80,
+    0xC0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x08, 0x00,
+    0x00, 0x00, 0x0C, 0x00, 0x1C, 0x00, 0x16, 0x00, 0x36, 0x00, 0x32, 0x00,
+    0x23, 0x00, 0x7F, 0x00, 0x7F, 0x00, 0x41, 0x80, 0xC0, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x14, 0x00, 0x00, 0x00, 0x0C, 0x00,
+    0x1C, 0x00, 0x16, 0x00, 0x36, 0x00, 0x32, 0x00, 0x23, 0x00, 0x7F, 0x00,
+    0x7F, 0x00, 0x41, 0x80, 0xC0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1A, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x1C, 0x00, 0x16, 0x00,
+    0x36, 0x00, 0x32, 0x00, 0x23, 0x00, 0x7F, 0x00, 0x7F, 0x00, 0x41, 0x80,
+    0xC0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x16, 0x00,
+    0x00, 0x00, 0x0C, 0x00, 0x1C, 0x00, 0x16, 0x00, 0x36, 0x00, 0x32, 0x00,
+    0x23, 0x00, 0x7F, 0x00, 0x7F, 0x00, 0x41, 0x80, 0xC0, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x14, 0x00, 0x16, 0x00, 0x1C, 0x00,
+    0x1C, 0x00, 0x16, 0x00, 0x36, 0x00, 0x32, 0x00, 0x23, 0x00, 0x7F, 0x00,
+    0x7F, 0x00, 0x41, 0x80, 0xC0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0xF0, 0x0B, 0x00, 0x1B, 0x00,
+    0x13, 0x00, 0x33, 0xF0, 0x23, 0x00, 0x7F, 0x00, 0x7F, 0x00, 0x43, 0x00,
+    0xC3, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x1F, 0x00, 0x30, 0x80, 0x60, 0x00, 0x60, 0x00, 0x40, 0x00,
+    0x40, 0x00, 0x60, 0x00, 0x60, 0x00, 0x30, 0x80, 0x1F, 0x00, 0x06, 0x00,
+    0x06, 0x00, 0x0C, 0x00, 0x10, 0x00, 0x08, 0x00, 0x00, 0x00, 0x7F, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x7F, 0x00, 0x60, 0x00, 0x60, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x08, 0x00, 0x00, 0x00, 0x7F, 0x00, 0x60, 0x00, 0x60, 0x00,
+    0x60, 0x00, 0x7F, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00,
+    0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00,
+    0x00, 0x00, 0x7F, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x7F, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x7F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x34, 0x00, 0x00, 0x00, 0x7F, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x7F, 0x00, 0x60, 0x00, 0x60, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x40, 0x60, 0x00, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60,
+    0x60, 0x00, 0x00, 0x00, 0x20, 0x60, 0x00, 0x60, 0x60, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00, 0x00, 0x60, 0xD0, 0x00, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00, 0x00,
+    0x90, 0x90, 0x00, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60,
+    0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7E, 0x00,
+    0x63, 0x80, 0x60, 0x80, 0x60, 0xC0, 0xF8, 0xC0, 0x60, 0xC0, 0x60, 0xC0,
+    0x60, 0x80, 0x63, 0x80, 0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0A, 0x00, 0x16, 0x00, 0x00, 0x00, 0x70, 0x80, 0x70, 0x80, 0x78, 0x80,
+    0x68, 0x80, 0x6C, 0x80, 0x64, 0x80, 0x66, 0x80, 0x63, 0x80, 0x63, 0x80,
+    0x61, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+    0x00, 0x00, 0x1F, 0x00, 0x31, 0x80, 0x60, 0xC0, 0x60, 0xC0, 0x40, 0xC0,
+    0x40, 0xC0, 0x60, 0xC0, 0x60, 0xC0, 0x31, 0x80, 0x1F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00, 0x1F, 0x00,
+    0x31, 0x80, 0x60, 0xC0, 0x60, 0xC0, 0x40, 0xC0, 0x40, 0xC0, 0x60, 0xC0,
+    0x60, 0xC0, 0x31, 0x80, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x31, 0x80, 0x60, 0xC0,
+    0x60, 0xC0, 0x40, 0xC0, 0x40, 0xC0, 0x60, 0xC0, 0x60, 0xC0, 0x31, 0x80,
+    0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x16, 0x00,
+    0x00, 0x00, 0x1F, 0x00, 0x31, 0x80, 0x60, 0xC0, 0x60, 0xC0, 0x40, 0xC0,
+    0x40, 0xC0, 0x60, 0xC0, 0x60, 0xC0, 0x31, 0x80, 0x1F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x1A, 0x00, 0x00, 0x00, 0x1F, 0x00,
+    0x31, 0x80, 0x60, 0xC0, 0x60, 0xC0, 0x40, 0xC0, 0x40, 0xC0, 0x60, 0xC0,
+    0x60, 0xC0, 0x31, 0x80, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x80,
+    0x11, 0x80, 0x0F, 0x00, 0x06, 0x00, 0x0E, 0x00, 0x19, 0x00, 0x30, 0x80,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x1F, 0xC0, 0x31, 0x80, 0x61, 0xC0, 0x62, 0xC0, 0x46, 0xC0,
+    0x4C, 0xC0, 0x58, 0xC0, 0x70, 0xC0, 0x31, 0x80, 0x5F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x61, 0x80,
+    0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80,
+    0x61, 0x80, 0x21, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80,
+    0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x21, 0x00,
+    0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x1A, 0x00,
+    0x00, 0x00, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80,
+    0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x21, 0x00, 0x1F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x12, 0x00, 0x00, 0x00, 0x61, 0x80,
+    0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80,
+    0x61, 0x80, 0x21, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x08, 0x00, 0xC1, 0x62, 0x26, 0x3C, 0x18, 0x18, 0x18, 0x18, 0x18,
+    0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x60, 0x7E, 0x63, 0x63,
+    0x63, 0x6E, 0x7C, 0x60, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x3E, 0x00, 0x62, 0x00, 0x66, 0x00, 0x68, 0x00, 0x68, 0x00,
+    0x6C, 0x00, 0x67, 0x00, 0x61, 0x00, 0x63, 0x00, 0x7E, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x10, 0x00, 0x38, 0x6E, 0x02,
+    0x3E, 0x62, 0x42, 0x66, 0x7A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x08,
+    0x00, 0x38, 0x6E, 0x02, 0x3E, 0x62, 0x42, 0x66, 0x7A, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x18, 0x3C, 0x24, 0x38, 0x6E, 0x02, 0x3E, 0x62, 0x42, 0x66,
+    0x7A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x3C, 0x00, 0x38, 0x6E, 0x02,
+    0x3E, 0x62, 0x42, 0x66, 0x7A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x24,
+    0x00, 0x38, 0x6E, 0x02, 0x3E, 0x62, 0x42, 0x66, 0x7A, 0x00, 0x00, 0x00,
+    0x00, 0x18, 0x24, 0x3C, 0x18, 0x38, 0x6E, 0x02, 0x3E, 0x62, 0x42, 0x66,
+    0x7A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x38, 0xE0, 0x6F, 0xF0, 0x03, 0x18, 0x3F, 0xF8, 0x63, 0xF8,
+    0x42, 0x00, 0x67, 0x00, 0x7D, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x36, 0x60, 0x40, 0x40, 0x40, 0x60,
+    0x3E, 0x08, 0x0C, 0x18, 0x00, 0x00, 0x30, 0x18, 0x08, 0x1C, 0x3E, 0x63,
+    0x7F, 0x7F, 0x40, 0x60, 0x3E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x08,
+    0x00, 0x1C, 0x3E, 0x63, 0x7F, 0x7F, 0x40, 0x60, 0x3E, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x18, 0x14, 0x00, 0x1C, 0x3E, 0x63, 0x7F, 0x7F, 0x40, 0x60,
+    0x3E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x34, 0x00, 0x1C, 0x3E, 0x63,
+    0x7F, 0x7F, 0x40, 0x60, 0x3E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x40,
+    0x00, 0x00, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x30, 0x20, 0x40, 0x00, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60,
+    0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0xE0, 0x90, 0x00, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xB0,
+    0x00, 0x00, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x1E, 0x38, 0x0C, 0x3E, 0x62, 0x43, 0x43, 0x43, 0x66,
+    0x3C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x3C, 0x00,
+    0x00, 0x00, 0x0C, 0x00, 0x7E, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x30, 0x10, 0x08, 0x1C, 0x3E, 0x62, 0x43, 0x43, 0x43, 0x66,
+    0x3C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x08, 0x00, 0x1C, 0x3E, 0x62,
+    0x43, 0x43, 0x43, 0x66, 0x3C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x14,
+    0x00, 0x1C, 0x3E, 0x62, 0x43, 0x43, 0x43, 0x66, 0x3C, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x10, 0x2C, 0x00, 0x1C, 0x3E, 0x62, 0x43, 0x43, 0x43, 0x66,
+    0x3C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x34, 0x00, 0x1C, 0x3E, 0x62,
+    0x43, 0x43, 0x43, 0x66, 0x3C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00,
+    0x7F, 0xC0, 0x00, 0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1D, 0x3E, 0x66,
+    0x4B, 0x5B, 0x73, 0x66, 0x7C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x30, 0x00, 0x18, 0x00, 0x08, 0x00, 0x40, 0x00, 0x63, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x67, 0x00, 0x3F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x08, 0x00,
+    0x00, 0x00, 0x40, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x67, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x14, 0x00, 0x00, 0x00, 0x40, 0x00,
+    0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x67, 0x00,
+    0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x34, 0x00, 0x00, 0x00, 0x40, 0x00, 0x63, 0x00, 0x63, 0x00,
+    0x63, 0x00, 0x63, 0x00, 0x63, 0x00, 0x67, 0x00, 0x3F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x08, 0x00, 0x40, 0x42, 0x62,
+    0x26, 0x24, 0x3C, 0x18, 0x18, 0x18, 0x30, 0x60, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x60, 0x00, 0x60, 0x00, 0x6C, 0x00, 0x76, 0x00, 0x63, 0x00,
+    0x61, 0x00, 0x61, 0x00, 0x63, 0x00, 0x63, 0x00, 0x7E, 0x00, 0x60, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x00, 0x00, 0x24, 0x34, 0x00, 0x40, 0x42, 0x62,
+    0x26, 0x24, 0x3C, 0x18, 0x18, 0x18, 0x30, 0x60, 0x00, 0x7E, 0x42, 0x42,
+    0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x7E, 0x00, 0x00, 0x00,
+];
@@ -0,0 +1,799 @@
+// Generated by tools/bdf2rust from dejavu-sans-24.bdf, do not edit.
+// The font's license is in LICENSE-DejaVu.
+
+use super::{GlyphRange, UnicodeFont};
+
+pub const SANS_24: UnicodeFont = UnicodeFont {
+    height: 24,
+    baseline: 19,
+    ranges: &SANS_24_RANGES,
+    bpp: 1,
+    widths: &SANS_24_WIDTHS,
+    offsets: &SANS_24_OFFSETS,
+    data: &SANS_24_DATA,
+    fallback: Some(191),
+};
+
+pub const SANS_24_RANGES: [GlyphRange; 2] = [
+    GlyphRange { first: 0x0020, count: 95, start: 0 },
+    GlyphRange { first: 0x00A0, count: 96, start: 95 },
+];
+
+pub const SANS_24_WIDTHS: [u8; 192] = [
+    7, 8, 9, 17, 13, 20, 16, 6, 8, 8, 10, 17,
+    7, 7, 7, 7, 13, 13, 13, 13, 13, 13, 13, 13,
+    13, 13, 7, 7, 17, 17, 17, 11, 21, 14, 14, 14,
+    16, 13, 12, 16, 16, 6, 6, 14, 11, 18, 15, 16,
+    12, 16, 14, 13, 13, 15, 14, 20, 14, 13, 14, 8,
+    7, 8, 17, 10, 10, 13, 13, 11, 13, 13, 7, 13,
+    13, 6, 6, 12, 6, 20, 13, 13, 13, 13, 8, 11,
+    8, 13, 12, 17, 12, 12, 11, 13, 7, 13, 17, 7,
+    8, 13, 13, 13, 13, 7, 10, 10, 21, 10, 13, 17,
+    7, 21, 10, 10, 17, 8, 8, 10, 13, 13, 7, 10,
+    8, 10, 13, 20, 20, 20, 11, 14, 14, 14, 14, 14,
+    14, 20, 14, 13, 13, 13, 13, 6, 6, 6, 6, 16,
+    15, 16, 16, 16, 16, 16, 17, 16, 15, 15, 15, 15,
+    13, 12, 13, 13, 13, 13, 13, 13, 13, 20, 11, 13,
+    13, 13, 13, 6, 6, 6, 6, 13, 13, 13, 13, 13,
+    13, 13, 17, 13, 13, 13, 13, 13, 12, 13, 12, 12,
+];
+
+pub const SANS_24_OFFSETS: [u32; 192] = [
+    0, 24, 48, 96, 168, 216, 288, 336, 360, 384, 408, 456,
+    528, 552, 576, 600, 624, 672, 720, 768, 816, 864, 912, 960,
+    1008, 1056, 1104, 1128, 1152, 1224, 1296, 1368, 1416, 1488, 1536, 1584,
+    1632, 1680, 1728, 1776, 1824, 1872, 1896, 1920, 1968, 2016, 2088, 2136,
+    2184, 2232, 2280, 2328, 2376, 2424, 2472, 2520, 2592, 2640, 2688, 2736,
+    2760, 2784, 2808, 2880, 2928, 2976, 3024, 3072, 3120, 3168, 3216, 3240,
+    3288, 3336, 3360, 3384, 3432, 3456, 3528, 3576, 3624, 3672, 3720, 3744,
+    3792, 3816, 3864, 3912, 3984, 4032, 4080, 4128, 4176, 4200, 4248, 4320,
+    4344, 4368, 4416, 4464, 4512, 4560, 4584, 4632, 4680, 4752, 4800, 4848,
+    4920, 4944, 5016, 5064, 5112, 5184, 5208, 5232, 5280, 5328, 5376, 5400,
+    5448, 5472, 5520, 5568, 5640, 5712, 5784, 5832, 5880, 5928, 5976, 6024,
+    6072, 6120, 6192, 6240, 6288, 6336, 6384, 6432, 6456, 6480, 6504, 6528,
+    6576, 6624, 6672, 6720, 6768, 6816, 6864, 6936, 6984, 7032, 7080, 7128,
+    7176, 7224, 7272, 7320, 7368, 7416, 7464, 7512, 7560, 7608, 7680, 7728,
+    7776, 7824, 7872, 7920, 7944, 7968, 7992, 8016, 8064, 8112, 8160, 8208,
+    8256, 8304, 8352, 8424, 8472, 8520, 8568, 8616, 8664, 8712, 8760, 8808,
+];
+
+pub const SANS_24_DATA: [u8; 8856] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18,
+    0x18, 0x18, 0x00, 0x00, 0x18, 0x18, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x33, 0x00, 0x33, 0x00,
+    0x33, 0x00, 0x33, 0x00, 0x33, 0x00, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x8C, 0x00, 0x01, 0x88, 0x00, 0x01, 0x98, 0x00, 0x03, 0x18, 0x00,
+    0x1F, 0xFF, 0x00, 0x1F, 0xFF, 0x00, 0x03, 0x30, 0x00, 0x06, 0x30, 0x00,
+    0x06, 0x30, 0x00, 0x3F, 0xFC, 0x00, 0x3F, 0xFE, 0x00, 0x0C, 0x60, 0x00,
+    0x0C, 0x60, 0x00, 0x0C, 0x40, 0x00, 0x0C, 0xC0, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x00, 0x02, 0x00,
+    0x1F, 0xC0, 0x3A, 0x60, 0x32, 0x00, 0x32, 0x00, 0x32, 0x00, 0x3F, 0x00,
+    0x0F, 0xC0, 0x02, 0xE0, 0x02, 0x60, 0x02, 0x60, 0x02, 0x60, 0x3F, 0xE0,
+    0x1F, 0x80, 0x02, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x3E, 0x06, 0x00, 0x33, 0x04, 0x00, 0x63, 0x0C, 0x00, 0x63, 0x18, 0x00,
+    0x63, 0x18, 0x00, 0x63, 0x30, 0x00, 0x3E, 0x60, 0x00, 0x1C, 0x67, 0x80,
+    0x00, 0xCF, 0xC0, 0x00, 0x8C, 0xC0, 0x01, 0x88, 0x40, 0x03, 0x18, 0x60,
+    0x03, 0x0C, 0xC0, 0x06, 0x0C, 0xC0, 0x04, 0x07, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0xC0, 0x1E, 0xE0,
+    0x18, 0x00, 0x18, 0x00, 0x18, 0x00, 0x0C, 0x00, 0x1E, 0x00, 0x3F, 0x06,
+    0x33, 0x8C, 0x71, 0xCC, 0x60, 0xEC, 0x70, 0x78, 0x30, 0x38, 0x3F, 0xFC,
+    0x0F, 0xCE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x20, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x04, 0x0C, 0x0C, 0x18, 0x18, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x18, 0x18, 0x0C, 0x0C, 0x04, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x20, 0x30, 0x30, 0x18, 0x18, 0x0C, 0x0C, 0x0C, 0x0C,
+    0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x18, 0x18, 0x10, 0x30, 0x20, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x0C, 0x00,
+    0x6D, 0x80, 0x3F, 0x00, 0x0E, 0x00, 0x3F, 0x00, 0x6C, 0xC0, 0x0C, 0x00,
+    0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00,
+    0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00, 0x01, 0xC0, 0x00,
+    0x3F, 0xFE, 0x00, 0x01, 0xC0, 0x00, 0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00,
+    0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x30, 0x30, 0x20, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x7C, 0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x06, 0x06, 0x0C, 0x0C, 0x0C, 0x18, 0x18, 0x18,
+    0x30, 0x30, 0x30, 0x20, 0x60, 0x60, 0x60, 0xC0, 0xC0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x80, 0x1D, 0xC0,
+    0x38, 0x60, 0x30, 0x60, 0x30, 0x70, 0x70, 0x70, 0x70, 0x30, 0x70, 0x30,
+    0x70, 0x30, 0x70, 0x70, 0x30, 0x70, 0x30, 0x60, 0x38, 0x60, 0x1D, 0xC0,
+    0x0F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x3F, 0x00,
+    0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00,
+    0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x1F, 0xE0,
+    0x3F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x80, 0x3F, 0xC0,
+    0x00, 0xE0, 0x00, 0x60, 0x00, 0x60, 0x00, 0xE0, 0x00, 0xC0, 0x01, 0xC0,
+    0x03, 0x80, 0x07, 0x00, 0x0E, 0x00, 0x1C, 0x00, 0x38, 0x00, 0x7F, 0xE0,
+    0x7F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x80, 0x3F, 0xC0,
+    0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0xE0, 0x0F, 0x80, 0x0F, 0xC0,
+    0x00, 0xE0, 0x00, 0x60, 0x00, 0x70, 0x00, 0x60, 0x00, 0x60, 0x7F, 0xC0,
+    0x3F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xC0, 0x03, 0xC0,
+    0x07, 0xC0, 0x06, 0xC0, 0x0C, 0xC0, 0x08, 0xC0, 0x18, 0xC0, 0x30, 0xC0,
+    0x30, 0xC0, 0x61, 0xC0, 0x7F, 0xF0, 0x7F, 0xF0, 0x00, 0xC0, 0x00, 0xC0,
+    0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x3F, 0xC0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0x00, 0x3F, 0xC0, 0x00, 0xE0,
+    0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0xE0, 0x7F, 0xC0,
+    0x3F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xE0, 0x1F, 0xE0,
+    0x18, 0x00, 0x30, 0x00, 0x30, 0x00, 0x37, 0x80, 0x7F, 0xE0, 0x78, 0x60,
+    0x70, 0x70, 0x30, 0x30, 0x30, 0x30, 0x30, 0x70, 0x38, 0x60, 0x1D, 0xE0,
+    0x0F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xE0, 0x3F, 0xE0,
+    0x00, 0xE0, 0x00, 0xC0, 0x00, 0xC0, 0x01, 0xC0, 0x01, 0x80, 0x01, 0x80,
+    0x03, 0x80, 0x03, 0x00, 0x07, 0x00, 0x06, 0x00, 0x06, 0x00, 0x0E, 0x00,
+    0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xC0, 0x3D, 0xE0,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x38, 0xE0, 0x1F, 0xC0, 0x1F, 0xC0,
+    0x38, 0xE0, 0x30, 0x70, 0x70, 0x30, 0x70, 0x70, 0x30, 0x60, 0x3D, 0xE0,
+    0x1F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x80, 0x3D, 0xC0,
+    0x30, 0xE0, 0x70, 0x60, 0x60, 0x60, 0x70, 0x70, 0x70, 0x70, 0x30, 0xF0,
+    0x1F, 0xF0, 0x0F, 0x70, 0x00, 0x60, 0x00, 0x60, 0x00, 0xC0, 0x3F, 0xC0,
+    0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x38, 0x38, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x38, 0x38, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x30, 0x30, 0x20, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00,
+    0x00, 0x1E, 0x00, 0x00, 0xFC, 0x00, 0x07, 0xE0, 0x00, 0x1F, 0x00, 0x00,
+    0x3C, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x07, 0xE0, 0x00, 0x00, 0xF8, 0x00,
+    0x00, 0x1E, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xFE, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xFE, 0x00, 0x1F, 0xFE, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00,
+    0x3E, 0x00, 0x00, 0x0F, 0x80, 0x00, 0x01, 0xF0, 0x00, 0x00, 0x7E, 0x00,
+    0x00, 0x0E, 0x00, 0x00, 0x3E, 0x00, 0x01, 0xF0, 0x00, 0x0F, 0xC0, 0x00,
+    0x3E, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x00, 0x7F, 0x80,
+    0x01, 0x80, 0x01, 0xC0, 0x01, 0x80, 0x03, 0x80, 0x07, 0x00, 0x0E, 0x00,
+    0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x0C, 0x00,
+    0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x30, 0x00, 0x03, 0xFE, 0x00, 0x07, 0x07, 0x00, 0x0C, 0x01, 0x80,
+    0x18, 0x00, 0xC0, 0x30, 0xF6, 0x60, 0x31, 0xFE, 0x60, 0x23, 0x0E, 0x60,
+    0x63, 0x06, 0x20, 0x63, 0x06, 0x60, 0x63, 0x06, 0x60, 0x23, 0x0E, 0x40,
+    0x31, 0xDF, 0xC0, 0x30, 0xF7, 0x00, 0x18, 0x00, 0x00, 0x0C, 0x00, 0x00,
+    0x07, 0x07, 0x00, 0x03, 0xFE, 0x00, 0x00, 0x70, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x80, 0x07, 0x80,
+    0x07, 0x80, 0x0E, 0xC0, 0x0C, 0xC0, 0x0C, 0xC0, 0x18, 0x60, 0x18, 0x60,
+    0x18, 0x70, 0x3F, 0xF0, 0x3F, 0xF0, 0x70, 0x38, 0x60, 0x18, 0x60, 0x18,
+    0xE0, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x3F, 0xE0,
+    0x30, 0x70, 0x30, 0x30, 0x30, 0x30, 0x30, 0x70, 0x3F, 0xE0, 0x3F, 0xE0,
+    0x30, 0x30, 0x30, 0x38, 0x30, 0x38, 0x30, 0x38, 0x30, 0x30, 0x3F, 0xF0,
+    0x3F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xF0, 0x1F, 0xF8,
+    0x18, 0x08, 0x30, 0x00, 0x30, 0x00, 0x70, 0x00, 0x70, 0x00, 0x60, 0x00,
+    0x70, 0x00, 0x70, 0x00, 0x30, 0x00, 0x30, 0x00, 0x18, 0x08, 0x1F, 0xF8,
+    0x07, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x3F, 0xF0,
+    0x30, 0x38, 0x30, 0x1C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0E, 0x30, 0x0E,
+    0x30, 0x0E, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x1C, 0x30, 0x38, 0x3F, 0xF0,
+    0x3F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xF0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xE0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xF0,
+    0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xE0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xC0, 0x3F, 0xC0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xF8, 0x1F, 0xFC,
+    0x18, 0x0C, 0x30, 0x00, 0x30, 0x00, 0x70, 0x00, 0x70, 0x00, 0x60, 0x7C,
+    0x70, 0x7C, 0x70, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x18, 0x0C, 0x1F, 0xFC,
+    0x07, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x1C, 0x30, 0x1C,
+    0x30, 0x1C, 0x30, 0x1C, 0x30, 0x1C, 0x30, 0x1C, 0x3F, 0xFC, 0x3F, 0xFC,
+    0x30, 0x1C, 0x30, 0x1C, 0x30, 0x1C, 0x30, 0x1C, 0x30, 0x1C, 0x30, 0x1C,
+    0x30, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x70, 0xE0, 0xC0, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x38, 0x30, 0x70,
+    0x30, 0xE0, 0x31, 0xC0, 0x33, 0x80, 0x37, 0x00, 0x3E, 0x00, 0x3E, 0x00,
+    0x37, 0x00, 0x33, 0x80, 0x31, 0xC0, 0x30, 0xE0, 0x30, 0x70, 0x30, 0x38,
+    0x30, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xE0,
+    0x3F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x38, 0x0F, 0x00, 0x3C, 0x0F, 0x00, 0x3C, 0x0F, 0x00, 0x34, 0x1B, 0x00,
+    0x36, 0x1B, 0x00, 0x36, 0x1B, 0x00, 0x33, 0x33, 0x00, 0x33, 0x33, 0x00,
+    0x33, 0x63, 0x00, 0x31, 0xE3, 0x00, 0x31, 0xE3, 0x00, 0x30, 0xC3, 0x00,
+    0x30, 0x03, 0x00, 0x30, 0x03, 0x00, 0x30, 0x03, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x18, 0x3C, 0x18,
+    0x3C, 0x18, 0x3E, 0x18, 0x36, 0x18, 0x33, 0x18, 0x33, 0x18, 0x31, 0x98,
+    0x31, 0x98, 0x30, 0xD8, 0x30, 0xD8, 0x30, 0x78, 0x30, 0x78, 0x30, 0x38,
+    0x30, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xE0, 0x1F, 0xF8,
+    0x18, 0x1C, 0x30, 0x0C, 0x30, 0x0E, 0x70, 0x06, 0x70, 0x06, 0x60, 0x06,
+    0x70, 0x06, 0x70, 0x06, 0x30, 0x0E, 0x30, 0x0C, 0x18, 0x1C, 0x1F, 0xF8,
+    0x07, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x80, 0x3F, 0xE0,
+    0x30, 0x60, 0x30, 0x70, 0x30, 0x70, 0x30, 0x70, 0x30, 0x60, 0x3F, 0xE0,
+    0x3F, 0x80, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xE0, 0x1F, 0xF8,
+    0x18, 0x1C, 0x30, 0x0C, 0x30, 0x0E, 0x70, 0x06, 0x70, 0x06, 0x60, 0x06,
+    0x70, 0x06, 0x70, 0x06, 0x30, 0x0E, 0x30, 0x0C, 0x18, 0x1C, 0x1F, 0xF8,
+    0x07, 0xF0, 0x00, 0x70, 0x00, 0x38, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x3F, 0xE0,
+    0x30, 0x60, 0x30, 0x70, 0x30, 0x70, 0x30, 0x70, 0x30, 0xE0, 0x3F, 0xC0,
+    0x3F, 0xC0, 0x30, 0xE0, 0x30, 0x70, 0x30, 0x30, 0x30, 0x38, 0x30, 0x18,
+    0x30, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xE0, 0x3D, 0xE0,
+    0x30, 0x00, 0x70, 0x00, 0x70, 0x00, 0x38, 0x00, 0x3F, 0x00, 0x0F, 0xC0,
+    0x01, 0xE0, 0x00, 0x70, 0x00, 0x30, 0x00, 0x30, 0x00, 0x70, 0x7D, 0xE0,
+    0x3F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xF8, 0xFF, 0xF8,
+    0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00,
+    0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00,
+    0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x38, 0x38, 0x1F, 0xF0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0x0C, 0x60, 0x18,
+    0x60, 0x18, 0x70, 0x38, 0x30, 0x30, 0x30, 0x30, 0x18, 0x70, 0x18, 0x60,
+    0x1C, 0x60, 0x0C, 0xC0, 0x0C, 0xC0, 0x0F, 0xC0, 0x07, 0x80, 0x07, 0x80,
+    0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x60, 0x70, 0x70, 0x60, 0x70, 0x60, 0x60, 0xF0, 0x60, 0x70, 0xF0, 0x60,
+    0x30, 0xD8, 0xE0, 0x30, 0x98, 0xC0, 0x31, 0x98, 0xC0, 0x39, 0x98, 0xC0,
+    0x19, 0x8D, 0xC0, 0x19, 0x0D, 0x80, 0x1B, 0x0D, 0x80, 0x1F, 0x0F, 0x80,
+    0x0F, 0x07, 0x80, 0x0E, 0x07, 0x00, 0x0E, 0x07, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x18, 0x38, 0x30,
+    0x18, 0x70, 0x0C, 0x60, 0x0E, 0xC0, 0x07, 0xC0, 0x03, 0x80, 0x03, 0x80,
+    0x07, 0x80, 0x0E, 0xC0, 0x0C, 0xE0, 0x18, 0x60, 0x38, 0x30, 0x30, 0x38,
+    0x60, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0x30, 0x60, 0x70,
+    0x30, 0x60, 0x38, 0xC0, 0x19, 0xC0, 0x0D, 0x80, 0x0F, 0x00, 0x07, 0x00,
+    0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00,
+    0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xF8, 0x7F, 0xF8,
+    0x00, 0x30, 0x00, 0x60, 0x00, 0xE0, 0x01, 0xC0, 0x03, 0x80, 0x03, 0x00,
+    0x06, 0x00, 0x0E, 0x00, 0x1C, 0x00, 0x38, 0x00, 0x30, 0x00, 0x7F, 0xF8,
+    0x7F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x3C, 0x3C, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x3C, 0x3C, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xC0, 0xC0, 0x60, 0x60, 0x60, 0x30, 0x30, 0x30,
+    0x10, 0x18, 0x18, 0x18, 0x0C, 0x0C, 0x0C, 0x06, 0x06, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x3C, 0x3C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C,
+    0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x3C, 0x3C, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0xC0, 0x00, 0x03, 0xE0, 0x00, 0x07, 0x30, 0x00, 0x0E, 0x18, 0x00,
+    0x1C, 0x0C, 0x00, 0x10, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xC0, 0xFF, 0xC0,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x38, 0x00, 0x18, 0x00, 0x0C, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0E, 0x00, 0x3F, 0x80, 0x21, 0xC0, 0x00, 0xE0, 0x00, 0x60,
+    0x1F, 0xE0, 0x3C, 0x60, 0x70, 0x60, 0x60, 0xE0, 0x70, 0xE0, 0x3B, 0xE0,
+    0x3F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x31, 0x00, 0x37, 0xC0, 0x3C, 0xE0, 0x38, 0x60, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x38, 0x60, 0x3C, 0xE0,
+    0x37, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x03, 0x00, 0x1F, 0xC0, 0x38, 0x40, 0x30, 0x00, 0x70, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x70, 0x00, 0x30, 0x00, 0x3C, 0xC0,
+    0x0F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60,
+    0x00, 0x60, 0x04, 0x60, 0x1F, 0xE0, 0x38, 0xE0, 0x30, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x70, 0x60, 0x30, 0xE0, 0x3D, 0xE0,
+    0x1F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xE0, 0x30, 0x60, 0x60, 0x60,
+    0x7F, 0xF0, 0x7F, 0xF0, 0x60, 0x00, 0x70, 0x00, 0x30, 0x00, 0x1C, 0xE0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x0E, 0x1E, 0x38, 0x30, 0x30, 0xFE, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x04, 0x00, 0x1F, 0xE0, 0x38, 0xE0, 0x30, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x70, 0x60, 0x30, 0xE0, 0x3F, 0xE0,
+    0x0F, 0x60, 0x00, 0x60, 0x00, 0x60, 0x39, 0xC0, 0x1F, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x31, 0x00, 0x37, 0xC0, 0x3C, 0xE0, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x60, 0xE0, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0xE0, 0x31, 0xC0, 0x33, 0x80, 0x36, 0x00,
+    0x3C, 0x00, 0x3C, 0x00, 0x36, 0x00, 0x33, 0x00, 0x31, 0x80, 0x30, 0xC0,
+    0x30, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00,
+    0x37, 0xCF, 0x80, 0x38, 0xF9, 0xC0, 0x30, 0x70, 0xC0, 0x30, 0x60, 0xC0,
+    0x30, 0x60, 0xC0, 0x30, 0x60, 0xC0, 0x30, 0x60, 0xC0, 0x30, 0x60, 0xC0,
+    0x30, 0x60, 0xC0, 0x30, 0x60, 0xC0, 0x30, 0x60, 0xC0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x01, 0x00, 0x37, 0xC0, 0x3C, 0xE0, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xC0, 0x30, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x70, 0x60, 0x60, 0x70, 0x60, 0x30, 0x60, 0x3D, 0xC0,
+    0x1F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x01, 0x00, 0x37, 0xC0, 0x3C, 0xE0, 0x38, 0x60, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x38, 0x60, 0x3C, 0xE0,
+    0x37, 0xC0, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x04, 0x00, 0x1F, 0xE0, 0x38, 0xE0, 0x30, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x70, 0x60, 0x30, 0xE0, 0x3D, 0xE0,
+    0x1F, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x37, 0x3C, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0E, 0x00, 0x3F, 0x80, 0x70, 0x80, 0x60, 0x00, 0x70, 0x00,
+    0x3C, 0x00, 0x1F, 0x80, 0x03, 0x80, 0x01, 0xC0, 0x01, 0xC0, 0x73, 0x80,
+    0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x20, 0x30, 0x30, 0x30, 0xFF, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x3E, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0xE0, 0x3D, 0xE0,
+    0x1F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x60, 0x60, 0x60, 0x60, 0x30, 0xE0, 0x30, 0xC0,
+    0x30, 0xC0, 0x19, 0xC0, 0x19, 0x80, 0x1D, 0x80, 0x0F, 0x00, 0x0F, 0x00,
+    0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x61, 0xC3, 0x00, 0x61, 0xC3, 0x00, 0x71, 0xC6, 0x00, 0x33, 0x66, 0x00,
+    0x33, 0x66, 0x00, 0x33, 0x66, 0x00, 0x1E, 0x2C, 0x00, 0x1E, 0x3C, 0x00,
+    0x1E, 0x3C, 0x00, 0x1C, 0x3C, 0x00, 0x0C, 0x18, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x70, 0xE0, 0x38, 0xC0, 0x19, 0x80, 0x0F, 0x80,
+    0x0F, 0x00, 0x07, 0x00, 0x0F, 0x00, 0x19, 0x80, 0x39, 0xC0, 0x30, 0xE0,
+    0x60, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x60, 0x60, 0x60, 0x60, 0x30, 0xE0, 0x30, 0xC0,
+    0x38, 0xC0, 0x19, 0x80, 0x19, 0x80, 0x0F, 0x80, 0x0F, 0x00, 0x07, 0x00,
+    0x06, 0x00, 0x06, 0x00, 0x0E, 0x00, 0x1C, 0x00, 0x38, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x7F, 0xC0, 0x01, 0xC0, 0x01, 0x80, 0x03, 0x00,
+    0x06, 0x00, 0x0E, 0x00, 0x1C, 0x00, 0x38, 0x00, 0x70, 0x00, 0x7F, 0xC0,
+    0x7F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x03, 0xE0, 0x03, 0x00,
+    0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x07, 0x00, 0x06, 0x00,
+    0x1C, 0x00, 0x1E, 0x00, 0x06, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00,
+    0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0xE0, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10,
+    0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x1E, 0x00, 0x06, 0x00,
+    0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x07, 0x00, 0x03, 0x00, 0x03, 0x00,
+    0x01, 0xE0, 0x03, 0xC0, 0x03, 0x00, 0x07, 0x00, 0x07, 0x00, 0x06, 0x00,
+    0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x3E, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x02, 0x00,
+    0x3F, 0xFE, 0x00, 0x30, 0x7C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x18, 0x00, 0x00,
+    0x08, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+    0x01, 0x00, 0x03, 0x80, 0x0F, 0xE0, 0x1D, 0x20, 0x39, 0x00, 0x31, 0x00,
+    0x31, 0x00, 0x31, 0x00, 0x31, 0x00, 0x31, 0x00, 0x39, 0x00, 0x1F, 0xE0,
+    0x0F, 0xE0, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xE0, 0x0F, 0x60,
+    0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x3F, 0xC0,
+    0x3F, 0x80, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x7F, 0xE0,
+    0x7F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x60, 0x30, 0x77, 0x70, 0x3F, 0xE0, 0x18, 0xC0, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x18, 0xC0, 0x3F, 0xE0, 0x77, 0x70, 0x60, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x30, 0x30, 0x70,
+    0x30, 0x60, 0x18, 0xC0, 0x18, 0xC0, 0x0D, 0x80, 0x7F, 0xF0, 0x07, 0x00,
+    0x07, 0x00, 0x7F, 0xF0, 0x3F, 0xE0, 0x07, 0x00, 0x07, 0x00, 0x07, 0x00,
+    0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10,
+    0x00, 0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x39, 0x00,
+    0x30, 0x00, 0x30, 0x00, 0x38, 0x00, 0x3E, 0x00, 0x67, 0x00, 0x61, 0x80,
+    0x61, 0x80, 0x31, 0x80, 0x1D, 0x80, 0x0F, 0x00, 0x03, 0x00, 0x01, 0x80,
+    0x03, 0x80, 0x3F, 0x00, 0x3E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x33, 0x00, 0x33, 0x00, 0x11, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xF8, 0x00, 0x03, 0x8E, 0x00, 0x06, 0x03, 0x00, 0x0C, 0xF9, 0x80,
+    0x19, 0xC8, 0x80, 0x13, 0x00, 0x80, 0x13, 0x00, 0xC0, 0x13, 0x00, 0x40,
+    0x13, 0x00, 0xC0, 0x13, 0x00, 0x80, 0x19, 0x88, 0x80, 0x08, 0xF9, 0x80,
+    0x06, 0x03, 0x00, 0x03, 0x0E, 0x00, 0x01, 0xF8, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3E, 0x00, 0x03, 0x00,
+    0x03, 0x00, 0x3F, 0x00, 0x61, 0x00, 0x63, 0x00, 0x77, 0x00, 0x3D, 0x00,
+    0x00, 0x00, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x60, 0x0C, 0xC0, 0x19, 0x80,
+    0x33, 0x00, 0x33, 0x00, 0x19, 0x80, 0x0C, 0xE0, 0x04, 0x60, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xFE, 0x00, 0x3F, 0xFE, 0x00,
+    0x00, 0x06, 0x00, 0x00, 0x06, 0x00, 0x00, 0x06, 0x00, 0x00, 0x06, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x7C, 0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xF8, 0x00, 0x03, 0x8E, 0x00, 0x06, 0x03, 0x00, 0x0D, 0xF1, 0x80,
+    0x19, 0x98, 0x80, 0x11, 0x8C, 0x80, 0x11, 0x98, 0xC0, 0x11, 0xF0, 0x40,
+    0x11, 0xB0, 0xC0, 0x11, 0x98, 0x80, 0x19, 0x8C, 0x80, 0x09, 0x85, 0x80,
+    0x06, 0x03, 0x00, 0x03, 0x0E, 0x00, 0x01, 0xF8, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x00, 0x33, 0x00,
+    0x31, 0x00, 0x31, 0x00, 0x3B, 0x00, 0x1E, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00,
+    0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00, 0x3F, 0xFE, 0x00, 0x3F, 0xFE, 0x00,
+    0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x3F, 0xFE, 0x00, 0x3F, 0xFE, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x7C, 0x06, 0x06, 0x04, 0x0C, 0x18, 0x30, 0x7E,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x7C, 0x06, 0x06, 0x1C, 0x0E, 0x06, 0x06, 0x7E,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x06, 0x00, 0x0C, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x3D, 0xF0,
+    0x3F, 0xB8, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0xE0, 0x1F, 0x60,
+    0x3F, 0x60, 0x3F, 0x60, 0x3F, 0x60, 0x3F, 0x60, 0x3F, 0x60, 0x1F, 0x60,
+    0x03, 0x60, 0x03, 0x60, 0x03, 0x60, 0x03, 0x60, 0x03, 0x60, 0x03, 0x60,
+    0x03, 0x60, 0x03, 0x60, 0x03, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x30,
+    0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x04, 0x00, 0x06, 0x00, 0x16, 0x00, 0x1C, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x78, 0x18, 0x08, 0x08, 0x08, 0x08, 0x08, 0x3E,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3E, 0x00, 0x33, 0x00,
+    0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x61, 0x80, 0x33, 0x00, 0x1E, 0x00,
+    0x00, 0x00, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23, 0x00, 0x31, 0x80, 0x1C, 0xC0,
+    0x0E, 0x60, 0x0E, 0x60, 0x1D, 0xC0, 0x33, 0x80, 0x23, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x78, 0x06, 0x00, 0x18, 0x04, 0x00, 0x08, 0x0C, 0x00, 0x08, 0x18, 0x00,
+    0x08, 0x18, 0x00, 0x08, 0x30, 0x00, 0x08, 0x20, 0x00, 0x3E, 0x60, 0xC0,
+    0x00, 0xC1, 0xC0, 0x00, 0xC2, 0xC0, 0x01, 0x86, 0xC0, 0x01, 0x04, 0xC0,
+    0x03, 0x0F, 0xE0, 0x06, 0x00, 0xC0, 0x06, 0x00, 0xC0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x78, 0x06, 0x00, 0x18, 0x04, 0x00, 0x08, 0x0C, 0x00, 0x08, 0x18, 0x00,
+    0x08, 0x18, 0x00, 0x08, 0x30, 0x00, 0x08, 0x23, 0x80, 0x3E, 0x67, 0xC0,
+    0x00, 0xC0, 0x60, 0x00, 0xC0, 0x40, 0x01, 0x80, 0xC0, 0x01, 0x01, 0x80,
+    0x03, 0x03, 0x00, 0x06, 0x06, 0x00, 0x06, 0x07, 0xE0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x7C, 0x06, 0x00, 0x06, 0x04, 0x00, 0x06, 0x0C, 0x00, 0x1C, 0x18, 0x00,
+    0x0E, 0x18, 0x00, 0x06, 0x30, 0x00, 0x06, 0x20, 0x00, 0x7E, 0x60, 0xC0,
+    0x10, 0xC1, 0xC0, 0x00, 0xC2, 0xC0, 0x01, 0x86, 0xC0, 0x01, 0x04, 0xC0,
+    0x03, 0x0F, 0xE0, 0x06, 0x00, 0xC0, 0x06, 0x00, 0xC0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x0C, 0x00, 0x1C, 0x00, 0x38, 0x00,
+    0x30, 0x00, 0x70, 0x00, 0x30, 0x80, 0x3F, 0xC0, 0x1F, 0x00, 0x00, 0x00,
+    0x06, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x80, 0x07, 0x80,
+    0x07, 0x80, 0x0E, 0xC0, 0x0C, 0xC0, 0x0C, 0xC0, 0x18, 0x60, 0x18, 0x60,
+    0x18, 0x70, 0x3F, 0xF0, 0x3F, 0xF0, 0x70, 0x38, 0x60, 0x18, 0x60, 0x18,
+    0xE0, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x80, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x80, 0x07, 0x80,
+    0x07, 0x80, 0x0E, 0xC0, 0x0C, 0xC0, 0x0C, 0xC0, 0x18, 0x60, 0x18, 0x60,
+    0x18, 0x70, 0x3F, 0xF0, 0x3F, 0xF0, 0x70, 0x38, 0x60, 0x18, 0x60, 0x18,
+    0xE0, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x07, 0x80, 0x04, 0x80, 0x08, 0x40, 0x00, 0x00, 0x03, 0x80, 0x07, 0x80,
+    0x07, 0x80, 0x0E, 0xC0, 0x0C, 0xC0, 0x0C, 0xC0, 0x18, 0x60, 0x18, 0x60,
+    0x18, 0x70, 0x3F, 0xF0, 0x3F, 0xF0, 0x70, 0x38, 0x60, 0x18, 0x60, 0x18,
+    0xE0, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0E, 0x40, 0x0B, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x03, 0x80, 0x07, 0x80,
+    0x07, 0x80, 0x0E, 0xC0, 0x0C, 0xC0, 0x0C, 0xC0, 0x18, 0x60, 0x18, 0x60,
+    0x18, 0x70, 0x3F, 0xF0, 0x3F, 0xF0, 0x70, 0x38, 0x60, 0x18, 0x60, 0x18,
+    0xE0, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0C, 0xC0, 0x0C, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x03, 0x80, 0x07, 0x80,
+    0x07, 0x80, 0x0E, 0xC0, 0x0C, 0xC0, 0x0C, 0xC0, 0x18, 0x60, 0x18, 0x60,
+    0x18, 0x70, 0x3F, 0xF0, 0x3F, 0xF0, 0x70, 0x38, 0x60, 0x18, 0x60, 0x18,
+    0xE0, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x07, 0x80, 0x0C, 0xC0, 0x0C, 0xC0, 0x0C, 0xC0, 0x07, 0x80, 0x07, 0x80,
+    0x07, 0x80, 0x0E, 0xC0, 0x0C, 0xC0, 0x0C, 0xC0, 0x18, 0x60, 0x18, 0x60,
+    0x18, 0x70, 0x3F, 0xF0, 0x3F, 0xF0, 0x70, 0x38, 0x60, 0x18, 0x60, 0x18,
+    0xE0, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0xFF, 0xE0, 0x03, 0xFF, 0xC0, 0x06, 0x60, 0x00, 0x06, 0x60, 0x00,
+    0x0E, 0x60, 0x00, 0x0C, 0x60, 0x00, 0x0C, 0x7F, 0xC0, 0x18, 0x7F, 0xC0,
+    0x18, 0x60, 0x00, 0x3F, 0xE0, 0x00, 0x3F, 0xE0, 0x00, 0x70, 0x60, 0x00,
+    0x60, 0x60, 0x00, 0x60, 0x7F, 0xE0, 0xC0, 0x7F, 0xE0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xF0, 0x1F, 0xF8,
+    0x18, 0x08, 0x30, 0x00, 0x30, 0x00, 0x70, 0x00, 0x70, 0x00, 0x60, 0x00,
+    0x70, 0x00, 0x70, 0x00, 0x30, 0x00, 0x30, 0x00, 0x18, 0x08, 0x1F, 0xF8,
+    0x07, 0xF0, 0x00, 0xC0, 0x00, 0x40, 0x02, 0xC0, 0x03, 0x80, 0x00, 0x00,
+    0x06, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3F, 0xF0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xE0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xF0,
+    0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x80, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, 0x3F, 0xF0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xE0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xF0,
+    0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x07, 0x00, 0x0D, 0x80, 0x08, 0x80, 0x00, 0x00, 0x3F, 0xF0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xE0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xF0,
+    0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0C, 0xC0, 0x0C, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xF0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xE0, 0x3F, 0xE0,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x3F, 0xF0,
+    0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x60, 0x30, 0x10, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x18, 0x30, 0x20, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x78, 0x48, 0x84, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xCC, 0xCC, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xC0, 0x3F, 0xF0,
+    0x30, 0x38, 0x30, 0x1C, 0x30, 0x0C, 0x30, 0x0E, 0xFE, 0x0E, 0xFF, 0x0E,
+    0x30, 0x0E, 0x30, 0x0E, 0x30, 0x0C, 0x30, 0x1C, 0x30, 0x38, 0x3F, 0xF0,
+    0x3F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x07, 0x60, 0x05, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x38, 0x18, 0x3C, 0x18,
+    0x3C, 0x18, 0x3E, 0x18, 0x36, 0x18, 0x33, 0x18, 0x33, 0x18, 0x31, 0x98,
+    0x31, 0x98, 0x30, 0xD8, 0x30, 0xD8, 0x30, 0x78, 0x30, 0x78, 0x30, 0x38,
+    0x30, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0x00, 0x01, 0x80, 0x00, 0x80, 0x00, 0x00, 0x07, 0xE0, 0x1F, 0xF8,
+    0x18, 0x1C, 0x30, 0x0C, 0x30, 0x0E, 0x70, 0x06, 0x70, 0x06, 0x60, 0x06,
+    0x70, 0x06, 0x70, 0x06, 0x30, 0x0E, 0x30, 0x0C, 0x18, 0x1C, 0x1F, 0xF8,
+    0x07, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xC0, 0x01, 0x80, 0x01, 0x00, 0x00, 0x00, 0x07, 0xE0, 0x1F, 0xF8,
+    0x18, 0x1C, 0x30, 0x0C, 0x30, 0x0E, 0x70, 0x06, 0x70, 0x06, 0x60, 0x06,
+    0x70, 0x06, 0x70, 0x06, 0x30, 0x0E, 0x30, 0x0C, 0x18, 0x1C, 0x1F, 0xF8,
+    0x07, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0xC0, 0x03, 0x40, 0x04, 0x20, 0x00, 0x00, 0x07, 0xE0, 0x1F, 0xF8,
+    0x18, 0x1C, 0x30, 0x0C, 0x30, 0x0E, 0x70, 0x06, 0x70, 0x06, 0x60, 0x06,
+    0x70, 0x06, 0x70, 0x06, 0x30, 0x0E, 0x30, 0x0C, 0x18, 0x1C, 0x1F, 0xF8,
+    0x07, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0x20, 0x07, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x07, 0xE0, 0x1F, 0xF8,
+    0x18, 0x1C, 0x30, 0x0C, 0x30, 0x0E, 0x70, 0x06, 0x70, 0x06, 0x60, 0x06,
+    0x70, 0x06, 0x70, 0x06, 0x30, 0x0E, 0x30, 0x0C, 0x18, 0x1C, 0x1F, 0xF8,
+    0x07, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x06, 0x60, 0x06, 0x60, 0x00, 0x00, 0x00, 0x00, 0x07, 0xE0, 0x1F, 0xF8,
+    0x18, 0x1C, 0x30, 0x0C, 0x30, 0x0E, 0x70, 0x06, 0x70, 0x06, 0x60, 0x06,
+    0x70, 0x06, 0x70, 0x06, 0x30, 0x0E, 0x30, 0x0C, 0x18, 0x1C, 0x1F, 0xF8,
+    0x07, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x0C, 0x00,
+    0x1C, 0x1C, 0x00, 0x0E, 0x38, 0x00, 0x07, 0x70, 0x00, 0x03, 0xE0, 0x00,
+    0x01, 0xC0, 0x00, 0x03, 0xE0, 0x00, 0x07, 0x70, 0x00, 0x0E, 0x38, 0x00,
+    0x1C, 0x1C, 0x00, 0x18, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xE6, 0x1F, 0xFC,
+    0x18, 0x1C, 0x30, 0x1C, 0x30, 0x3E, 0x70, 0x66, 0x70, 0xC6, 0x61, 0x86,
+    0x63, 0x06, 0x76, 0x06, 0x36, 0x0E, 0x3C, 0x0C, 0x38, 0x1C, 0x3F, 0xF8,
+    0x67, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0x00, 0x03, 0x00, 0x01, 0x80, 0x00, 0x00, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x38, 0x38, 0x1F, 0xF0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xC0, 0x01, 0x80, 0x01, 0x00, 0x00, 0x00, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x38, 0x38, 0x1F, 0xF0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0x80, 0x06, 0xC0, 0x04, 0x40, 0x00, 0x00, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x38, 0x38, 0x1F, 0xF0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x06, 0xE0, 0x0E, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18,
+    0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x30, 0x18, 0x38, 0x38, 0x1F, 0xF0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0x00, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00, 0xE0, 0x30, 0x60, 0x70,
+    0x30, 0x60, 0x38, 0xC0, 0x19, 0xC0, 0x0D, 0x80, 0x0F, 0x00, 0x07, 0x00,
+    0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00, 0x06, 0x00,
+    0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x38, 0x00, 0x3F, 0xC0, 0x31, 0xE0, 0x30, 0x70, 0x30, 0x70, 0x30, 0x70,
+    0x30, 0x70, 0x30, 0xE0, 0x3F, 0xC0, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x1F, 0xC0, 0x38, 0xC0,
+    0x30, 0x60, 0x31, 0xE0, 0x33, 0x80, 0x33, 0x00, 0x33, 0x00, 0x33, 0x80,
+    0x31, 0xC0, 0x30, 0xE0, 0x30, 0x70, 0x30, 0x30, 0x30, 0x30, 0x36, 0xF0,
+    0x37, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x18, 0x00, 0x0C, 0x00, 0x06, 0x00,
+    0x00, 0x00, 0x0E, 0x00, 0x3F, 0x80, 0x21, 0xC0, 0x00, 0xE0, 0x00, 0x60,
+    0x1F, 0xE0, 0x3C, 0x60, 0x70, 0x60, 0x60, 0xE0, 0x70, 0xE0, 0x3B, 0xE0,
+    0x3F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01, 0x80, 0x03, 0x00, 0x06, 0x00,
+    0x00, 0x00, 0x0E, 0x00, 0x3F, 0x80, 0x21, 0xC0, 0x00, 0xE0, 0x00, 0x60,
+    0x1F, 0xE0, 0x3C, 0x60, 0x70, 0x60, 0x60, 0xE0, 0x70, 0xE0, 0x3B, 0xE0,
+    0x3F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0E, 0x00, 0x0F, 0x00, 0x19, 0x80,
+    0x00, 0x00, 0x0E, 0x00, 0x3F, 0x80, 0x21, 0xC0, 0x00, 0xE0, 0x00, 0x60,
+    0x1F, 0xE0, 0x3C, 0x60, 0x70, 0x60, 0x60, 0xE0, 0x70, 0xE0, 0x3B, 0xE0,
+    0x3F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x80, 0x1F, 0x80, 0x13, 0x00,
+    0x00, 0x00, 0x0E, 0x00, 0x3F, 0x80, 0x21, 0xC0, 0x00, 0xE0, 0x00, 0x60,
+    0x1F, 0xE0, 0x3C, 0x60, 0x70, 0x60, 0x60, 0xE0, 0x70, 0xE0, 0x3B, 0xE0,
+    0x3F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0x80, 0x19, 0x80, 0x19, 0x80,
+    0x00, 0x00, 0x0E, 0x00, 0x3F, 0x80, 0x21, 0xC0, 0x00, 0xE0, 0x00, 0x60,
+    0x1F, 0xE0, 0x3C, 0x60, 0x70, 0x60, 0x60, 0xE0, 0x70, 0xE0, 0x3B, 0xE0,
+    0x3F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0F, 0x00, 0x19, 0x80, 0x19, 0x80, 0x19, 0x80, 0x0F, 0x00,
+    0x00, 0x00, 0x0E, 0x00, 0x3F, 0x80, 0x21, 0xC0, 0x00, 0xE0, 0x00, 0x60,
+    0x1F, 0xE0, 0x3C, 0x60, 0x70, 0x60, 0x60, 0xE0, 0x70, 0xE0, 0x3B, 0xE0,
+    0x3F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0E, 0x06, 0x00,
+    0x3F, 0x9F, 0x80, 0x21, 0xF8, 0xC0, 0x00, 0xE0, 0x60, 0x00, 0x60, 0x60,
+    0x1F, 0xFF, 0xE0, 0x3C, 0x7F, 0xE0, 0x70, 0x60, 0x00, 0x60, 0xE0, 0x00,
+    0x70, 0xF0, 0x00, 0x3B, 0xBC, 0xE0, 0x3F, 0x1F, 0xC0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x03, 0x00, 0x1F, 0xC0, 0x38, 0x40, 0x30, 0x00, 0x70, 0x00,
+    0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x70, 0x00, 0x30, 0x00, 0x3C, 0xC0,
+    0x0F, 0xC0, 0x03, 0x00, 0x01, 0x00, 0x03, 0x00, 0x0F, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0C, 0x00, 0x06, 0x00, 0x02, 0x00,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xE0, 0x30, 0x60, 0x60, 0x60,
+    0x7F, 0xF0, 0x7F, 0xF0, 0x60, 0x00, 0x70, 0x00, 0x30, 0x00, 0x1C, 0xE0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01, 0x80, 0x03, 0x00, 0x02, 0x00,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xE0, 0x30, 0x60, 0x60, 0x60,
+    0x7F, 0xF0, 0x7F, 0xF0, 0x60, 0x00, 0x70, 0x00, 0x30, 0x00, 0x1C, 0xE0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x07, 0x00, 0x0D, 0x80, 0x08, 0x80,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xE0, 0x30, 0x60, 0x60, 0x60,
+    0x7F, 0xF0, 0x7F, 0xF0, 0x60, 0x00, 0x70, 0x00, 0x30, 0x00, 0x1C, 0xE0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x80, 0x1D, 0xC0, 0x08, 0x80,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xE0, 0x30, 0x60, 0x60, 0x60,
+    0x7F, 0xF0, 0x7F, 0xF0, 0x60, 0x00, 0x70, 0x00, 0x30, 0x00, 0x1C, 0xE0,
+    0x0F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x80, 0xC0, 0x60, 0x30, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0C, 0x1C, 0x18, 0x30, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x20, 0x70, 0x58, 0xCC, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0xCC, 0xCC, 0xC8, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x0F, 0xC0, 0x0F, 0x00,
+    0x3B, 0x00, 0x01, 0x80, 0x0F, 0xC0, 0x1F, 0xC0, 0x30, 0x60, 0x70, 0x60,
+    0x60, 0x60, 0x60, 0x70, 0x60, 0x60, 0x70, 0x60, 0x30, 0x60, 0x3D, 0xC0,
+    0x0F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0E, 0x40, 0x0B, 0xC0, 0x19, 0x80,
+    0x00, 0x00, 0x01, 0x00, 0x37, 0xC0, 0x3C, 0xE0, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x0C, 0x00, 0x04, 0x00, 0x06, 0x00,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xC0, 0x30, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x70, 0x60, 0x60, 0x70, 0x60, 0x30, 0x60, 0x3D, 0xC0,
+    0x1F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01, 0x80, 0x03, 0x00, 0x06, 0x00,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xC0, 0x30, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x70, 0x60, 0x60, 0x70, 0x60, 0x30, 0x60, 0x3D, 0xC0,
+    0x1F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x07, 0x00, 0x0D, 0x00, 0x09, 0x80,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xC0, 0x30, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x70, 0x60, 0x60, 0x70, 0x60, 0x30, 0x60, 0x3D, 0xC0,
+    0x1F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xC0, 0x1F, 0x80, 0x11, 0x80,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xC0, 0x30, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x70, 0x60, 0x60, 0x70, 0x60, 0x30, 0x60, 0x3D, 0xC0,
+    0x1F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0x80, 0x19, 0x80, 0x08, 0x80,
+    0x00, 0x00, 0x02, 0x00, 0x1F, 0xC0, 0x38, 0xC0, 0x30, 0x60, 0x60, 0x60,
+    0x60, 0x60, 0x60, 0x70, 0x60, 0x60, 0x70, 0x60, 0x30, 0x60, 0x3D, 0xC0,
+    0x1F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00,
+    0x01, 0xC0, 0x00, 0x01, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x3F, 0xFE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xC0, 0x00,
+    0x01, 0xC0, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x02, 0x30, 0x1F, 0xE0, 0x38, 0xE0, 0x30, 0xE0, 0x61, 0xE0,
+    0x63, 0x60, 0x66, 0x70, 0x6C, 0x60, 0x78, 0x60, 0x38, 0x60, 0x3D, 0xC0,
+    0x7F, 0x80, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x0C, 0x00, 0x06, 0x00, 0x06, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0xE0, 0x3D, 0xE0,
+    0x1F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01, 0x80, 0x03, 0x00, 0x06, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0xE0, 0x3D, 0xE0,
+    0x1F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x07, 0x00, 0x0D, 0x80, 0x08, 0x80,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0xE0, 0x3D, 0xE0,
+    0x1F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x80, 0x1D, 0x80, 0x08, 0x80,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60,
+    0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0x60, 0x30, 0xE0, 0x3D, 0xE0,
+    0x1F, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01, 0x80, 0x03, 0x00, 0x06, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x60, 0x60, 0x60, 0x60, 0x30, 0xE0, 0x30, 0xC0,
+    0x38, 0xC0, 0x19, 0x80, 0x19, 0x80, 0x0F, 0x80, 0x0F, 0x00, 0x07, 0x00,
+    0x06, 0x00, 0x06, 0x00, 0x0E, 0x00, 0x1C, 0x00, 0x38, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x30, 0x00, 0x31, 0x00, 0x37, 0xC0, 0x3C, 0xE0, 0x38, 0x60, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x38, 0x60, 0x3C, 0xE0,
+    0x37, 0xC0, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0x80, 0x19, 0x80, 0x09, 0x80,
+    0x00, 0x00, 0x00, 0x00, 0x60, 0x60, 0x60, 0x60, 0x30, 0xE0, 0x30, 0xC0,
+    0x38, 0xC0, 0x19, 0x80, 0x19, 0x80, 0x0F, 0x80, 0x0F, 0x00, 0x07, 0x00,
+    0x06, 0x00, 0x06, 0x00, 0x0E, 0x00, 0x1C, 0x00, 0x38, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x7F, 0xE0, 0x40, 0x20, 0x40, 0x20, 0x40, 0x20, 0x40, 0x20,
+    0x40, 0x20, 0x40, 0x20, 0x40, 0x20, 0x40, 0x20, 0x40, 0x20, 0x40, 0x20,
+    0x40, 0x20, 0x40, 0x20, 0x40, 0x20, 0x40, 0x20, 0x40, 0x20, 0x40, 0x20,
+    0x7F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
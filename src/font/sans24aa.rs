@@ -0,0 +1,1260 @@
+// Generated by tools/bdf2rust from DejaVuSans.ttf, do not edit.
+// The font's license is in LICENSE-DejaVu.
+
+use super::BitmapFont;
+
+pub const SANS_24_AA: BitmapFont = BitmapFont {
+    height: 24,
+    baseline: 19,
+    first: 32,
+    count: 95,
+    bpp: 4,
+    widths: &SANS_24_AA_WIDTHS,
+    offsets: &SANS_24_AA_OFFSETS,
+    data: &SANS_24_AA_DATA,
+};
+
+pub const SANS_24_AA_WIDTHS: [u8; 95] = [
+    7, 8, 9, 17, 13, 20, 16, 6, 8, 8, 10, 17,
+    7, 7, 7, 7, 13, 13, 13, 13, 13, 13, 13, 13,
+    13, 13, 7, 7, 17, 17, 17, 11, 21, 14, 14, 14,
+    16, 13, 12, 16, 16, 6, 6, 14, 11, 18, 15, 16,
+    12, 16, 14, 13, 13, 15, 14, 20, 14, 13, 14, 8,
+    7, 8, 17, 10, 10, 13, 13, 11, 13, 13, 7, 13,
+    13, 6, 6, 12, 6, 20, 13, 13, 13, 13, 8, 11,
+    8, 13, 12, 17, 12, 12, 11, 13, 7, 13, 17,
+];
+
+pub const SANS_24_AA_OFFSETS: [u16; 95] = [
+    0, 96, 192, 312, 528, 696, 936, 1128, 1200, 1296, 1392, 1512,
+    1728, 1824, 1920, 2016, 2112, 2280, 2448, 2616, 2784, 2952, 3120, 3288,
+    3456, 3624, 3792, 3888, 3984, 4200, 4416, 4632, 4776, 5040, 5208, 5376,
+    5544, 5736, 5904, 6048, 6240, 6432, 6504, 6576, 6744, 6888, 7104, 7296,
+    7488, 7632, 7824, 7992, 8160, 8328, 8520, 8688, 8928, 9096, 9264, 9432,
+    9528, 9624, 9720, 9936, 10056, 10176, 10344, 10512, 10656, 10824, 10992, 11088,
+    11256, 11424, 11496, 11568, 11712, 11784, 12024, 12192, 12360, 12528, 12696, 12792,
+    12936, 13032, 13200, 13344, 13560, 13704, 13848, 13992, 14160, 14256, 14424,
+];
+
+pub const SANS_24_AA_DATA: [u8; 14640] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0D, 0xF2, 0x00, 0x00, 0x0D, 0xF2, 0x00,
+    0x00, 0x0D, 0xF2, 0x00, 0x00, 0x0D, 0xF2, 0x00, 0x00, 0x0D, 0xF2, 0x00,
+    0x00, 0x0D, 0xF2, 0x00, 0x00, 0x0D, 0xF2, 0x00, 0x00, 0x0D, 0xF2, 0x00,
+    0x00, 0x0C, 0xF1, 0x00, 0x00, 0x0B, 0xF0, 0x00, 0x00, 0x02, 0x30, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x81, 0x00, 0x00, 0x0D, 0xF2, 0x00,
+    0x00, 0x0D, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFA, 0x03, 0xF7,
+    0x00, 0x00, 0xFA, 0x03, 0xF7, 0x00, 0x00, 0xFA, 0x03, 0xF7, 0x00, 0x00,
+    0xFA, 0x03, 0xF7, 0x00, 0x00, 0xFA, 0x03, 0xF7, 0x00, 0x00, 0x86, 0x02,
+    0x84, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x08, 0xB0, 0x01, 0xC6, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0E, 0xB0, 0x05, 0xF4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2F, 0x70, 0x09,
+    0xF1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6F, 0x30, 0x0D, 0xC0, 0x00, 0x00,
+    0x00, 0x29, 0x99, 0xDF, 0xA9, 0xAF, 0xD9, 0x97, 0x00, 0x00, 0x4E, 0xEE,
+    0xFF, 0xEE, 0xFF, 0xEE, 0xEA, 0x00, 0x00, 0x00, 0x02, 0xF7, 0x00, 0x9F,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x06, 0xF3, 0x00, 0xDC, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0A, 0xE0, 0x01, 0xF8, 0x00, 0x00, 0x00, 0x04, 0xAA, 0xAE,
+    0xEA, 0xAB, 0xFC, 0xAA, 0x60, 0x00, 0x05, 0xCC, 0xDF, 0xDC, 0xCE, 0xFC,
+    0xCC, 0x70, 0x00, 0x00, 0x00, 0x6F, 0x30, 0x0D, 0xC0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xAE, 0x00, 0x1F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0xEB,
+    0x00, 0x5F, 0x40, 0x00, 0x00, 0x00, 0x00, 0x02, 0xF7, 0x00, 0x9F, 0x10,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xA0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xE0, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x14, 0xF4, 0x20, 0x00, 0x00, 0x00, 0x19, 0xFF, 0xFF, 0xFE, 0x60,
+    0x00, 0x00, 0xBF, 0xA5, 0xF3, 0x6A, 0x70, 0x00, 0x02, 0xFD, 0x01, 0xE0,
+    0x00, 0x00, 0x00, 0x04, 0xFB, 0x01, 0xE0, 0x00, 0x00, 0x00, 0x01, 0xFF,
+    0x51, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xFE, 0xF7, 0x30, 0x00, 0x00,
+    0x00, 0x03, 0x9D, 0xFF, 0xFC, 0x30, 0x00, 0x00, 0x00, 0x01, 0xF6, 0xCF,
+    0xD1, 0x00, 0x00, 0x00, 0x01, 0xE0, 0x0C, 0xF4, 0x00, 0x00, 0x00, 0x01,
+    0xE0, 0x09, 0xF5, 0x00, 0x03, 0x60, 0x01, 0xE0, 0x1E, 0xF2, 0x00, 0x04,
+    0xFD, 0xA8, 0xFA, 0xFF, 0x80, 0x00, 0x01, 0x5A, 0xCE, 0xFD, 0xA4, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xE0,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x42, 0x00, 0x00, 0x00,
+    0x02, 0x51, 0x00, 0x00, 0x00, 0x8F, 0xFF, 0xA1, 0x00, 0x00, 0x0C, 0xC0,
+    0x00, 0x00, 0x05, 0xF8, 0x15, 0xF8, 0x00, 0x00, 0x6F, 0x30, 0x00, 0x00,
+    0x0A, 0xF1, 0x00, 0xDE, 0x00, 0x01, 0xE9, 0x00, 0x00, 0x00, 0x0C, 0xC0,
+    0x00, 0x9F, 0x10, 0x09, 0xE1, 0x00, 0x00, 0x00, 0x0B, 0xE0, 0x00, 0xAF,
+    0x00, 0x3F, 0x60, 0x00, 0x00, 0x00, 0x09, 0xF2, 0x00, 0xEC, 0x00, 0xCC,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0xEC, 0x7B, 0xF4, 0x06, 0xF3, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x2A, 0xCB, 0x40, 0x1E, 0x90, 0x08, 0xCC, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x9E, 0x10, 0xAF, 0x88, 0xFA, 0x00, 0x00, 0x00,
+    0x00, 0x03, 0xF6, 0x03, 0xF8, 0x00, 0x9F, 0x20, 0x00, 0x00, 0x00, 0x0C,
+    0xC0, 0x06, 0xF4, 0x00, 0x5F, 0x50, 0x00, 0x00, 0x00, 0x6F, 0x30, 0x07,
+    0xF3, 0x00, 0x4F, 0x60, 0x00, 0x00, 0x01, 0xE9, 0x00, 0x05, 0xF6, 0x00,
+    0x7F, 0x40, 0x00, 0x00, 0x09, 0xE1, 0x00, 0x01, 0xDC, 0x22, 0xDD, 0x00,
+    0x00, 0x00, 0x4F, 0x60, 0x00, 0x00, 0x3D, 0xFF, 0xD3, 0x00, 0x00, 0x00,
+    0x35, 0x00, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x01, 0x34, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8F, 0xFF,
+    0xFD, 0x40, 0x00, 0x00, 0x00, 0x07, 0xFE, 0x85, 0x7D, 0x70, 0x00, 0x00,
+    0x00, 0x0D, 0xF4, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x0E, 0xF0, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x03, 0xFF, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xFF, 0xE3,
+    0x00, 0x00, 0x01, 0x10, 0x00, 0x9F, 0x86, 0xFE, 0x30, 0x00, 0x4F, 0x90,
+    0x03, 0xFB, 0x00, 0x7F, 0xE2, 0x00, 0x6F, 0x50, 0x07, 0xF6, 0x00, 0x07,
+    0xFE, 0x20, 0xBF, 0x10, 0x0A, 0xF5, 0x00, 0x00, 0x7F, 0xD5, 0xF8, 0x00,
+    0x07, 0xF8, 0x00, 0x00, 0x08, 0xFF, 0xE1, 0x00, 0x03, 0xFF, 0x40, 0x00,
+    0x02, 0xEF, 0xD2, 0x00, 0x00, 0x7F, 0xFA, 0x66, 0x9E, 0xFB, 0xFD, 0x20,
+    0x00, 0x04, 0xDF, 0xFF, 0xFA, 0x20, 0x9F, 0xD2, 0x00, 0x00, 0x01, 0x43,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xFA, 0x00, 0x00, 0xFA, 0x00, 0x00, 0xFA, 0x00, 0x00, 0xFA, 0x00,
+    0x00, 0xFA, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x49, 0x20, 0x00, 0x00, 0xCD, 0x00, 0x00, 0x04, 0xF7, 0x00,
+    0x00, 0x0B, 0xE1, 0x00, 0x00, 0x4F, 0x90, 0x00, 0x00, 0x8F, 0x50, 0x00,
+    0x00, 0xBF, 0x20, 0x00, 0x00, 0xDF, 0x00, 0x00, 0x01, 0xFD, 0x00, 0x00,
+    0x03, 0xFC, 0x00, 0x00, 0x01, 0xFD, 0x00, 0x00, 0x00, 0xEF, 0x00, 0x00,
+    0x00, 0xBF, 0x20, 0x00, 0x00, 0x9F, 0x50, 0x00, 0x00, 0x4F, 0x80, 0x00,
+    0x00, 0x0C, 0xE1, 0x00, 0x00, 0x04, 0xF6, 0x00, 0x00, 0x00, 0xCD, 0x00,
+    0x00, 0x00, 0x4A, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x94, 0x00, 0x00, 0x00, 0xDC, 0x00, 0x00, 0x00, 0x6F, 0x50, 0x00,
+    0x00, 0x1E, 0xC0, 0x00, 0x00, 0x08, 0xF4, 0x00, 0x00, 0x04, 0xF9, 0x00,
+    0x00, 0x02, 0xFC, 0x00, 0x00, 0x00, 0xFE, 0x00, 0x00, 0x00, 0xDF, 0x20,
+    0x00, 0x00, 0xBF, 0x30, 0x00, 0x00, 0xCF, 0x20, 0x00, 0x00, 0xEE, 0x00,
+    0x00, 0x02, 0xFC, 0x00, 0x00, 0x04, 0xF9, 0x00, 0x00, 0x08, 0xF5, 0x00,
+    0x00, 0x0E, 0xC0, 0x00, 0x00, 0x6F, 0x50, 0x00, 0x00, 0xCD, 0x00, 0x00,
+    0x02, 0xA5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x00, 0x6B, 0x00,
+    0x00, 0x05, 0x00, 0x6B, 0x00, 0x32, 0x2D, 0xB3, 0x6B, 0x19, 0xE6, 0x01,
+    0x7E, 0xCD, 0xE9, 0x20, 0x00, 0x04, 0xFF, 0x80, 0x00, 0x02, 0xAD, 0xAC,
+    0xCC, 0x40, 0x2E, 0x81, 0x6B, 0x06, 0xE7, 0x02, 0x00, 0x6B, 0x00, 0x11,
+    0x00, 0x00, 0x6B, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xE7, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xF7, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x03, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xF7, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x45, 0x55, 0x57, 0xFA, 0x55, 0x55, 0x50, 0x00,
+    0x00, 0xCF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF1, 0x00, 0x00, 0x56, 0x66,
+    0x68, 0xFA, 0x66, 0x66, 0x61, 0x00, 0x00, 0x00, 0x00, 0x03, 0xF7, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xF7, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x03, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xF7, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x58, 0x50, 0x00, 0x00, 0x9F, 0x80, 0x00,
+    0x00, 0xAF, 0x50, 0x00, 0x00, 0xED, 0x00, 0x00, 0x03, 0xF5, 0x00, 0x00,
+    0x02, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x07, 0x88, 0x88, 0x30, 0x0F, 0xFF, 0xFF, 0x60, 0x03, 0x33, 0x33, 0x10,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x78, 0x30, 0x00, 0x00, 0xCF, 0x50, 0x00,
+    0x00, 0xCF, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0E, 0xC0, 0x00, 0x00, 0x4F, 0x70,
+    0x00, 0x00, 0x8F, 0x20, 0x00, 0x00, 0xDD, 0x00, 0x00, 0x02, 0xF8, 0x00,
+    0x00, 0x07, 0xF4, 0x00, 0x00, 0x0C, 0xE0, 0x00, 0x00, 0x1F, 0x90, 0x00,
+    0x00, 0x6F, 0x50, 0x00, 0x00, 0xBE, 0x10, 0x00, 0x01, 0xFA, 0x00, 0x00,
+    0x05, 0xF6, 0x00, 0x00, 0x0A, 0xF1, 0x00, 0x00, 0x0E, 0xB0, 0x00, 0x00,
+    0x4F, 0x70, 0x00, 0x00, 0x8F, 0x20, 0x00, 0x00, 0xCC, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0x41, 0x00, 0x00, 0x00, 0x00, 0x02, 0xCF, 0xFF, 0xD3, 0x00, 0x00, 0x00,
+    0x2E, 0xF9, 0x69, 0xFE, 0x30, 0x00, 0x00, 0xAF, 0x70, 0x00, 0x6F, 0xB0,
+    0x00, 0x01, 0xFE, 0x10, 0x00, 0x0D, 0xF2, 0x00, 0x05, 0xFA, 0x00, 0x00,
+    0x09, 0xF7, 0x00, 0x07, 0xF8, 0x00, 0x00, 0x06, 0xF8, 0x00, 0x08, 0xF7,
+    0x00, 0x00, 0x05, 0xFA, 0x00, 0x09, 0xF6, 0x00, 0x00, 0x05, 0xFB, 0x00,
+    0x08, 0xF7, 0x00, 0x00, 0x05, 0xFA, 0x00, 0x07, 0xF8, 0x00, 0x00, 0x06,
+    0xF8, 0x00, 0x05, 0xFA, 0x00, 0x00, 0x09, 0xF7, 0x00, 0x01, 0xFE, 0x10,
+    0x00, 0x0D, 0xF2, 0x00, 0x00, 0x9F, 0x80, 0x00, 0x6F, 0xB0, 0x00, 0x00,
+    0x2E, 0xF9, 0x69, 0xFE, 0x30, 0x00, 0x00, 0x02, 0xCF, 0xFF, 0xD3, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0xBE, 0xFD, 0x00, 0x00, 0x00, 0x00,
+    0xBF, 0xFE, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x54, 0x12, 0xFD, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFD,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFD, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x02, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFD, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0xFD, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFD, 0x00, 0x00, 0x00, 0x00,
+    0x5A, 0xAB, 0xFE, 0xAA, 0xA2, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0xF3,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24,
+    0x31, 0x00, 0x00, 0x00, 0x02, 0x9E, 0xFF, 0xFF, 0xB3, 0x00, 0x00, 0x06,
+    0xFD, 0xA7, 0x8C, 0xFE, 0x20, 0x00, 0x04, 0x50, 0x00, 0x00, 0xAF, 0xA0,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x3F, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xA0, 0x00, 0x00, 0x00,
+    0x00, 0x02, 0xEF, 0x20, 0x00, 0x00, 0x00, 0x00, 0x1C, 0xF7, 0x00, 0x00,
+    0x00, 0x00, 0x01, 0xBF, 0x90, 0x00, 0x00, 0x00, 0x00, 0x1B, 0xFA, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xBF, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xFA,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xAF, 0xB0, 0x00, 0x00, 0x00, 0x00, 0x07,
+    0xFF, 0xBA, 0xAA, 0xAA, 0xA1, 0x00, 0x07, 0xFF, 0xFF, 0xFF, 0xFF, 0xF1,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24,
+    0x42, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xFF, 0xFF, 0xD5, 0x00, 0x00, 0x00,
+    0xEB, 0x87, 0x7A, 0xFF, 0x60, 0x00, 0x00, 0x10, 0x00, 0x00, 0x5F, 0xD0,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0E, 0xF1, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x02, 0xBF, 0x70, 0x00, 0x00, 0x00,
+    0xBD, 0xEF, 0xD6, 0x00, 0x00, 0x00, 0x00, 0xAC, 0xDF, 0xFA, 0x10, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x9F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C,
+    0xF4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0xF6, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0B, 0xF4, 0x00, 0x02, 0x10, 0x00, 0x00, 0x5F, 0xF1, 0x00, 0x06,
+    0xFA, 0x87, 0x8B, 0xFF, 0x60, 0x00, 0x04, 0xCF, 0xFF, 0xFF, 0xC4, 0x00,
+    0x00, 0x00, 0x01, 0x34, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2E, 0xFC, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xBF, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x06, 0xF8, 0xFC, 0x00,
+    0x00, 0x00, 0x00, 0x2E, 0xA3, 0xFC, 0x00, 0x00, 0x00, 0x00, 0xBE, 0x13,
+    0xFC, 0x00, 0x00, 0x00, 0x06, 0xF6, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x1E,
+    0xB0, 0x03, 0xFC, 0x00, 0x00, 0x00, 0xAE, 0x20, 0x03, 0xFC, 0x00, 0x00,
+    0x05, 0xF7, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x0E, 0xE4, 0x44, 0x46, 0xFD,
+    0x44, 0x00, 0x0F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x00, 0x07, 0x77, 0x77,
+    0x78, 0xFD, 0x76, 0x00, 0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xFC, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0xFF, 0x30, 0x00, 0x00,
+    0xCF, 0xBA, 0xAA, 0xAA, 0x20, 0x00, 0x00, 0xCF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xCF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0x10, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xCF, 0xCE, 0xDA, 0x50, 0x00, 0x00, 0x00, 0xCF,
+    0xDC, 0xEF, 0xFB, 0x10, 0x00, 0x00, 0x51, 0x00, 0x04, 0xDF, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x3F, 0xF1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0D,
+    0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xF4, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x1E, 0xF1, 0x00, 0x02, 0x10, 0x00, 0x00, 0xAF, 0xC0, 0x00, 0x06,
+    0xFA, 0x87, 0x8D, 0xFE, 0x20, 0x00, 0x04, 0xDF, 0xFF, 0xFF, 0xA2, 0x00,
+    0x00, 0x00, 0x01, 0x34, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x14, 0x31, 0x00, 0x00, 0x00, 0x00, 0x4D, 0xFF, 0xFF, 0xA0, 0x00, 0x00,
+    0x07, 0xFE, 0xA7, 0x8A, 0xC0, 0x00, 0x00, 0x3F, 0xD3, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xBF, 0x50, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFD, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x04, 0xFA, 0x29, 0xDD, 0xA4, 0x00, 0x00, 0x06, 0xFB,
+    0xEE, 0xBC, 0xFF, 0x70, 0x00, 0x08, 0xFF, 0xC1, 0x00, 0x4F, 0xF3, 0x00,
+    0x07, 0xFF, 0x30, 0x00, 0x08, 0xF8, 0x00, 0x05, 0xFE, 0x00, 0x00, 0x05,
+    0xFB, 0x00, 0x04, 0xFD, 0x00, 0x00, 0x04, 0xFB, 0x00, 0x00, 0xEF, 0x10,
+    0x00, 0x07, 0xF9, 0x00, 0x00, 0x8F, 0x80, 0x00, 0x1D, 0xF5, 0x00, 0x00,
+    0x1C, 0xFA, 0x67, 0xDF, 0xA0, 0x00, 0x00, 0x01, 0xBF, 0xFF, 0xF8, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x05, 0xFF, 0xFF, 0xFF, 0xFF, 0xF5, 0x00, 0x03,
+    0xAA, 0xAA, 0xAA, 0xBF, 0xF2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6F, 0xB0,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0x50, 0x00, 0x00, 0x00, 0x00, 0x03,
+    0xFE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xF8, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0E, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xC0, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0xBF, 0x60, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFE, 0x10,
+    0x00, 0x00, 0x00, 0x00, 0x07, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0D,
+    0xF4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4F, 0xD0, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xAF, 0x70, 0x00, 0x00, 0x00, 0x00, 0x01, 0xEF, 0x20, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x43, 0x10, 0x00, 0x00, 0x00, 0x07, 0xEF, 0xFF, 0xE8, 0x10, 0x00, 0x00,
+    0x8F, 0xE7, 0x57, 0xDF, 0xA0, 0x00, 0x01, 0xFF, 0x20, 0x00, 0x1E, 0xF2,
+    0x00, 0x03, 0xFC, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x02, 0xFE, 0x00, 0x00,
+    0x0C, 0xF3, 0x00, 0x00, 0xAF, 0x80, 0x00, 0x6F, 0xB0, 0x00, 0x00, 0x08,
+    0xEE, 0xCD, 0xE9, 0x10, 0x00, 0x00, 0x19, 0xEF, 0xEF, 0xFA, 0x20, 0x00,
+    0x00, 0xCF, 0x81, 0x00, 0x7F, 0xD1, 0x00, 0x05, 0xFB, 0x00, 0x00, 0x09,
+    0xF7, 0x00, 0x08, 0xF7, 0x00, 0x00, 0x06, 0xFA, 0x00, 0x07, 0xF9, 0x00,
+    0x00, 0x07, 0xF9, 0x00, 0x04, 0xFE, 0x10, 0x00, 0x1D, 0xF6, 0x00, 0x00,
+    0xBF, 0xE7, 0x57, 0xDF, 0xC0, 0x00, 0x00, 0x18, 0xEF, 0xFF, 0xF9, 0x10,
+    0x00, 0x00, 0x00, 0x02, 0x43, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x41, 0x00, 0x00, 0x00, 0x00, 0x07, 0xEF, 0xFF, 0xC2, 0x00, 0x00, 0x00,
+    0x9F, 0xD7, 0x59, 0xFD, 0x20, 0x00, 0x03, 0xFE, 0x10, 0x00, 0x6F, 0x90,
+    0x00, 0x07, 0xF8, 0x00, 0x00, 0x0E, 0xF1, 0x00, 0x0A, 0xF6, 0x00, 0x00,
+    0x0B, 0xF5, 0x00, 0x09, 0xF7, 0x00, 0x00, 0x0C, 0xF7, 0x00, 0x07, 0xFA,
+    0x00, 0x00, 0x1F, 0xF8, 0x00, 0x01, 0xEF, 0x50, 0x01, 0xAF, 0xFA, 0x00,
+    0x00, 0x5F, 0xFD, 0xBE, 0xEA, 0xF8, 0x00, 0x00, 0x02, 0x9C, 0xC9, 0x39,
+    0xF6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xF4, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x3F, 0xC0, 0x00, 0x00, 0x10, 0x00, 0x02, 0xCF, 0x50, 0x00, 0x00,
+    0xBB, 0x87, 0xAE, 0xF8, 0x00, 0x00, 0x00, 0x9F, 0xFF, 0xFD, 0x50, 0x00,
+    0x00, 0x00, 0x01, 0x34, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6A, 0x60, 0x00,
+    0x00, 0x9F, 0x80, 0x00, 0x00, 0x8D, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x58, 0x50, 0x00, 0x00, 0x9F, 0x80, 0x00,
+    0x00, 0x9F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6A, 0x60, 0x00,
+    0x00, 0x9F, 0x80, 0x00, 0x00, 0x8D, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x58, 0x50, 0x00, 0x00, 0x9F, 0x80, 0x00,
+    0x00, 0xAF, 0x50, 0x00, 0x00, 0xED, 0x00, 0x00, 0x03, 0xF5, 0x00, 0x00,
+    0x02, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0xB1, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x5A, 0xFF, 0xF1, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0x9E, 0xFF, 0xC6, 0x10, 0x00, 0x00, 0x00, 0x28, 0xDF, 0xFD, 0x72,
+    0x00, 0x00, 0x00, 0x00, 0x5C, 0xFF, 0xD8, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xCF, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6D, 0xFF,
+    0xC7, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x39, 0xEF, 0xFB, 0x61,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xAF, 0xFF, 0xA5, 0x10, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x01, 0x6B, 0xFF, 0xE1, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x27, 0xD1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x56, 0x66,
+    0x66, 0x66, 0x66, 0x66, 0x61, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xF1, 0x00, 0x00, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0x44,
+    0x44, 0x44, 0x44, 0x44, 0x40, 0x00, 0x00, 0xCF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xF1, 0x00, 0x00, 0x57, 0x77, 0x77, 0x77, 0x77, 0x77, 0x71, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xA8, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xCF, 0xFC, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xAF,
+    0xFF, 0xA5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x6B, 0xFF, 0xE9,
+    0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27, 0xCF, 0xFD, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xDF, 0xF1, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x16, 0xBF, 0xFE, 0x91, 0x00, 0x00, 0x00, 0x00, 0x5A, 0xEF, 0xFA,
+    0x50, 0x00, 0x00, 0x00, 0x04, 0x9E, 0xFF, 0xB6, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xBF, 0xFD, 0x72, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xB9, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x34, 0x20, 0x00, 0x00,
+    0x02, 0x9F, 0xFF, 0xFC, 0x30, 0x00, 0x08, 0xFA, 0x77, 0xCF, 0xE1, 0x00,
+    0x05, 0x30, 0x00, 0x0E, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x09, 0xF6, 0x00,
+    0x00, 0x00, 0x00, 0x1D, 0xF3, 0x00, 0x00, 0x00, 0x01, 0xBF, 0x70, 0x00,
+    0x00, 0x00, 0x0B, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x8F, 0xA0, 0x00, 0x00,
+    0x00, 0x00, 0xDF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xED, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x01, 0x88, 0x00, 0x00, 0x00, 0x00, 0x01, 0xFF, 0x00, 0x00, 0x00,
+    0x00, 0x01, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x25, 0x76, 0x51, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8E, 0xFF,
+    0xFF, 0xFE, 0xA2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3D, 0xF9, 0x41, 0x02,
+    0x58, 0xEE, 0x50, 0x00, 0x00, 0x00, 0x04, 0xFB, 0x10, 0x00, 0x00, 0x00,
+    0x2D, 0xF5, 0x00, 0x00, 0x00, 0x1D, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0xBE, 0x10, 0x00, 0x00, 0x9D, 0x10, 0x01, 0x9D, 0xC9, 0x19, 0x70, 0x2F,
+    0x80, 0x00, 0x01, 0xF7, 0x00, 0x1D, 0xFA, 0x9C, 0xDE, 0x90, 0x09, 0xD0,
+    0x00, 0x04, 0xF1, 0x00, 0x8F, 0x50, 0x00, 0xAF, 0x90, 0x06, 0xF1, 0x00,
+    0x07, 0xE0, 0x00, 0xCD, 0x00, 0x00, 0x3F, 0x90, 0x05, 0xF2, 0x00, 0x09,
+    0xC0, 0x00, 0xEB, 0x00, 0x00, 0x0F, 0x90, 0x06, 0xF0, 0x00, 0x07, 0xE0,
+    0x00, 0xCC, 0x00, 0x00, 0x2F, 0x90, 0x0B, 0xC0, 0x00, 0x04, 0xF2, 0x00,
+    0x9F, 0x30, 0x00, 0x8F, 0x90, 0x5F, 0x40, 0x00, 0x01, 0xF6, 0x00, 0x2E,
+    0xE7, 0x69, 0xEE, 0xBA, 0xF8, 0x00, 0x00, 0x00, 0xAE, 0x10, 0x03, 0xCF,
+    0xFC, 0x3D, 0xEB, 0x30, 0x00, 0x00, 0x00, 0x1E, 0xA0, 0x00, 0x01, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xFA, 0x20, 0x00, 0x00, 0x00,
+    0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5E, 0xE7, 0x31, 0x01, 0x38, 0xFD,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x01, 0xAF, 0xFF, 0xEF, 0xFE, 0x81, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x36, 0x86, 0x31, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xFF, 0x60, 0x00, 0x00, 0x00,
+    0x00, 0x0A, 0xFF, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xDB, 0xF3, 0x00,
+    0x00, 0x00, 0x00, 0x7F, 0x76, 0xF8, 0x00, 0x00, 0x00, 0x00, 0xCF, 0x21,
+    0xEE, 0x00, 0x00, 0x00, 0x03, 0xFB, 0x00, 0xAF, 0x50, 0x00, 0x00, 0x09,
+    0xF6, 0x00, 0x4F, 0xA0, 0x00, 0x00, 0x1E, 0xE1, 0x00, 0x0D, 0xF1, 0x00,
+    0x00, 0x5F, 0xA0, 0x00, 0x08, 0xF7, 0x00, 0x00, 0xBF, 0xB8, 0x88, 0x8A,
+    0xFD, 0x00, 0x02, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x30, 0x08, 0xF9, 0x22,
+    0x22, 0x22, 0x7F, 0x90, 0x0D, 0xF3, 0x00, 0x00, 0x00, 0x1F, 0xE1, 0x4F,
+    0xD0, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0xAF, 0x70, 0x00, 0x00, 0x00, 0x05,
+    0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xDA, 0x30, 0x00, 0x00,
+    0xFF, 0xA9, 0x9A, 0xDF, 0xF4, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x08, 0xFC,
+    0x00, 0x00, 0xFF, 0x10, 0x00, 0x01, 0xFF, 0x00, 0x00, 0xFF, 0x10, 0x00,
+    0x01, 0xFE, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x1A, 0xF9, 0x00, 0x00, 0xFF,
+    0xCC, 0xCD, 0xFE, 0x80, 0x00, 0x00, 0xFF, 0xCC, 0xCD, 0xEF, 0xB2, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x06, 0xFE, 0x10, 0x00, 0xFF, 0x10, 0x00, 0x00,
+    0xAF, 0x70, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x8F, 0xA0, 0x00, 0xFF, 0x10,
+    0x00, 0x00, 0x9F, 0x80, 0x00, 0xFF, 0x10, 0x00, 0x04, 0xFF, 0x50, 0x00,
+    0xFF, 0xBA, 0xAB, 0xCF, 0xF9, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xDB, 0x50,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x42, 0x00, 0x00, 0x00, 0x00, 0x3B, 0xEF, 0xFF, 0xFB, 0x40, 0x00,
+    0x07, 0xFF, 0xC8, 0x68, 0xBE, 0xF4, 0x00, 0x5F, 0xE5, 0x00, 0x00, 0x02,
+    0xB4, 0x00, 0xDF, 0x60, 0x00, 0x00, 0x00, 0x01, 0x06, 0xFD, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x08, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xF6,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0A, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xF8, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x06, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xDF, 0x60,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x4F, 0xE6, 0x00, 0x00, 0x02, 0xB4, 0x00,
+    0x07, 0xFF, 0xC9, 0x68, 0xBF, 0xF4, 0x00, 0x00, 0x2B, 0xEF, 0xFF, 0xFA,
+    0x30, 0x00, 0x00, 0x00, 0x02, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFD,
+    0xBA, 0x50, 0x00, 0x00, 0x00, 0xFF, 0xA9, 0xAC, 0xDF, 0xFC, 0x40, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x03, 0xBF, 0xE2, 0x00, 0x00, 0xFF, 0x10, 0x00,
+    0x00, 0x0A, 0xFB, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x02, 0xEF, 0x30,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0xBF, 0x50, 0x00, 0xFF, 0x10, 0x00,
+    0x00, 0x00, 0x9F, 0x80, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x8F, 0x90,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x9F, 0x80, 0x00, 0xFF, 0x10, 0x00,
+    0x00, 0x00, 0xBF, 0x50, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x02, 0xEF, 0x30,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x0A, 0xFB, 0x00, 0x00, 0xFF, 0x10, 0x00,
+    0x03, 0xBF, 0xE2, 0x00, 0x00, 0xFF, 0xBA, 0xAC, 0xEF, 0xFB, 0x30, 0x00,
+    0x00, 0xFF, 0xFF, 0xED, 0xB9, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xF8, 0x00, 0x00,
+    0xFF, 0xBA, 0xAA, 0xAA, 0xA5, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF,
+    0xDD, 0xDD, 0xDD, 0xD3, 0x00, 0x00, 0xFF, 0xCC, 0xCC, 0xCC, 0xC3, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xFF, 0xBA, 0xAA, 0xAA, 0xA7, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFB,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xA0, 0x00, 0xFF, 0xBA, 0xAA, 0xAA, 0x70,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0xDD, 0xDD, 0xDD, 0x00, 0x00, 0xFF, 0xCC, 0xCC, 0xCC, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x43, 0x10, 0x00, 0x00, 0x00, 0x00, 0x2B, 0xEF,
+    0xFF, 0xFD, 0x71, 0x00, 0x00, 0x07, 0xFF, 0xC9, 0x67, 0xAD, 0xFD, 0x00,
+    0x00, 0x5F, 0xE5, 0x00, 0x00, 0x00, 0x7D, 0x00, 0x01, 0xDF, 0x50, 0x00,
+    0x00, 0x00, 0x01, 0x00, 0x06, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xF6, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0C, 0xF5, 0x00, 0x00, 0x1D, 0xDD, 0xDD, 0x40,
+    0x0A, 0xF6, 0x00, 0x00, 0x1C, 0xCC, 0xEF, 0x40, 0x08, 0xF8, 0x00, 0x00,
+    0x00, 0x00, 0xBF, 0x40, 0x06, 0xFC, 0x00, 0x00, 0x00, 0x00, 0xBF, 0x40,
+    0x01, 0xDF, 0x60, 0x00, 0x00, 0x00, 0xBF, 0x40, 0x00, 0x4F, 0xE6, 0x00,
+    0x00, 0x00, 0xBF, 0x40, 0x00, 0x07, 0xEF, 0xC9, 0x67, 0x8D, 0xFE, 0x30,
+    0x00, 0x00, 0x2B, 0xEF, 0xFF, 0xFD, 0x71, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x43, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00,
+    0x00, 0x08, 0xF7, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x08, 0xF7, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x08, 0xF7, 0x00, 0x00, 0xFF, 0x10, 0x00,
+    0x00, 0x08, 0xF7, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x08, 0xF7, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x08, 0xF7, 0x00, 0x00, 0xFF, 0xDD, 0xDD,
+    0xDD, 0xDE, 0xF7, 0x00, 0x00, 0xFF, 0xCC, 0xCC, 0xCC, 0xCE, 0xF7, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x08, 0xF7, 0x00, 0x00, 0xFF, 0x10, 0x00,
+    0x00, 0x08, 0xF7, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x08, 0xF7, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x08, 0xF7, 0x00, 0x00, 0xFF, 0x10, 0x00,
+    0x00, 0x08, 0xF7, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x08, 0xF7, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x08, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10,
+    0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10,
+    0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10,
+    0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10,
+    0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10,
+    0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10,
+    0x00, 0xFF, 0x10, 0x00, 0xFF, 0x10, 0x00, 0xFF, 0x00, 0x02, 0xFD, 0x00,
+    0x08, 0xFA, 0x00, 0xBF, 0xF3, 0x00, 0xFC, 0x40, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x01, 0xBF, 0xC1, 0x00,
+    0xFF, 0x10, 0x00, 0x1B, 0xFB, 0x10, 0x00, 0xFF, 0x10, 0x01, 0xCF, 0xA0,
+    0x00, 0x00, 0xFF, 0x10, 0x2D, 0xFA, 0x00, 0x00, 0x00, 0xFF, 0x12, 0xDF,
+    0x90, 0x00, 0x00, 0x00, 0xFF, 0x3D, 0xF8, 0x00, 0x00, 0x00, 0x00, 0xFF,
+    0xEF, 0x70, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x70, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x5E, 0xF7, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x14, 0xEF, 0x70,
+    0x00, 0x00, 0x00, 0xFF, 0x10, 0x4E, 0xF7, 0x00, 0x00, 0x00, 0xFF, 0x10,
+    0x04, 0xEF, 0x70, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x4E, 0xF7, 0x00, 0x00,
+    0xFF, 0x10, 0x00, 0x04, 0xEF, 0x70, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x4E,
+    0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xBA, 0xAA, 0xAA, 0xA0,
+    0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0xF4, 0x00, 0x00, 0x00, 0x7F, 0xFC, 0x00, 0x00, 0xFF, 0xF9,
+    0x00, 0x00, 0x00, 0xCF, 0xFC, 0x00, 0x00, 0xFF, 0xBE, 0x10, 0x00, 0x03,
+    0xFB, 0xFC, 0x00, 0x00, 0xFF, 0x5F, 0x60, 0x00, 0x09, 0xF5, 0xFC, 0x00,
+    0x00, 0xFF, 0x1E, 0xB0, 0x00, 0x0E, 0xC3, 0xFC, 0x00, 0x00, 0xFF, 0x09,
+    0xF2, 0x00, 0x5F, 0x63, 0xFC, 0x00, 0x00, 0xFF, 0x04, 0xF8, 0x00, 0xBE,
+    0x13, 0xFC, 0x00, 0x00, 0xFF, 0x00, 0xDD, 0x02, 0xFA, 0x03, 0xFC, 0x00,
+    0x00, 0xFF, 0x00, 0x7F, 0x47, 0xF4, 0x03, 0xFC, 0x00, 0x00, 0xFF, 0x00,
+    0x2F, 0x9D, 0xD0, 0x03, 0xFC, 0x00, 0x00, 0xFF, 0x00, 0x0B, 0xFF, 0x80,
+    0x03, 0xFC, 0x00, 0x00, 0xFF, 0x00, 0x05, 0xFF, 0x20, 0x03, 0xFC, 0x00,
+    0x00, 0xFF, 0x00, 0x00, 0x22, 0x00, 0x03, 0xFC, 0x00, 0x00, 0xFF, 0x00,
+    0x00, 0x00, 0x00, 0x03, 0xFC, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xE1, 0x00,
+    0x00, 0x09, 0xF6, 0x00, 0x00, 0xFF, 0xF9, 0x00, 0x00, 0x09, 0xF6, 0x00,
+    0x00, 0xFF, 0xEF, 0x20, 0x00, 0x09, 0xF6, 0x00, 0x00, 0xFF, 0x7F, 0x90,
+    0x00, 0x09, 0xF6, 0x00, 0x00, 0xFF, 0x0D, 0xF3, 0x00, 0x09, 0xF6, 0x00,
+    0x00, 0xFF, 0x06, 0xFA, 0x00, 0x09, 0xF6, 0x00, 0x00, 0xFF, 0x00, 0xCF,
+    0x30, 0x09, 0xF6, 0x00, 0x00, 0xFF, 0x00, 0x5F, 0xB0, 0x09, 0xF6, 0x00,
+    0x00, 0xFF, 0x00, 0x0C, 0xF4, 0x09, 0xF6, 0x00, 0x00, 0xFF, 0x00, 0x04,
+    0xFC, 0x09, 0xF6, 0x00, 0x00, 0xFF, 0x00, 0x00, 0xBF, 0x59, 0xF6, 0x00,
+    0x00, 0xFF, 0x00, 0x00, 0x3F, 0xD9, 0xF6, 0x00, 0x00, 0xFF, 0x00, 0x00,
+    0x0A, 0xFE, 0xF6, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x02, 0xFF, 0xF6, 0x00,
+    0x00, 0xFF, 0x00, 0x00, 0x00, 0x9F, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x03, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0xFF,
+    0xFF, 0xD6, 0x00, 0x00, 0x00, 0x08, 0xFF, 0xA7, 0x6A, 0xEF, 0xA1, 0x00,
+    0x00, 0x5F, 0xE4, 0x00, 0x00, 0x2D, 0xF9, 0x00, 0x01, 0xDF, 0x50, 0x00,
+    0x00, 0x02, 0xFF, 0x20, 0x06, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x9F, 0x90,
+    0x08, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xC0, 0x0A, 0xF6, 0x00, 0x00,
+    0x00, 0x00, 0x3F, 0xE0, 0x0C, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x2F, 0xF1,
+    0x0A, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xE0, 0x08, 0xF8, 0x00, 0x00,
+    0x00, 0x00, 0x5F, 0xC0, 0x06, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x9F, 0x90,
+    0x00, 0xDF, 0x50, 0x00, 0x00, 0x02, 0xFF, 0x20, 0x00, 0x5F, 0xE4, 0x00,
+    0x00, 0x2D, 0xF8, 0x00, 0x00, 0x07, 0xFF, 0xB7, 0x7A, 0xEF, 0xA1, 0x00,
+    0x00, 0x00, 0x3C, 0xFF, 0xFF, 0xD6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+    0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0xFF, 0xFE, 0xC6, 0x00, 0x00, 0xFF, 0xA9, 0xAC, 0xFF, 0xA0,
+    0x00, 0xFF, 0x10, 0x00, 0x3E, 0xF5, 0x00, 0xFF, 0x10, 0x00, 0x09, 0xF9,
+    0x00, 0xFF, 0x10, 0x00, 0x07, 0xFA, 0x00, 0xFF, 0x10, 0x00, 0x09, 0xF8,
+    0x00, 0xFF, 0x10, 0x00, 0x4F, 0xF5, 0x00, 0xFF, 0xBA, 0xBD, 0xFF, 0xA0,
+    0x00, 0xFF, 0xEE, 0xED, 0xB5, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x03, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0xFF,
+    0xFF, 0xD6, 0x00, 0x00, 0x00, 0x08, 0xFF, 0xA7, 0x6A, 0xEF, 0xA1, 0x00,
+    0x00, 0x5F, 0xE4, 0x00, 0x00, 0x2D, 0xF9, 0x00, 0x01, 0xDF, 0x50, 0x00,
+    0x00, 0x02, 0xFF, 0x20, 0x06, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x9F, 0x90,
+    0x08, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xC0, 0x0A, 0xF6, 0x00, 0x00,
+    0x00, 0x00, 0x3F, 0xE0, 0x0C, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x2F, 0xF1,
+    0x0A, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x3F, 0xE0, 0x08, 0xF8, 0x00, 0x00,
+    0x00, 0x00, 0x5F, 0xD0, 0x06, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x9F, 0x90,
+    0x00, 0xDF, 0x50, 0x00, 0x00, 0x02, 0xFF, 0x30, 0x00, 0x5F, 0xE4, 0x00,
+    0x00, 0x2D, 0xF9, 0x00, 0x00, 0x07, 0xFF, 0xB7, 0x7A, 0xEF, 0xB0, 0x00,
+    0x00, 0x00, 0x3C, 0xFF, 0xFF, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+    0x4B, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xCF, 0x90, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x1A, 0xA4, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFE, 0xC7, 0x10, 0x00, 0x00,
+    0xFF, 0xA9, 0xAB, 0xFF, 0xB0, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x2E, 0xF6,
+    0x00, 0x00, 0xFF, 0x10, 0x00, 0x08, 0xF9, 0x00, 0x00, 0xFF, 0x10, 0x00,
+    0x07, 0xFA, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x0A, 0xF8, 0x00, 0x00, 0xFF,
+    0x10, 0x02, 0x8F, 0xE2, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFC, 0x40, 0x00,
+    0x00, 0xFF, 0xA9, 0xBE, 0xFC, 0x20, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x7F,
+    0xC0, 0x00, 0x00, 0xFF, 0x10, 0x00, 0x0B, 0xF6, 0x00, 0x00, 0xFF, 0x10,
+    0x00, 0x03, 0xFE, 0x10, 0x00, 0xFF, 0x10, 0x00, 0x00, 0xAF, 0x70, 0x00,
+    0xFF, 0x10, 0x00, 0x00, 0x3F, 0xE1, 0x00, 0xFF, 0x10, 0x00, 0x00, 0x0B,
+    0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x43, 0x20, 0x00, 0x00, 0x00, 0x18, 0xEF, 0xFF, 0xFE, 0xA0, 0x00, 0x00,
+    0xBF, 0xD8, 0x67, 0x9D, 0xF1, 0x00, 0x05, 0xFC, 0x10, 0x00, 0x00, 0x40,
+    0x00, 0x08, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xFA, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0xFF, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00, 0xAF,
+    0xFF, 0xC9, 0x41, 0x00, 0x00, 0x00, 0x06, 0xBF, 0xFF, 0xFE, 0x50, 0x00,
+    0x00, 0x00, 0x01, 0x48, 0xDF, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B,
+    0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xFD, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x04, 0xFC, 0x00, 0x06, 0x40, 0x00, 0x00, 0x0B, 0xF9, 0x00, 0x09,
+    0xFD, 0x97, 0x68, 0xDF, 0xE1, 0x00, 0x04, 0xAE, 0xFF, 0xFF, 0xF9, 0x20,
+    0x00, 0x00, 0x00, 0x23, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA0, 0xAA,
+    0xAA, 0xAE, 0xFC, 0xAA, 0xAA, 0x70, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B,
+    0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xFD, 0x00, 0x00,
+    0x00, 0x0B, 0xF4, 0x00, 0x03, 0xFD, 0x00, 0x00, 0x00, 0x0B, 0xF4, 0x00,
+    0x03, 0xFD, 0x00, 0x00, 0x00, 0x0B, 0xF4, 0x00, 0x03, 0xFD, 0x00, 0x00,
+    0x00, 0x0B, 0xF4, 0x00, 0x03, 0xFD, 0x00, 0x00, 0x00, 0x0B, 0xF4, 0x00,
+    0x03, 0xFD, 0x00, 0x00, 0x00, 0x0B, 0xF4, 0x00, 0x03, 0xFD, 0x00, 0x00,
+    0x00, 0x0B, 0xF4, 0x00, 0x03, 0xFD, 0x00, 0x00, 0x00, 0x0B, 0xF4, 0x00,
+    0x03, 0xFD, 0x00, 0x00, 0x00, 0x0B, 0xF4, 0x00, 0x03, 0xFD, 0x00, 0x00,
+    0x00, 0x0C, 0xF4, 0x00, 0x01, 0xFF, 0x00, 0x00, 0x00, 0x0D, 0xF2, 0x00,
+    0x00, 0xEF, 0x30, 0x00, 0x00, 0x2F, 0xF0, 0x00, 0x00, 0x8F, 0xB1, 0x00,
+    0x00, 0xAF, 0x90, 0x00, 0x00, 0x1D, 0xFD, 0x86, 0x8C, 0xFE, 0x10, 0x00,
+    0x00, 0x01, 0x9F, 0xFF, 0xFF, 0x91, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24,
+    0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xAF, 0x70, 0x00, 0x00, 0x00, 0x06, 0xFB, 0x4F,
+    0xD0, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x0D, 0xF4, 0x00, 0x00, 0x00, 0x2F,
+    0xE0, 0x07, 0xF9, 0x00, 0x00, 0x00, 0x8F, 0x90, 0x02, 0xFE, 0x10, 0x00,
+    0x00, 0xDF, 0x30, 0x00, 0xBF, 0x50, 0x00, 0x04, 0xFC, 0x00, 0x00, 0x5F,
+    0xB0, 0x00, 0x0A, 0xF7, 0x00, 0x00, 0x0E, 0xF2, 0x00, 0x1E, 0xF1, 0x00,
+    0x00, 0x09, 0xF7, 0x00, 0x6F, 0xA0, 0x00, 0x00, 0x03, 0xFD, 0x00, 0xCF,
+    0x50, 0x00, 0x00, 0x00, 0xCF, 0x42, 0xFE, 0x00, 0x00, 0x00, 0x00, 0x7F,
+    0x98, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x1F, 0xEE, 0xF2, 0x00, 0x00, 0x00,
+    0x00, 0x0A, 0xFF, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x04, 0xFF, 0x60, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x3F, 0xD0, 0x00, 0x00, 0x1F, 0xF7, 0x00, 0x00,
+    0x07, 0xF9, 0x0E, 0xF2, 0x00, 0x00, 0x5F, 0xFB, 0x00, 0x00, 0x0B, 0xF5,
+    0x0A, 0xF6, 0x00, 0x00, 0x9F, 0xBE, 0x00, 0x00, 0x0E, 0xF1, 0x06, 0xF9,
+    0x00, 0x00, 0xCC, 0x6F, 0x30, 0x00, 0x3F, 0xC0, 0x03, 0xFD, 0x00, 0x01,
+    0xF9, 0x3F, 0x70, 0x00, 0x7F, 0x90, 0x00, 0xEF, 0x20, 0x05, 0xF5, 0x0E,
+    0xB0, 0x00, 0xBF, 0x50, 0x00, 0xAF, 0x50, 0x09, 0xF1, 0x0A, 0xE0, 0x00,
+    0xEF, 0x10, 0x00, 0x6F, 0x90, 0x0C, 0xD0, 0x07, 0xF3, 0x03, 0xFC, 0x00,
+    0x00, 0x3F, 0xD0, 0x1F, 0x90, 0x03, 0xF7, 0x07, 0xF9, 0x00, 0x00, 0x0E,
+    0xF2, 0x5F, 0x50, 0x00, 0xEB, 0x0B, 0xF5, 0x00, 0x00, 0x0A, 0xF5, 0x8F,
+    0x20, 0x00, 0xBE, 0x0E, 0xF1, 0x00, 0x00, 0x06, 0xF9, 0xCD, 0x00, 0x00,
+    0x7F, 0x6F, 0xC0, 0x00, 0x00, 0x03, 0xFD, 0xF9, 0x00, 0x00, 0x3F, 0xDF,
+    0x80, 0x00, 0x00, 0x00, 0xEF, 0xF6, 0x00, 0x00, 0x0E, 0xFF, 0x50, 0x00,
+    0x00, 0x00, 0xAF, 0xF2, 0x00, 0x00, 0x0B, 0xFF, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x05, 0xFC, 0x00, 0x00, 0x00, 0x6F, 0xC0, 0x00,
+    0xAF, 0x80, 0x00, 0x02, 0xEF, 0x20, 0x00, 0x1E, 0xF3, 0x00, 0x0B, 0xF7,
+    0x00, 0x00, 0x05, 0xFC, 0x00, 0x6F, 0xC0, 0x00, 0x00, 0x00, 0xAF, 0x82,
+    0xEF, 0x20, 0x00, 0x00, 0x00, 0x1E, 0xFC, 0xF7, 0x00, 0x00, 0x00, 0x00,
+    0x05, 0xFF, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x06, 0xFF, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x2E, 0xFE, 0xF3, 0x00, 0x00, 0x00, 0x00, 0xBF, 0x75, 0xFC,
+    0x00, 0x00, 0x00, 0x06, 0xFC, 0x00, 0xAF, 0x80, 0x00, 0x00, 0x2E, 0xF3,
+    0x00, 0x1E, 0xF3, 0x00, 0x00, 0xBF, 0x70, 0x00, 0x05, 0xFC, 0x00, 0x06,
+    0xFC, 0x00, 0x00, 0x00, 0xAF, 0x70, 0x2E, 0xF3, 0x00, 0x00, 0x00, 0x2E,
+    0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xAF, 0x80, 0x00, 0x00, 0x01, 0xDF, 0x40, 0x1E,
+    0xF3, 0x00, 0x00, 0x09, 0xF9, 0x00, 0x05, 0xFC, 0x00, 0x00, 0x4F, 0xD1,
+    0x00, 0x00, 0xAF, 0x80, 0x01, 0xDF, 0x40, 0x00, 0x00, 0x1E, 0xF3, 0x09,
+    0xF9, 0x00, 0x00, 0x00, 0x05, 0xFD, 0x4F, 0xD1, 0x00, 0x00, 0x00, 0x00,
+    0x9F, 0xFF, 0x40, 0x00, 0x00, 0x00, 0x00, 0x1E, 0xF8, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B,
+    0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0B, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0xF5, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE0, 0x09,
+    0xAA, 0xAA, 0xAA, 0xAA, 0xEF, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x04, 0xFF,
+    0x30, 0x00, 0x00, 0x00, 0x00, 0x2E, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0xCF, 0x80, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xFB, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x7F, 0xD1, 0x00, 0x00, 0x00, 0x00, 0x04, 0xFE, 0x30, 0x00, 0x00,
+    0x00, 0x00, 0x2E, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x01, 0xCF, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x0A, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xD1,
+    0x00, 0x00, 0x00, 0x00, 0x05, 0xFE, 0x20, 0x00, 0x00, 0x00, 0x00, 0x1E,
+    0xFD, 0xAA, 0xAA, 0xAA, 0xAA, 0xA2, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0xAA, 0xAA, 0x00, 0x03, 0xFE, 0xBB, 0x00, 0x03, 0xF9, 0x00, 0x00,
+    0x03, 0xF9, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00,
+    0x03, 0xF9, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00,
+    0x03, 0xF9, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00,
+    0x03, 0xF9, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00,
+    0x03, 0xF9, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00, 0x03, 0xFE, 0xBB, 0x00,
+    0x02, 0xAA, 0xAA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xDD, 0x00, 0x00, 0x00, 0x8F, 0x30, 0x00, 0x00,
+    0x3F, 0x70, 0x00, 0x00, 0x0E, 0xC0, 0x00, 0x00, 0x09, 0xF2, 0x00, 0x00,
+    0x04, 0xF6, 0x00, 0x00, 0x00, 0xEB, 0x00, 0x00, 0x00, 0xAF, 0x10, 0x00,
+    0x00, 0x5F, 0x50, 0x00, 0x00, 0x1F, 0xA0, 0x00, 0x00, 0x0B, 0xE0, 0x00,
+    0x00, 0x07, 0xF4, 0x00, 0x00, 0x02, 0xF9, 0x00, 0x00, 0x00, 0xCD, 0x00,
+    0x00, 0x00, 0x8F, 0x30, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00, 0x0D, 0xB0,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0xAA, 0xAA, 0x30, 0x00, 0xBB, 0xDF, 0x40, 0x00, 0x00, 0x9F, 0x40,
+    0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x9F, 0x40,
+    0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x9F, 0x40,
+    0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x9F, 0x40,
+    0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x9F, 0x40,
+    0x00, 0x00, 0x9F, 0x40, 0x00, 0x00, 0x9F, 0x40, 0x00, 0xBB, 0xDF, 0x40,
+    0x00, 0xAA, 0xAA, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x1C, 0xFE, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0xCF, 0xCF, 0xE3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0xF9, 0x06, 0xFE,
+    0x30, 0x00, 0x00, 0x00, 0x01, 0xBF, 0x80, 0x00, 0x4E, 0xE2, 0x00, 0x00,
+    0x00, 0x0B, 0xF6, 0x00, 0x00, 0x03, 0xED, 0x20, 0x00, 0x00, 0x48, 0x50,
+    0x00, 0x00, 0x00, 0x28, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x88, 0x88, 0x88, 0x88, 0x88, 0xDD, 0xDD, 0xDD, 0xDD, 0xDD,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x77,
+    0x00, 0x00, 0x00, 0x00, 0x6F, 0x70, 0x00, 0x00, 0x00, 0x08, 0xF3, 0x00,
+    0x00, 0x00, 0x00, 0xAD, 0x10, 0x00, 0x00, 0x00, 0x14, 0x20, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x03, 0x68, 0x75, 0x10, 0x00, 0x00, 0x00, 0xDF, 0xFF, 0xFF,
+    0xD4, 0x00, 0x00, 0x00, 0xB6, 0x31, 0x26, 0xEE, 0x10, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x6F, 0x70, 0x00, 0x00, 0x00, 0x00, 0x22, 0x4F, 0x90, 0x00,
+    0x00, 0x29, 0xEF, 0xFF, 0xFF, 0xB0, 0x00, 0x02, 0xEF, 0xA7, 0x65, 0x6F,
+    0xB0, 0x00, 0x08, 0xF6, 0x00, 0x00, 0x3F, 0xB0, 0x00, 0x0B, 0xF2, 0x00,
+    0x00, 0x6F, 0xB0, 0x00, 0x09, 0xF7, 0x00, 0x01, 0xDF, 0xB0, 0x00, 0x04,
+    0xFE, 0x95, 0x7E, 0xBF, 0xB0, 0x00, 0x00, 0x7E, 0xFF, 0xE9, 0x2F, 0xB0,
+    0x00, 0x00, 0x01, 0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xA8, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x02, 0xFB, 0x02, 0x57, 0x41, 0x00, 0x00, 0x02, 0xFB, 0x5F, 0xFF,
+    0xFD, 0x20, 0x00, 0x02, 0xFE, 0xE6, 0x12, 0x9F, 0xD0, 0x00, 0x02, 0xFF,
+    0x60, 0x00, 0x0A, 0xF6, 0x00, 0x02, 0xFE, 0x10, 0x00, 0x03, 0xFB, 0x00,
+    0x02, 0xFD, 0x00, 0x00, 0x01, 0xFD, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x00,
+    0xFE, 0x00, 0x02, 0xFD, 0x00, 0x00, 0x01, 0xFC, 0x00, 0x02, 0xFF, 0x20,
+    0x00, 0x05, 0xFA, 0x00, 0x02, 0xFF, 0x90, 0x00, 0x1C, 0xF4, 0x00, 0x02,
+    0xFD, 0xEA, 0x56, 0xCF, 0xB0, 0x00, 0x02, 0xFB, 0x3D, 0xFF, 0xF9, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x13, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x25, 0x77, 0x51, 0x00,
+    0x00, 0x08, 0xFF, 0xFF, 0xFF, 0x10, 0x00, 0x9F, 0xD4, 0x12, 0x4A, 0x10,
+    0x04, 0xFD, 0x10, 0x00, 0x00, 0x00, 0x09, 0xF6, 0x00, 0x00, 0x00, 0x00,
+    0x0B, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x0C, 0xF2, 0x00, 0x00, 0x00, 0x00,
+    0x0A, 0xF4, 0x00, 0x00, 0x00, 0x00, 0x08, 0xF8, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0xEE, 0x30, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xE8, 0x55, 0x8D, 0x10,
+    0x00, 0x04, 0xDF, 0xFF, 0xFB, 0x00, 0x00, 0x00, 0x01, 0x43, 0x10, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x07, 0xA2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xF3, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x0A, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xF3,
+    0x00, 0x00, 0x00, 0x47, 0x62, 0x0A, 0xF3, 0x00, 0x00, 0x1C, 0xFF, 0xFF,
+    0x7A, 0xF3, 0x00, 0x00, 0xCF, 0xA2, 0x15, 0xEE, 0xF3, 0x00, 0x05, 0xFB,
+    0x00, 0x00, 0x5F, 0xF3, 0x00, 0x09, 0xF5, 0x00, 0x00, 0x0D, 0xF3, 0x00,
+    0x0B, 0xF2, 0x00, 0x00, 0x0B, 0xF3, 0x00, 0x0C, 0xF1, 0x00, 0x00, 0x0A,
+    0xF3, 0x00, 0x0B, 0xF3, 0x00, 0x00, 0x0C, 0xF3, 0x00, 0x09, 0xF6, 0x00,
+    0x00, 0x1E, 0xF3, 0x00, 0x03, 0xFD, 0x10, 0x00, 0x8F, 0xF3, 0x00, 0x00,
+    0x9F, 0xC6, 0x59, 0xEC, 0xF3, 0x00, 0x00, 0x08, 0xFF, 0xFD, 0x4A, 0xF3,
+    0x00, 0x00, 0x00, 0x13, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x25, 0x76, 0x30, 0x00, 0x00, 0x00, 0x08, 0xFF, 0xFF,
+    0xF9, 0x10, 0x00, 0x00, 0x9F, 0xB3, 0x13, 0xAF, 0x90, 0x00, 0x03, 0xFC,
+    0x00, 0x00, 0x0C, 0xF2, 0x00, 0x08, 0xF5, 0x00, 0x00, 0x06, 0xF6, 0x00,
+    0x0B, 0xFA, 0x99, 0x99, 0x9B, 0xF8, 0x00, 0x0C, 0xFC, 0xCC, 0xCC, 0xCC,
+    0xC7, 0x00, 0x0A, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xF7, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0xEE, 0x20, 0x00, 0x00, 0x11, 0x00, 0x00,
+    0x6F, 0xF9, 0x65, 0x7A, 0xF2, 0x00, 0x00, 0x03, 0xCF, 0xFF, 0xFE, 0x91,
+    0x00, 0x00, 0x00, 0x01, 0x34, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x01, 0x79, 0xA0, 0x00, 0x2E, 0xFE, 0xD0, 0x00, 0x8F, 0x80, 0x00,
+    0x00, 0xAF, 0x30, 0x00, 0x24, 0xCF, 0x54, 0x40, 0x8F, 0xFF, 0xFF, 0xF0,
+    0x13, 0xCF, 0x43, 0x30, 0x00, 0xBF, 0x20, 0x00, 0x00, 0xBF, 0x20, 0x00,
+    0x00, 0xBF, 0x20, 0x00, 0x00, 0xBF, 0x20, 0x00, 0x00, 0xBF, 0x20, 0x00,
+    0x00, 0xBF, 0x20, 0x00, 0x00, 0xBF, 0x20, 0x00, 0x00, 0xBF, 0x20, 0x00,
+    0x00, 0xBF, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x01, 0x47, 0x62, 0x02, 0x41, 0x00, 0x00, 0x2C, 0xFF, 0xFF,
+    0x7A, 0xF3, 0x00, 0x00, 0xCF, 0x92, 0x15, 0xEE, 0xF3, 0x00, 0x05, 0xFB,
+    0x00, 0x00, 0x4F, 0xF3, 0x00, 0x09, 0xF4, 0x00, 0x00, 0x0D, 0xF3, 0x00,
+    0x0B, 0xF2, 0x00, 0x00, 0x0B, 0xF3, 0x00, 0x0C, 0xF1, 0x00, 0x00, 0x0A,
+    0xF3, 0x00, 0x0A, 0xF3, 0x00, 0x00, 0x0C, 0xF3, 0x00, 0x08, 0xF7, 0x00,
+    0x00, 0x2F, 0xF3, 0x00, 0x02, 0xEE, 0x30, 0x00, 0xAF, 0xF3, 0x00, 0x00,
+    0x7F, 0xEA, 0x9D, 0xCB, 0xF3, 0x00, 0x00, 0x05, 0xBE, 0xC9, 0x1B, 0xF2,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0D, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x6F, 0xA0, 0x00, 0x00, 0x7A, 0x64, 0x59, 0xFF, 0x20, 0x00, 0x00, 0x6E,
+    0xFF, 0xFF, 0xC3, 0x00, 0x00, 0x00, 0x00, 0x24, 0x31, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xA8, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x02, 0xFB, 0x01, 0x57, 0x51, 0x00, 0x00, 0x02, 0xFB, 0x4F, 0xFF,
+    0xFD, 0x30, 0x00, 0x02, 0xFE, 0xE6, 0x22, 0x9F, 0xB0, 0x00, 0x02, 0xFF,
+    0x50, 0x00, 0x0D, 0xF2, 0x00, 0x02, 0xFE, 0x00, 0x00, 0x09, 0xF3, 0x00,
+    0x02, 0xFB, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x08,
+    0xF5, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x02, 0xFB, 0x00,
+    0x00, 0x08, 0xF5, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x02,
+    0xFB, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x08, 0xF5,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xA8, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xA8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x43, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xA8, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xA8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x43, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFB, 0x00,
+    0x03, 0xFA, 0x00, 0x5B, 0xF6, 0x00, 0xFF, 0xA0, 0x00, 0x42, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xA8, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x03, 0x42,
+    0x02, 0xFB, 0x00, 0x00, 0x9F, 0xB1, 0x02, 0xFB, 0x00, 0x1B, 0xF9, 0x00,
+    0x02, 0xFB, 0x01, 0xCF, 0x70, 0x00, 0x02, 0xFB, 0x2D, 0xF6, 0x00, 0x00,
+    0x02, 0xFD, 0xEE, 0x40, 0x00, 0x00, 0x02, 0xFD, 0xFE, 0x30, 0x00, 0x00,
+    0x02, 0xFB, 0x4E, 0xE4, 0x00, 0x00, 0x02, 0xFB, 0x04, 0xEE, 0x40, 0x00,
+    0x02, 0xFB, 0x00, 0x3E, 0xF5, 0x00, 0x02, 0xFB, 0x00, 0x03, 0xEF, 0x50,
+    0x02, 0xFB, 0x00, 0x00, 0x2D, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xA8, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00,
+    0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x01, 0xFC, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x43,
+    0x02, 0x57, 0x30, 0x00, 0x04, 0x76, 0x10, 0x00, 0x02, 0xFB, 0x5F, 0xFF,
+    0xFB, 0x02, 0xDF, 0xFF, 0xE3, 0x00, 0x02, 0xFE, 0xE6, 0x23, 0xBF, 0x8D,
+    0x93, 0x28, 0xFB, 0x00, 0x02, 0xFF, 0x50, 0x00, 0x2F, 0xF9, 0x00, 0x00,
+    0xCF, 0x20, 0x02, 0xFD, 0x00, 0x00, 0x0E, 0xF3, 0x00, 0x00, 0x9F, 0x40,
+    0x02, 0xFB, 0x00, 0x00, 0x0C, 0xF1, 0x00, 0x00, 0x8F, 0x50, 0x02, 0xFB,
+    0x00, 0x00, 0x0C, 0xF1, 0x00, 0x00, 0x8F, 0x50, 0x02, 0xFB, 0x00, 0x00,
+    0x0C, 0xF1, 0x00, 0x00, 0x8F, 0x50, 0x02, 0xFB, 0x00, 0x00, 0x0C, 0xF1,
+    0x00, 0x00, 0x8F, 0x50, 0x02, 0xFB, 0x00, 0x00, 0x0C, 0xF1, 0x00, 0x00,
+    0x8F, 0x50, 0x02, 0xFB, 0x00, 0x00, 0x0C, 0xF1, 0x00, 0x00, 0x8F, 0x50,
+    0x02, 0xFB, 0x00, 0x00, 0x0C, 0xF1, 0x00, 0x00, 0x8F, 0x50, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x43, 0x01, 0x57, 0x51, 0x00, 0x00, 0x02, 0xFB, 0x4F, 0xFF,
+    0xFD, 0x30, 0x00, 0x02, 0xFE, 0xE6, 0x22, 0x9F, 0xB0, 0x00, 0x02, 0xFF,
+    0x50, 0x00, 0x0D, 0xF2, 0x00, 0x02, 0xFE, 0x00, 0x00, 0x09, 0xF3, 0x00,
+    0x02, 0xFB, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x08,
+    0xF5, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x02, 0xFB, 0x00,
+    0x00, 0x08, 0xF5, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x02,
+    0xFB, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x08, 0xF5,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x36, 0x75, 0x20, 0x00, 0x00, 0x00, 0x1A, 0xFF, 0xFF,
+    0xE6, 0x00, 0x00, 0x00, 0xBF, 0xB3, 0x15, 0xEF, 0x60, 0x00, 0x04, 0xFC,
+    0x00, 0x00, 0x3F, 0xD1, 0x00, 0x09, 0xF6, 0x00, 0x00, 0x0B, 0xF3, 0x00,
+    0x0B, 0xF3, 0x00, 0x00, 0x09, 0xF5, 0x00, 0x0C, 0xF2, 0x00, 0x00, 0x08,
+    0xF7, 0x00, 0x0B, 0xF3, 0x00, 0x00, 0x09, 0xF5, 0x00, 0x08, 0xF7, 0x00,
+    0x00, 0x0D, 0xF3, 0x00, 0x03, 0xFD, 0x10, 0x00, 0x6F, 0xC0, 0x00, 0x00,
+    0x9F, 0xD7, 0x59, 0xFF, 0x30, 0x00, 0x00, 0x06, 0xEF, 0xFF, 0xC3, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x43, 0x02, 0x57, 0x41, 0x00, 0x00, 0x02, 0xFB, 0x5F, 0xFF,
+    0xFD, 0x20, 0x00, 0x02, 0xFE, 0xE6, 0x12, 0x9F, 0xD0, 0x00, 0x02, 0xFF,
+    0x60, 0x00, 0x0A, 0xF6, 0x00, 0x02, 0xFE, 0x10, 0x00, 0x03, 0xFB, 0x00,
+    0x02, 0xFD, 0x00, 0x00, 0x01, 0xFD, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x00,
+    0xFE, 0x00, 0x02, 0xFD, 0x00, 0x00, 0x01, 0xFC, 0x00, 0x02, 0xFF, 0x20,
+    0x00, 0x05, 0xFA, 0x00, 0x02, 0xFF, 0x90, 0x00, 0x1C, 0xF4, 0x00, 0x02,
+    0xFD, 0xEA, 0x56, 0xCF, 0xB0, 0x00, 0x02, 0xFB, 0x3D, 0xFF, 0xF9, 0x00,
+    0x00, 0x02, 0xFB, 0x00, 0x13, 0x10, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFB,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x53, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x47, 0x62, 0x02, 0x41, 0x00, 0x00, 0x1C, 0xFF, 0xFF,
+    0x7A, 0xF3, 0x00, 0x00, 0xCF, 0xA2, 0x15, 0xEE, 0xF3, 0x00, 0x05, 0xFB,
+    0x00, 0x00, 0x5F, 0xF3, 0x00, 0x09, 0xF5, 0x00, 0x00, 0x0D, 0xF3, 0x00,
+    0x0B, 0xF2, 0x00, 0x00, 0x0B, 0xF3, 0x00, 0x0C, 0xF1, 0x00, 0x00, 0x0A,
+    0xF3, 0x00, 0x0B, 0xF3, 0x00, 0x00, 0x0C, 0xF3, 0x00, 0x09, 0xF6, 0x00,
+    0x00, 0x1E, 0xF3, 0x00, 0x03, 0xFD, 0x10, 0x00, 0x8F, 0xF3, 0x00, 0x00,
+    0x9F, 0xC6, 0x59, 0xEC, 0xF3, 0x00, 0x00, 0x08, 0xFF, 0xFD, 0x4A, 0xF3,
+    0x00, 0x00, 0x00, 0x13, 0x20, 0x0A, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0A, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xF3, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0A, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x51, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x43, 0x01, 0x58, 0x02, 0xFB, 0x5F, 0xFF,
+    0x02, 0xFE, 0xE7, 0x32, 0x02, 0xFF, 0x60, 0x00, 0x02, 0xFE, 0x00, 0x00,
+    0x02, 0xFC, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00,
+    0x02, 0xFB, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00, 0x02, 0xFB, 0x00, 0x00,
+    0x02, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x68, 0x75, 0x20, 0x00,
+    0x00, 0xBF, 0xFF, 0xFF, 0xF2, 0x00, 0x08, 0xFA, 0x20, 0x14, 0x92, 0x00,
+    0x0B, 0xF1, 0x00, 0x00, 0x00, 0x00, 0x0A, 0xF7, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0xFF, 0xD9, 0x51, 0x00, 0x00, 0x00, 0x3A, 0xEF, 0xFF, 0x80, 0x00,
+    0x00, 0x00, 0x03, 0x8E, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x06, 0xFA, 0x00,
+    0x02, 0x00, 0x00, 0x06, 0xF9, 0x00, 0x0D, 0xC7, 0x55, 0x9E, 0xF4, 0x00,
+    0x09, 0xEF, 0xFF, 0xFC, 0x50, 0x00, 0x00, 0x02, 0x44, 0x20, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x01, 0x86, 0x00, 0x00, 0x01, 0xFC, 0x00, 0x00,
+    0x01, 0xFC, 0x00, 0x00, 0x25, 0xFC, 0x44, 0x42, 0x7F, 0xFF, 0xFF, 0xF9,
+    0x14, 0xFC, 0x33, 0x32, 0x01, 0xFC, 0x00, 0x00, 0x01, 0xFC, 0x00, 0x00,
+    0x01, 0xFC, 0x00, 0x00, 0x01, 0xFC, 0x00, 0x00, 0x01, 0xFC, 0x00, 0x00,
+    0x01, 0xFC, 0x00, 0x00, 0x00, 0xEE, 0x00, 0x00, 0x00, 0xAF, 0xC9, 0x85,
+    0x00, 0x19, 0xDF, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x01, 0x42, 0x00, 0x00, 0x02, 0x41, 0x00, 0x04, 0xF9, 0x00, 0x00,
+    0x0A, 0xF3, 0x00, 0x04, 0xF9, 0x00, 0x00, 0x0A, 0xF3, 0x00, 0x04, 0xF9,
+    0x00, 0x00, 0x0A, 0xF3, 0x00, 0x04, 0xF9, 0x00, 0x00, 0x0A, 0xF3, 0x00,
+    0x04, 0xF9, 0x00, 0x00, 0x0A, 0xF3, 0x00, 0x04, 0xF9, 0x00, 0x00, 0x0A,
+    0xF3, 0x00, 0x04, 0xF9, 0x00, 0x00, 0x0B, 0xF3, 0x00, 0x02, 0xFB, 0x00,
+    0x00, 0x0D, 0xF3, 0x00, 0x00, 0xEE, 0x10, 0x00, 0x6F, 0xF3, 0x00, 0x00,
+    0x8F, 0xD6, 0x6A, 0xEC, 0xF3, 0x00, 0x00, 0x19, 0xFF, 0xFD, 0x3A, 0xF3,
+    0x00, 0x00, 0x00, 0x14, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x20, 0x00, 0x00, 0x02, 0x42,
+    0x2F, 0xD0, 0x00, 0x00, 0x0A, 0xF4, 0x0B, 0xF4, 0x00, 0x00, 0x1F, 0xE0,
+    0x05, 0xF9, 0x00, 0x00, 0x6F, 0x80, 0x00, 0xEE, 0x10, 0x00, 0xCF, 0x30,
+    0x00, 0x9F, 0x50, 0x02, 0xFC, 0x00, 0x00, 0x3F, 0xB0, 0x08, 0xF6, 0x00,
+    0x00, 0x0D, 0xF2, 0x0D, 0xF1, 0x00, 0x00, 0x07, 0xF7, 0x4F, 0xA0, 0x00,
+    0x00, 0x02, 0xFD, 0xAF, 0x40, 0x00, 0x00, 0x00, 0xBF, 0xFE, 0x00, 0x00,
+    0x00, 0x00, 0x5F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x04, 0x30, 0x00, 0x03, 0x42, 0x00, 0x00, 0x34, 0x00,
+    0x0E, 0xE0, 0x00, 0x0D, 0xFB, 0x00, 0x01, 0xFC, 0x00, 0x0A, 0xF3, 0x00,
+    0x2F, 0xFE, 0x00, 0x05, 0xF8, 0x00, 0x06, 0xF7, 0x00, 0x6F, 0x9F, 0x40,
+    0x09, 0xF4, 0x00, 0x02, 0xFB, 0x00, 0xAE, 0x2F, 0x80, 0x0D, 0xF1, 0x00,
+    0x00, 0xDE, 0x00, 0xEB, 0x0D, 0xC0, 0x2F, 0xB0, 0x00, 0x00, 0x9F, 0x43,
+    0xF7, 0x09, 0xF1, 0x6F, 0x70, 0x00, 0x00, 0x5F, 0x87, 0xF3, 0x05, 0xF5,
+    0x9F, 0x30, 0x00, 0x00, 0x2F, 0xBA, 0xE0, 0x01, 0xF9, 0xDE, 0x00, 0x00,
+    0x00, 0x0D, 0xFE, 0xA0, 0x00, 0xCE, 0xFB, 0x00, 0x00, 0x00, 0x09, 0xFF,
+    0x60, 0x00, 0x8F, 0xF7, 0x00, 0x00, 0x00, 0x05, 0xFF, 0x20, 0x00, 0x4F,
+    0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x41, 0x00, 0x00, 0x04, 0x41,
+    0x07, 0xFB, 0x00, 0x00, 0x7F, 0xB0, 0x00, 0xBF, 0x70, 0x03, 0xFE, 0x10,
+    0x00, 0x1D, 0xF3, 0x1D, 0xF4, 0x00, 0x00, 0x04, 0xFD, 0xBF, 0x80, 0x00,
+    0x00, 0x00, 0x7F, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x5F, 0xF7, 0x00, 0x00,
+    0x00, 0x02, 0xEE, 0xEF, 0x30, 0x00, 0x00, 0x0C, 0xF6, 0x4F, 0xD1, 0x00,
+    0x00, 0x8F, 0x90, 0x08, 0xFA, 0x00, 0x04, 0xFD, 0x10, 0x00, 0xBF, 0x60,
+    0x2E, 0xF3, 0x00, 0x00, 0x2E, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x20, 0x00, 0x00, 0x02, 0x42,
+    0x1F, 0xD0, 0x00, 0x00, 0x0A, 0xF4, 0x0A, 0xF4, 0x00, 0x00, 0x2F, 0xD0,
+    0x04, 0xFA, 0x00, 0x00, 0x7F, 0x70, 0x00, 0xCF, 0x20, 0x00, 0xDF, 0x10,
+    0x00, 0x6F, 0x70, 0x04, 0xFA, 0x00, 0x00, 0x1E, 0xD0, 0x0A, 0xF4, 0x00,
+    0x00, 0x09, 0xF4, 0x1F, 0xD0, 0x00, 0x00, 0x03, 0xFA, 0x7F, 0x70, 0x00,
+    0x00, 0x00, 0xCF, 0xDF, 0x10, 0x00, 0x00, 0x00, 0x6F, 0xFA, 0x00, 0x00,
+    0x00, 0x00, 0x0E, 0xF4, 0x00, 0x00, 0x00, 0x00, 0x2F, 0xD0, 0x00, 0x00,
+    0x00, 0x00, 0x8F, 0x60, 0x00, 0x00, 0x01, 0x48, 0xFE, 0x10, 0x00, 0x00,
+    0x06, 0xFF, 0xD4, 0x00, 0x00, 0x00, 0x02, 0x53, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x44, 0x44, 0x44, 0x44, 0x00,
+    0x0D, 0xFF, 0xFF, 0xFF, 0xFE, 0x00, 0x02, 0x33, 0x33, 0x39, 0xFC, 0x00,
+    0x00, 0x00, 0x00, 0x4F, 0xD2, 0x00, 0x00, 0x00, 0x03, 0xEE, 0x30, 0x00,
+    0x00, 0x00, 0x1D, 0xF4, 0x00, 0x00, 0x00, 0x01, 0xCF, 0x60, 0x00, 0x00,
+    0x00, 0x0A, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x8F, 0xA0, 0x00, 0x00, 0x00,
+    0x06, 0xFC, 0x10, 0x00, 0x00, 0x00, 0x1F, 0xF9, 0x88, 0x88, 0x87, 0x00,
+    0x2F, 0xFF, 0xFF, 0xFF, 0xFE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x05, 0x89, 0x60, 0x00, 0x00, 0x00, 0x00, 0xAF, 0xEC, 0x60, 0x00, 0x00,
+    0x00, 0x02, 0xFD, 0x10, 0x00, 0x00, 0x00, 0x00, 0x03, 0xF9, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x04, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xF9,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x06, 0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2C, 0xF4, 0x00, 0x00, 0x00,
+    0x00, 0x6E, 0xFD, 0x40, 0x00, 0x00, 0x00, 0x00, 0x49, 0xCF, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0A, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06,
+    0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xF9, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x04, 0xF9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xF9, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x03, 0xFA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xEE,
+    0x52, 0x10, 0x00, 0x00, 0x00, 0x00, 0x6E, 0xFF, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x01, 0x35, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x4B, 0x40, 0x00, 0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00,
+    0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00,
+    0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00,
+    0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00,
+    0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00,
+    0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00,
+    0x00, 0x6F, 0x50, 0x00, 0x00, 0x6F, 0x50, 0x00, 0x00, 0x5D, 0x40, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4A, 0x86,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x5C, 0xEF, 0xC0, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0B, 0xF3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x07, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xF6,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x06, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFD, 0x20, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x5D, 0xFE, 0x70, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xC9,
+    0x50, 0x00, 0x00, 0x00, 0x04, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06,
+    0xF7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xF6, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x07, 0xF6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xF6, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x08, 0xF5, 0x00, 0x00, 0x00, 0x00, 0x02, 0x4D, 0xF2,
+    0x00, 0x00, 0x00, 0x00, 0x6F, 0xFF, 0x80, 0x00, 0x00, 0x00, 0x00, 0x25,
+    0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x04, 0xAD, 0xDA, 0x61, 0x00, 0x03, 0xC1, 0x00,
+    0x00, 0x9F, 0xEB, 0xCF, 0xFF, 0xCA, 0xDF, 0xD1, 0x00, 0x00, 0xC6, 0x00,
+    0x01, 0x6A, 0xDF, 0xC7, 0x10, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
@@ -0,0 +1,253 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::color::Rgb565;
+use crate::constant::*;
+use crate::font::Font;
+use crate::lcd::{self, Lcd, LcdError, LcdInterface};
+use crate::sprite::Sprite;
+
+// past this many separate regions a flush is cheaper as one window
+const MAX_DIRTY: usize = 16;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Rect { x, y, width, height }
+    }
+
+    /// Exclusive right edge.
+    pub fn right(&self) -> u16 {
+        self.x.saturating_add(self.width)
+    }
+
+    /// Exclusive bottom edge.
+    pub fn bottom(&self) -> u16 {
+        self.y.saturating_add(self.height)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// True when the rectangles overlap or share an edge.
+    pub fn touches(&self, other: &Rect) -> bool {
+        self.x <= other.right() && other.x <= self.right()
+            && self.y <= other.bottom() && other.y <= self.bottom()
+    }
+
+    /// Smallest rectangle covering both.
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+        Rect::new(x, y, right - x, bottom - y)
+    }
+}
+
+/// Off-screen copy of the panel.
+///
+/// Drawing only touches memory and records the changed area; `flush` then
+/// sends just those regions to the panel.
+pub struct FrameBuffer {
+    pub width: u16,
+    pub height: u16,
+    pub data: Vec<Rgb565>,
+    dirty: Vec<Rect>,
+}
+
+impl FrameBuffer {
+    pub fn new(width: u16, height: u16) -> Self {
+        FrameBuffer {
+            width,
+            height,
+            data: vec![WHITE; width as usize * height as usize],
+            dirty: Vec::new(),
+        }
+    }
+
+    /// A buffer covering the whole panel.
+    pub fn for_lcd<I: LcdInterface>(lcd: &Lcd<I>) -> Self {
+        FrameBuffer::new(lcd.max_x, lcd.max_y)
+    }
+
+    pub fn dirty(&self) -> &[Rect] {
+        &self.dirty
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Record `rect` (clipped to the buffer) as needing a flush.
+    pub fn mark_dirty(&mut self, rect: Rect) {
+        let mut rect = match self.clip(rect.x, rect.y, rect.right(), rect.bottom()) {
+            Some(r) => r,
+            None => return,
+        };
+
+        // absorb every region the new one touches, growing it as we go
+        let mut i = 0;
+        while i < self.dirty.len() {
+            if self.dirty[i].touches(&rect) {
+                rect = rect.union(&self.dirty.swap_remove(i));
+                i = 0;
+            } else {
+                i += 1;
+            }
+        }
+        self.dirty.push(rect);
+
+        if self.dirty.len() > MAX_DIRTY {
+            let all = self.dirty.iter().fold(rect, |acc, r| acc.union(r));
+            self.dirty.clear();
+            self.dirty.push(all);
+        }
+    }
+
+    /// Mark the whole buffer dirty, e.g. after writing `data` directly.
+    pub fn invalidate(&mut self) {
+        self.dirty.clear();
+        self.dirty.push(Rect::new(0, 0, self.width, self.height));
+    }
+
+    pub fn get_pixel(&self, x: u16, y: u16) -> Option<Rgb565> {
+        if x < self.width && y < self.height {
+            Some(self.data[y as usize * self.width as usize + x as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn clear(&mut self, color: Rgb565) {
+        self.data.iter_mut().for_each(|p| *p = color);
+        self.invalidate();
+    }
+
+    pub fn draw_point(&mut self, x: u16, y: u16, color: Rgb565) {
+        if x < self.width && y < self.height {
+            self.data[y as usize * self.width as usize + x as usize] = color;
+            self.mark_dirty(Rect::new(x, y, 1, 1));
+        }
+    }
+
+    /// Same corner convention as `Lcd::fill_rectangle`: `x2`/`y2` are exclusive.
+    pub fn fill_rectangle(&mut self, x1: u16, y1: u16, x2: u16, y2: u16, color: Rgb565) {
+        let rect = match self.clip(x1, y1, x2, y2) {
+            Some(r) => r,
+            None => return,
+        };
+        for y in rect.y..rect.bottom() {
+            let start = y as usize * self.width as usize;
+            self.data[start + rect.x as usize..start + rect.right() as usize].fill(color);
+        }
+        self.mark_dirty(rect);
+    }
+
+    /// Set the pixels of `c` in `font`, leaving the rest alone. Anti-aliased
+    /// fonts are blended over what is already there. Returns the advance
+    /// width.
+    pub fn draw_char<F: Font + ?Sized>(&mut self, x: u16, y: u16, c: char, font: &F, color: Rgb565) -> u16 {
+        let glyph = match font.glyph(c).or_else(|| font.fallback()) {
+            Some(glyph) => glyph,
+            None => return 0,
+        };
+        for row in 0..font.height() {
+            let py = y as usize + row as usize;
+            for col in 0..glyph.width {
+                let px = x as usize + col as usize;
+                let alpha = glyph.alpha(col, row);
+                if alpha > 0 && px < self.width as usize && py < self.height as usize {
+                    let dst = &mut self.data[py * self.width as usize + px];
+                    *dst = color.blend(*dst, alpha);
+                }
+            }
+        }
+        self.mark_dirty(Rect::new(x, y, glyph.width as u16, font.height() as u16));
+        glyph.width as u16
+    }
+
+    pub fn draw_string<F: Font + ?Sized>(&mut self, x: u16, y: u16, s: &str, font: &F, color: Rgb565) {
+        let mut mx = x;
+        for c in s.chars() {
+            let advance = self.draw_char(mx, y, c, font, color);
+            mx = mx.saturating_add(advance);
+        }
+    }
+
+    /// Copy a picture packed like `Lcd::lcd_draw_picture` expects, two
+    /// pixels per word with the left one in the high half, cut off at the
+    /// buffer edge. `data` has to hold exactly the `width * height` pixels.
+    pub fn draw_picture(&mut self, x: u16, y: u16, width: u16, height: u16, data: &[u32]) -> Result<(), LcdError> {
+        if data.len() != lcd::packed_words(width, height) {
+            return Err(LcdError::BufferSizeMismatch);
+        }
+        let pixels = data.iter().flat_map(|&w| [Rgb565((w >> 16) as u16), Rgb565(w as u16)]);
+        let fb_width = self.width as usize;
+        for (i, color) in pixels.take(width as usize * height as usize).enumerate() {
+            let px = x as usize + i % width as usize;
+            let py = y as usize + i / width as usize;
+            if px < fb_width && py < self.height as usize {
+                self.data[py * fb_width + px] = color;
+            }
+        }
+        self.mark_dirty(Rect::new(x, y, width, height));
+        Ok(())
+    }
+
+    /// Composite `sprite` with its top left corner at `x`, `y`, which may be
+    /// off the buffer: key colour pixels are skipped and alpha is blended
+    /// over what is already there. A sprite whose pixels or alpha plane
+    /// don't match its size is refused with `BufferSizeMismatch`.
+    pub fn draw_sprite(&mut self, x: i32, y: i32, sprite: &Sprite) -> Result<(), LcdError> {
+        let width = self.width as usize;
+        let data = &mut self.data;
+        let drawn = sprite.composite(x, y, self.width, self.height, |px, py, color, alpha| {
+            let dst = &mut data[py * width + px];
+            *dst = color.blend(*dst, alpha);
+        })?;
+        if let Some((x, y, w, h)) = drawn {
+            self.mark_dirty(Rect::new(x, y, w, h));
+        }
+        Ok(())
+    }
+
+    /// Send every dirty region to the panel and forget them. If the panel
+    /// refuses a region, e.g. because the buffer is bigger than the screen,
+    /// the regions stay dirty.
+    pub fn flush<I: LcdInterface>(&mut self, lcd: &mut Lcd<I>) -> Result<(), LcdError> {
+        let dirty = core::mem::take(&mut self.dirty);
+        let width = self.width as usize;
+        let data = &self.data;
+        let sent = dirty.iter().try_for_each(|rect| {
+            lcd.lcd_set_area(rect.x, rect.y, rect.right() - 1, rect.bottom() - 1)?;
+            let rows = (rect.y as usize..rect.bottom() as usize).flat_map(|y| {
+                let start = y * width;
+                data[start + rect.x as usize..start + rect.right() as usize].iter().copied()
+            });
+            lcd.write_pixels(rows);
+            Ok(())
+        });
+        if sent.is_err() {
+            self.dirty = dirty;
+        }
+        sent
+    }
+
+    fn clip(&self, x1: u16, y1: u16, x2: u16, y2: u16) -> Option<Rect> {
+        let x2 = x2.min(self.width);
+        let y2 = y2.min(self.height);
+        if x1 >= x2 || y1 >= y2 {
+            return None;
+        }
+        Some(Rect::new(x1, y1, x2 - x1, y2 - y1))
+    }
+}
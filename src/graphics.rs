@@ -0,0 +1,149 @@
+use core::convert::Infallible;
+
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, OriginDimensions, Size},
+    pixelcolor::{self, raw::RawU16, IntoStorage},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+use crate::color::Rgb565;
+use crate::framebuffer::{FrameBuffer, Rect};
+use crate::lcd::{Lcd, LcdError, LcdInterface};
+
+impl From<pixelcolor::Rgb565> for Rgb565 {
+    fn from(color: pixelcolor::Rgb565) -> Self {
+        Rgb565(color.into_storage())
+    }
+}
+
+impl From<Rgb565> for pixelcolor::Rgb565 {
+    fn from(color: Rgb565) -> Self {
+        pixelcolor::Rgb565::from(RawU16::new(color.0))
+    }
+}
+
+impl<I: LcdInterface> OriginDimensions for Lcd<I> {
+    fn size(&self) -> Size {
+        Size::new(self.max_x as u32, self.max_y as u32)
+    }
+}
+
+impl<I: LcdInterface> DrawTarget for Lcd<I> {
+    type Color = pixelcolor::Rgb565;
+    type Error = LcdError;
+
+    fn draw_iter<P>(&mut self, pixels: P) -> Result<(), Self::Error>
+    where
+        P: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounds = self.bounding_box();
+        for Pixel(point, color) in pixels {
+            if bounds.contains(point) {
+                self.lcd_draw_point(point.x as u16, point.y as u16, color.into())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_contiguous<C>(&mut self, area: &Rectangle, colors: C) -> Result<(), Self::Error>
+    where
+        C: IntoIterator<Item = Self::Color>,
+    {
+        let drawable = area.intersection(&self.bounding_box());
+        let bottom_right = match drawable.bottom_right() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+
+        self.lcd_set_area(
+            drawable.top_left.x as u16,
+            drawable.top_left.y as u16,
+            bottom_right.x as u16,
+            bottom_right.y as u16,
+        )?;
+
+        // the clipped pixels come out in the same row-major order as the
+        // GRAM window, so they can be streamed straight into RAMWR
+        let pixels = area
+            .points()
+            .zip(colors)
+            .filter(|(point, _)| drawable.contains(*point))
+            .map(|(_, color)| Rgb565::from(color));
+        self.write_pixels(pixels);
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box());
+        let bottom_right = match area.bottom_right() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+
+        self.lcd_set_area(
+            area.top_left.x as u16,
+            area.top_left.y as u16,
+            bottom_right.x as u16,
+            bottom_right.y as u16,
+        )?;
+
+        self.write_color(color.into(), (area.size.width * area.size.height) as usize);
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill_solid(&self.bounding_box(), color)
+    }
+}
+
+impl OriginDimensions for FrameBuffer {
+    fn size(&self) -> Size {
+        Size::new(self.width as u32, self.height as u32)
+    }
+}
+
+impl DrawTarget for FrameBuffer {
+    type Color = pixelcolor::Rgb565;
+    type Error = Infallible;
+
+    fn draw_iter<P>(&mut self, pixels: P) -> Result<(), Self::Error>
+    where
+        P: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounds = self.bounding_box();
+        let mut touched: Option<Rect> = None;
+        for Pixel(point, color) in pixels {
+            if bounds.contains(point) {
+                let (x, y) = (point.x as u16, point.y as u16);
+                self.data[y as usize * self.width as usize + x as usize] = color.into();
+                let pixel = Rect::new(x, y, 1, 1);
+                touched = Some(touched.map_or(pixel, |r| r.union(&pixel)));
+            }
+        }
+        if let Some(rect) = touched {
+            self.mark_dirty(rect);
+        }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box());
+        if let Some(bottom_right) = area.bottom_right() {
+            self.fill_rectangle(
+                area.top_left.x as u16,
+                area.top_left.y as u16,
+                bottom_right.x as u16 + 1,
+                bottom_right.y as u16 + 1,
+                color.into(),
+            );
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        FrameBuffer::clear(self, color.into());
+        Ok(())
+    }
+}
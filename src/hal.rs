@@ -4,3 +4,4 @@ pub mod sysctl;
 pub mod dmac;
 pub mod utils;
 pub mod dvp;
+pub mod pwm;
@@ -158,7 +158,7 @@ impl Dmac {
         }
     }
 
-    fn is_channel_idle(&mut self, ch: Channel) -> bool {
+    pub fn is_channel_idle(&mut self, ch: Channel) -> bool {
         unsafe {
             let handler = DMAC::ptr();
             ((*handler).chen.read().bits() >> ch as u8) & 0x1 == 0
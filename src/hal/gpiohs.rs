@@ -32,6 +32,13 @@ trait GpiohsAccess {
             u32_set_bit(p, bit, index);
         }
     }
+
+    fn input_value(index: usize) -> bool {
+        unsafe {
+            let p = &Self::peripheral().input_val as *const _ as *const u32;
+            (core::ptr::read_volatile(p) >> index) & 1 != 0
+        }
+    }
 }
 
 impl GpiohsAccess for k210_hal::pac::GPIOHS {
@@ -54,6 +61,15 @@ impl Gpiohs {
         k210_hal::pac::GPIOHS::set_input_en(self.index, false);
     }
 
+    pub fn set_input(&mut self) {
+        k210_hal::pac::GPIOHS::set_output_en(self.index, false);
+        k210_hal::pac::GPIOHS::set_input_en(self.index, true);
+    }
+
+    pub fn is_high(&self) -> bool {
+        k210_hal::pac::GPIOHS::input_value(self.index)
+    }
+
     pub fn set_high(&mut self) {
         k210_hal::pac::GPIOHS::set_output_value(self.index, true);
     }
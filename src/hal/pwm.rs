@@ -0,0 +1,156 @@
+use k210_hal::pac::{FPIOA, SYSCTL, TIMER0, TIMER1, TIMER2};
+use k210_hal::pac::timer0::RegisterBlock;
+
+// channel control register bits
+const CR_ENABLE: u32 = 1 << 0;
+const CR_USER_MODE: u32 = 1 << 1;
+const CR_INTERRUPT_MASK: u32 = 1 << 2;
+const CR_PWM_ENABLE: u32 = 1 << 3;
+
+// timers are clocked from IN0 (26MHz) divided by (threshold + 1) * 2
+const TIMER_CLK: u32 = 13_000_000;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Timer {
+    Timer0 = 0,
+    Timer1 = 1,
+    Timer2 = 2,
+}
+
+fn registers(timer: Timer) -> &'static RegisterBlock {
+    unsafe {
+        match timer {
+            Timer::Timer0 => &*TIMER0::ptr(),
+            Timer::Timer1 => &*TIMER1::ptr(),
+            Timer::Timer2 => &*TIMER2::ptr(),
+        }
+    }
+}
+
+/// What a channel drives for a given duty.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Output {
+    Low,
+    High,
+    /// Timer ticks low, then high, per period; both at least one.
+    Toggle { low: u32, high: u32 },
+}
+
+/// Duty as a fraction of 65535 in timer ticks. Only 0 and 65535 are
+/// constant, anything in between keeps at least one tick of either level.
+fn output(periods: u32, duty: u16) -> Output {
+    match duty {
+        0 => Output::Low,
+        0xFFFF => Output::High,
+        _ => {
+            let high = (periods as u64 * duty as u64 / 0xFFFF) as u32;
+            let high = high.clamp(1, periods - 1);
+            Output::Toggle { low: periods - high, high }
+        }
+    }
+}
+
+/// One timer channel in PWM mode. The output appears on the FPIOA
+/// function TIMERn_TOGGLEm, which the caller has to route to pad `io`.
+pub struct Pwm {
+    timer: Timer,
+    channel: usize,
+    io: usize,
+    periods: u32,
+}
+
+impl Pwm {
+    pub fn new_pwm(timer: Timer, channel: usize, io: usize, frequency: u32) -> Self {
+        unsafe {
+            // enable bus clock and device clock
+            let ptr = SYSCTL::ptr();
+            (*ptr).clk_en_cent.modify(|_, w| w.apb0_clk_en().set_bit());
+            match timer {
+                Timer::Timer0 => {
+                    (*ptr).clk_sel0.modify(|_, w| w.timer0_clk_sel().clear_bit());
+                    (*ptr).clk_th2.modify(|_, w| w.timer0_clk().bits(0));
+                    (*ptr).clk_en_peri.modify(|_, w| w.timer0_clk_en().set_bit());
+                }
+                Timer::Timer1 => {
+                    (*ptr).clk_sel0.modify(|_, w| w.timer1_clk_sel().clear_bit());
+                    (*ptr).clk_th2.modify(|_, w| w.timer1_clk().bits(0));
+                    (*ptr).clk_en_peri.modify(|_, w| w.timer1_clk_en().set_bit());
+                }
+                Timer::Timer2 => {
+                    (*ptr).clk_sel0.modify(|_, w| w.timer2_clk_sel().clear_bit());
+                    (*ptr).clk_th2.modify(|_, w| w.timer2_clk().bits(0));
+                    (*ptr).clk_en_peri.modify(|_, w| w.timer2_clk_en().set_bit());
+                }
+            }
+        }
+
+        let periods = (TIMER_CLK / frequency).max(2);
+        let mut pwm = Pwm { timer, channel, io, periods };
+        pwm.set_duty(0);
+        pwm
+    }
+
+    pub fn periods(&self) -> u32 {
+        self.periods
+    }
+
+    /// High time as a fraction of 65535. 0 and 65535 keep the output
+    /// constantly low or high.
+    pub fn set_duty(&mut self, duty: u16) {
+        let (low, high) = match output(self.periods, duty) {
+            Output::Toggle { low, high } => (low, high),
+            level => {
+                // a stopped channel holds its toggle output low, the pad
+                // inverts that for a constant high
+                self.disable();
+                self.set_inverted(level == Output::High);
+                return;
+            }
+        };
+        let regs = registers(self.timer);
+        unsafe {
+            regs.channel[self.channel].load_count.write(|w| w.bits(low));
+            regs.load_count2[self.channel].write(|w| w.bits(high));
+        }
+        self.set_inverted(false);
+        self.enable();
+    }
+
+    fn set_inverted(&mut self, inverted: bool) {
+        unsafe {
+            (*FPIOA::ptr()).io[self.io].modify(|_, w| w.do_inv().bit(inverted));
+        }
+    }
+
+    pub fn enable(&mut self) {
+        let regs = registers(self.timer);
+        unsafe {
+            regs.channel[self.channel].control.write(|w| {
+                w.bits(CR_INTERRUPT_MASK | CR_PWM_ENABLE | CR_USER_MODE | CR_ENABLE)
+            });
+        }
+    }
+
+    pub fn disable(&mut self) {
+        let regs = registers(self.timer);
+        unsafe {
+            regs.channel[self.channel].control.write(|w| w.bits(CR_INTERRUPT_MASK));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{output, Output};
+
+    #[test]
+    fn duty_to_ticks() {
+        assert_eq!(output(650, 0), Output::Low);
+        assert_eq!(output(650, 0xFFFF), Output::High);
+        assert_eq!(output(650, 0x8000), Output::Toggle { low: 325, high: 325 });
+        // the first and last steps still toggle
+        assert_eq!(output(650, 1), Output::Toggle { low: 649, high: 1 });
+        assert_eq!(output(650, 0xFFFE), Output::Toggle { low: 1, high: 649 });
+        assert_eq!(output(2, 0x4000), Output::Toggle { low: 1, high: 1 });
+    }
+}
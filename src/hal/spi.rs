@@ -1,11 +1,11 @@
 use k210_hal::{
     pac::SPI0,
-    pac::spi0::ctrlr0::TMOD_A,
     pac::SYSCTL,
     sysctl::ACLK,
 };
 
 pub use k210_hal::{
+    pac::spi0::ctrlr0::TMOD_A,
     pac::spi0::ctrlr0::WORK_MODE_A,
     pac::spi0::ctrlr0::FRAME_FORMAT_A,
     pac::spi0::spi_ctrlr0::AITM_A,
@@ -13,10 +13,21 @@ pub use k210_hal::{
 
 use crate::hal::dmac::{Dmac, Channel, Inc, Msize, TrWidth};
 use crate::hal::sysctl::{set_dma_sel, DmaSelect};
+use crate::hal::utils::Deadline;
 
 use core::convert::Into;
 use core::marker::Copy;
 
+use alloc::vec::Vec;
+
+// longest a receive may wait for its frames, far more than a few status
+// bytes take even at the slowest baud rate
+const RECV_TIMEOUT_US: usize = 10_000;
+
+/// The receive FIFO did not fill in time.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Timeout;
+
 pub struct Spi {}
 
 impl Spi {
@@ -28,7 +39,7 @@ impl Spi {
         baud: u32,
     ) -> Self {
         unsafe {
-            // spi0 don't receive data
+            // transmit only, see set_transfer_mode for reads
             let tmod = TMOD_A::TRANS;
             let handler = SPI0::ptr();
             // no interrupts for now, we just send data
@@ -101,6 +112,48 @@ impl Spi {
         }
     }
 
+    /// Switch between transmit only and the receive modes. With `RECV`
+    /// in an enhanced frame format the instruction phase still goes out
+    /// first; `EEPROM` does the same in standard SPI.
+    pub fn set_transfer_mode(&mut self, tmod: TMOD_A) {
+        unsafe {
+            let handler = SPI0::ptr();
+            (*handler).ctrlr0.modify(|_, w| w.tmod().variant(tmod));
+        }
+    }
+
+    /// Send `tx` (instruction and address) and clock in `rx.len()` frames
+    /// in the same chip select cycle. Needs a receive transfer mode. Gives
+    /// up with `Timeout` if the frames don't arrive.
+    pub fn recv_data(&mut self, cs: u32, tx: &[u32], rx: &mut [u32]) -> Result<(), Timeout> {
+        if rx.is_empty() { return Ok(()) };
+        unsafe {
+            let handler = SPI0::ptr();
+            (*handler).ctrlr1.write(|w| w.bits(rx.len() as u32 - 1));
+            (*handler).ssienr.write(|w| w.bits(0x01));
+            for &val in tx {
+                (*handler).dr[0].write(|w| w.bits(val));
+            }
+            // the transfer starts once the slave is selected
+            (*handler).ser.write(|w| w.bits(1 << cs));
+
+            let deadline = Deadline::after_us(RECV_TIMEOUT_US);
+            let mut i = 0;
+            while i < rx.len() && !deadline.passed() {
+                let mut level = (*handler).rxflr.read().bits() as usize;
+                while level > 0 && i < rx.len() {
+                    rx[i] = (*handler).dr[0].read().bits();
+                    level -= 1;
+                    i += 1;
+                }
+            }
+
+            (*handler).ser.write(|w| w.bits(0x00));
+            (*handler).ssienr.write(|w| w.bits(0x00));
+            if i < rx.len() { Err(Timeout) } else { Ok(()) }
+        }
+    }
+
     pub fn send_data<U: Into<u32> + Copy>(&mut self, cs: u32, tx: &[U]) {
         unsafe {
             let handler = SPI0::ptr();
@@ -152,6 +205,13 @@ impl Spi {
     }
 
     pub fn send_data_dma(&mut self, cs: u32, buf: u64, len: u32, ch: Channel) {
+        self.start_data_dma(cs, buf, len, ch);
+        self.finish_data_dma(ch);
+    }
+
+    /// Kick off a DMA transmission and return without waiting for it.
+    /// `buf` must stay alive until `finish_data_dma` has returned.
+    pub fn start_data_dma(&mut self, cs: u32, buf: u64, len: u32, ch: Channel) {
         unsafe {
             let handler = SPI0::ptr();
             (*handler).dmacr.write(|w| w.bits(0x02));
@@ -169,6 +229,23 @@ impl Spi {
                 len,
             );
             (*handler).ser.write(|w| w.bits(1 << cs));
+        }
+    }
+
+    /// True once the DMA channel has drained and the SPI shifter is idle.
+    pub fn is_dma_done(&mut self, ch: Channel) -> bool {
+        unsafe {
+            let handler = SPI0::ptr();
+            let mut dma = Dmac{};
+            dma.is_channel_idle(ch) && ((*handler).sr.read().bits() & 0x05) == 0x04
+        }
+    }
+
+    /// Wait for a transmission started by `start_data_dma` and release the bus.
+    pub fn finish_data_dma(&mut self, ch: Channel) {
+        unsafe {
+            let handler = SPI0::ptr();
+            let mut dma = Dmac{};
             dma.wait_done(ch);
             while ((*handler).sr.read().bits() & 0x05) != 0x04 {
                 // IDLE
@@ -178,4 +255,39 @@ impl Spi {
             (*handler).ssienr.write(|w| w.bits(0x00));
         }
     }
+
+    /// Like `send_data_dma`, but hands the buffer to a `DmaTransfer`
+    /// instead of blocking until it has gone out.
+    pub fn send_data_dma_async(mut self, cs: u32, buf: Vec<u32>, ch: Channel) -> DmaTransfer {
+        self.start_data_dma(cs, buf.as_ptr() as u64, buf.len() as u32, ch);
+        DmaTransfer { spi: self, ch, buf: Some(buf) }
+    }
+}
+
+/// A DMA transmission in flight. Owns the buffer being sent so it cannot
+/// be freed or reused early; dropping the handle waits for completion.
+pub struct DmaTransfer {
+    spi: Spi,
+    ch: Channel,
+    buf: Option<Vec<u32>>,
+}
+
+impl DmaTransfer {
+    pub fn is_done(&mut self) -> bool {
+        self.spi.is_dma_done(self.ch)
+    }
+
+    /// Block until the transfer is complete and give the buffer back.
+    pub fn wait(mut self) -> Vec<u32> {
+        self.spi.finish_data_dma(self.ch);
+        self.buf.take().unwrap()
+    }
+}
+
+impl Drop for DmaTransfer {
+    fn drop(&mut self) {
+        if self.buf.is_some() {
+            self.spi.finish_data_dma(self.ch);
+        }
+    }
 }
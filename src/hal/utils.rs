@@ -1,11 +1,22 @@
 const CPU_FREQ: usize = 390_000_000;
 
-pub fn usleep(usec: usize) {
-    let interval = usec * CPU_FREQ/1_000_000;
-    let current = riscv::register::mcycle::read();
-    loop {
-        if (riscv::register::mcycle::read() - current) > interval {
-            break;
-        }
+/// A point in time on the cycle counter, for bounding busy waits.
+pub struct Deadline {
+    start: usize,
+    interval: usize,
+}
+
+impl Deadline {
+    pub fn after_us(usec: usize) -> Self {
+        Deadline { start: riscv::register::mcycle::read(), interval: usec * CPU_FREQ / 1_000_000 }
+    }
+
+    pub fn passed(&self) -> bool {
+        riscv::register::mcycle::read().wrapping_sub(self.start) > self.interval
     }
-}
\ No newline at end of file
+}
+
+pub fn usleep(usec: usize) {
+    let deadline = Deadline::after_us(usec);
+    while !deadline.passed() {}
+}
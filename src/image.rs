@@ -0,0 +1,129 @@
+//! Streaming image decoders. Images are decoded straight out of a byte
+//! slice, e.g. one pulled in with `include_bytes!`, a row or a JPEG block
+//! at a time, so only a band of rows or a single tile is ever held in RAM.
+
+pub mod bmp;
+pub mod jpeg;
+pub mod qoi;
+
+pub use bmp::Bmp;
+pub use jpeg::Jpeg;
+pub use qoi::Qoi;
+
+use alloc::vec;
+
+use crate::color::Rgb565;
+use crate::lcd::{Lcd, LcdError, LcdInterface};
+
+// rows decoded before a band goes out to the panel
+const BAND_ROWS: usize = 16;
+
+/// Why an image could not be decoded.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ImageError {
+    /// Not the expected file type, or a header that makes no sense.
+    BadHeader,
+    /// A valid file using a feature the decoder doesn't handle, e.g. a
+    /// progressive JPEG or an image wider than `u16::MAX`.
+    Unsupported,
+    /// The data ends before the image does.
+    Truncated,
+    /// The pixel data doesn't decode.
+    Corrupt,
+    /// The panel refused the image, e.g. a corner off screen.
+    Lcd(LcdError),
+}
+
+impl From<LcdError> for ImageError {
+    fn from(e: LcdError) -> Self {
+        ImageError::Lcd(e)
+    }
+}
+
+/// Source of pixel rows for `Lcd::draw_image`.
+pub trait ImageDecoder {
+    fn width(&self) -> u16;
+
+    fn height(&self) -> u16;
+
+    /// Whether rows come out bottom row first, as most BMP files store
+    /// them.
+    fn bottom_up(&self) -> bool {
+        false
+    }
+
+    /// Decode the next row into `row`, which is `width` pixels long.
+    fn next_row(&mut self, row: &mut [Rgb565]) -> Result<(), ImageError>;
+}
+
+// little and big endian readers that fail on short data
+pub(crate) fn le16(data: &[u8], at: usize) -> Result<u16, ImageError> {
+    let bytes = data.get(at..at + 2).ok_or(ImageError::Truncated)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+pub(crate) fn le32(data: &[u8], at: usize) -> Result<u32, ImageError> {
+    let bytes = data.get(at..at + 4).ok_or(ImageError::Truncated)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+pub(crate) fn be16(data: &[u8], at: usize) -> Result<u16, ImageError> {
+    let bytes = data.get(at..at + 2).ok_or(ImageError::Truncated)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+pub(crate) fn be32(data: &[u8], at: usize) -> Result<u32, ImageError> {
+    let bytes = data.get(at..at + 4).ok_or(ImageError::Truncated)?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+impl<I: LcdInterface> Lcd<I> {
+    /// Decode `image` with its top left corner at `x`, `y`, which must be on
+    /// screen, cut off at the right and bottom edge. Rows are decoded one
+    /// at a time, their visible part gathered into a band of a few lines,
+    /// and each band is sent through its own window, so the image never
+    /// has to fit in memory. Bands already sent stay on screen if decoding
+    /// fails part way.
+    pub fn draw_image<D: ImageDecoder>(&mut self, x: u16, y: u16, image: &mut D) -> Result<(), ImageError> {
+        let (width, height) = (image.width() as usize, image.height() as usize);
+        self.ready()?;
+        if x >= self.max_x || y >= self.max_y {
+            return Err(LcdError::OutOfBounds.into());
+        }
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        let visible_w = width.min((self.max_x - x) as usize);
+        let visible_h = height.min((self.max_y - y) as usize);
+        let bottom_up = image.bottom_up();
+
+        // the decoder fills whole rows, the band only keeps what fits on
+        // screen
+        let mut row = vec![Rgb565::default(); width];
+        let mut band = vec![Rgb565::default(); visible_w * BAND_ROWS];
+        let mut done = 0;
+        while done < height {
+            let rows = BAND_ROWS.min(height - done);
+            for line in band.chunks_mut(visible_w).take(rows) {
+                image.next_row(&mut row)?;
+                line.copy_from_slice(&row[..visible_w]);
+            }
+            // image rows top..top + rows, in the order they were decoded
+            let top = if bottom_up { height - done - rows } else { done };
+            done += rows;
+            if top >= visible_h {
+                // below the screen; top down there is nothing left to show
+                if bottom_up { continue } else { break }
+            }
+            let shown = rows.min(visible_h - top);
+            self.set_window(x, y + top as u16, x + visible_w as u16 - 1, y + (top + shown) as u16 - 1);
+            let band = &band;
+            let pixels = (0..shown).flat_map(move |i| {
+                let i = if bottom_up { rows - 1 - i } else { i };
+                band[i * visible_w..(i + 1) * visible_w].iter().copied()
+            });
+            self.write_pixels(pixels);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+//! Windows bitmaps: 1, 4 and 8 bit palette images, uncompressed or RLE
+//! compressed, and 16, 24 and 32 bit true colour ones.
+
+use super::{le16, le32, ImageDecoder, ImageError};
+use crate::color::Rgb565;
+use crate::constant::BLACK;
+
+const BI_RGB: u32 = 0;
+const BI_RLE8: u32 = 1;
+const BI_RLE4: u32 = 2;
+const BI_BITFIELDS: u32 = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Encoding {
+    Raw,
+    Rle,
+}
+
+/// Streaming BMP decoder.
+///
+/// Uncompressed rows are read straight from `data` in stored order. RLE
+/// images can only be decoded front to back, from the bottom row up unless
+/// the height is negative; pixels the encoder skipped over are black.
+pub struct Bmp<'a> {
+    data: &'a [u8],
+    width: u16,
+    height: u16,
+    top_down: bool,
+    bpp: u16,
+    encoding: Encoding,
+    palette: [Rgb565; 256],
+    /// Channel masks of 16 and 32 bit images, red, green, blue.
+    masks: [u32; 3],
+    /// Start of the pixel data.
+    pixels: usize,
+    stride: usize,
+    /// Rows decoded so far.
+    row: u16,
+    // RLE state: read position, blank rows left over from a delta and the
+    // column the row after them starts at
+    pos: usize,
+    skip_rows: u16,
+    skip_x: usize,
+    ended: bool,
+}
+
+impl<'a> Bmp<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, ImageError> {
+        if data.get(0..2) != Some(b"BM") {
+            return Err(ImageError::BadHeader);
+        }
+        let pixels = le32(data, 10)? as usize;
+        let header = le32(data, 14)? as usize;
+        let (width, height, bpp, compression, colors, entry) = if header == 12 {
+            // OS/2 core header: 16 bit sizes, no compression, 3 byte palette
+            (le16(data, 18)? as i32, le16(data, 20)? as i16 as i32, le16(data, 24)?, BI_RGB, 0, 3)
+        } else if header >= 40 {
+            let colors = le32(data, 46)? as usize;
+            (le32(data, 18)? as i32, le32(data, 22)? as i32, le16(data, 28)?, le32(data, 30)?, colors, 4)
+        } else {
+            return Err(ImageError::BadHeader);
+        };
+        if width <= 0 || height == 0 {
+            return Err(ImageError::BadHeader);
+        }
+        let (width, top_down) = (width as u32, height < 0);
+        let height = height.unsigned_abs();
+        if width > u16::MAX as u32 || height > u16::MAX as u32 {
+            return Err(ImageError::Unsupported);
+        }
+
+        let encoding = match (compression, bpp) {
+            (BI_RGB, 1 | 4 | 8 | 16 | 24 | 32) | (BI_BITFIELDS, 16 | 32) => Encoding::Raw,
+            (BI_RLE8, 8) | (BI_RLE4, 4) => Encoding::Rle,
+            _ => return Err(ImageError::Unsupported),
+        };
+        // bottom up is the only order RLE images come in
+        if encoding == Encoding::Rle && top_down {
+            return Err(ImageError::BadHeader);
+        }
+
+        let masks = if compression == BI_BITFIELDS {
+            // right after a 40 byte header, inside a longer one
+            [le32(data, 54)?, le32(data, 58)?, le32(data, 62)?]
+        } else if bpp == 16 {
+            [0x7C00, 0x03E0, 0x001F]
+        } else {
+            [0xFF0000, 0x00FF00, 0x0000FF]
+        };
+
+        let mut palette = [BLACK; 256];
+        if bpp <= 8 {
+            let mut at = 14 + header;
+            if compression == BI_BITFIELDS {
+                at += 12;
+            }
+            let count = if colors == 0 || colors > 256 { 1 << bpp } else { colors };
+            for entry_color in palette.iter_mut().take(count) {
+                let bgr = data.get(at..at + 3).ok_or(ImageError::Truncated)?;
+                *entry_color = Rgb565::from_rgb888(bgr[2], bgr[1], bgr[0]);
+                at += entry;
+            }
+        }
+
+        let stride = (width as usize * bpp as usize).div_ceil(32) * 4;
+        if encoding == Encoding::Raw && data.len() < pixels + stride * height as usize {
+            return Err(ImageError::Truncated);
+        }
+        Ok(Bmp {
+            data,
+            width: width as u16,
+            height: height as u16,
+            top_down,
+            bpp,
+            encoding,
+            palette,
+            masks,
+            pixels,
+            stride,
+            row: 0,
+            pos: pixels,
+            skip_rows: 0,
+            skip_x: 0,
+            ended: false,
+        })
+    }
+
+    fn raw_row(&mut self, row: &mut [Rgb565]) -> Result<(), ImageError> {
+        let start = self.pixels + self.row as usize * self.stride;
+        let line = self.data.get(start..start + self.stride).ok_or(ImageError::Truncated)?;
+        for (x, pixel) in row.iter_mut().enumerate() {
+            *pixel = match self.bpp {
+                1 | 4 | 8 => {
+                    let bpp = self.bpp as usize;
+                    let bit = x * bpp;
+                    let index = (line[bit / 8] >> (8 - bpp - bit % 8)) & ((1 << bpp) - 1) as u8;
+                    self.palette[index as usize]
+                }
+                16 => self.masked(u16::from_le_bytes([line[x * 2], line[x * 2 + 1]]) as u32),
+                24 => Rgb565::from_rgb888(line[x * 3 + 2], line[x * 3 + 1], line[x * 3]),
+                _ => self.masked(u32::from_le_bytes([line[x * 4], line[x * 4 + 1], line[x * 4 + 2], line[x * 4 + 3]])),
+            };
+        }
+        Ok(())
+    }
+
+    /// Pixel out of the channel masks, each channel scaled to 8 bits.
+    fn masked(&self, value: u32) -> Rgb565 {
+        let [r, g, b] = self.masks.map(|mask| {
+            if mask == 0 {
+                return 0;
+            }
+            let max = mask >> mask.trailing_zeros();
+            (((value & mask) >> mask.trailing_zeros()) as u64 * 255 / max as u64) as u8
+        });
+        Rgb565::from_rgb888(r, g, b)
+    }
+
+    fn byte(&mut self) -> Result<u8, ImageError> {
+        let byte = *self.data.get(self.pos).ok_or(ImageError::Truncated)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn rle_row(&mut self, row: &mut [Rgb565]) -> Result<(), ImageError> {
+        row.fill(BLACK);
+        if self.skip_rows > 0 {
+            self.skip_rows -= 1;
+            return Ok(());
+        }
+        let mut x = core::mem::take(&mut self.skip_x);
+        let nibbles = self.bpp == 4;
+        while !self.ended {
+            let (count, value) = (self.byte()? as usize, self.byte()?);
+            if count > 0 {
+                // run of `count` pixels, RLE4 alternates between two indices
+                for i in 0..count {
+                    let index = if !nibbles { value } else if i % 2 == 0 { value >> 4 } else { value & 0x0F };
+                    if let Some(pixel) = row.get_mut(x + i) {
+                        *pixel = self.palette[index as usize];
+                    }
+                }
+                x += count;
+                continue;
+            }
+            match value {
+                // end of line
+                0 => break,
+                // end of bitmap, the rows left stay blank
+                1 => self.ended = true,
+                // delta: move right and up
+                2 => {
+                    let (dx, dy) = (self.byte()? as usize, self.byte()? as u16);
+                    x += dx;
+                    if dy > 0 {
+                        self.skip_rows = dy - 1;
+                        self.skip_x = x;
+                        break;
+                    }
+                }
+                // literal run of `value` pixels, padded to 16 bits
+                count => {
+                    let count = count as usize;
+                    let bytes = if nibbles { count.div_ceil(2) } else { count };
+                    let literal = self.data.get(self.pos..self.pos + bytes).ok_or(ImageError::Truncated)?;
+                    for i in 0..count {
+                        let index = if !nibbles {
+                            literal[i]
+                        } else if i % 2 == 0 {
+                            literal[i / 2] >> 4
+                        } else {
+                            literal[i / 2] & 0x0F
+                        };
+                        if let Some(pixel) = row.get_mut(x + i) {
+                            *pixel = self.palette[index as usize];
+                        }
+                    }
+                    x += count;
+                    self.pos += bytes + bytes % 2;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ImageDecoder for Bmp<'_> {
+    fn width(&self) -> u16 {
+        self.width
+    }
+
+    fn height(&self) -> u16 {
+        self.height
+    }
+
+    fn bottom_up(&self) -> bool {
+        !self.top_down
+    }
+
+    fn next_row(&mut self, row: &mut [Rgb565]) -> Result<(), ImageError> {
+        match self.encoding {
+            Encoding::Raw => self.raw_row(row)?,
+            Encoding::Rle => self.rle_row(row)?,
+        }
+        self.row += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,588 @@
+//! Baseline JPEG, as written by the OV2640 and most cameras and editors.
+//!
+//! The image is decoded one MCU (minimum coded unit, 8x8 to 16x16 pixels)
+//! at a time and handed out as RGB565 tiles, optionally scaled down by 2, 4
+//! or 8 in the inverse DCT. Progressive files are refused: they spread
+//! every block over several scans, so all coefficients would have to be
+//! held at once.
+
+use super::{be16, ImageError};
+use crate::color::Rgb565;
+use crate::lcd::{Lcd, LcdError, LcdInterface};
+
+/// Natural (row major) position of the n-th coefficient in a block.
+const ZIGZAG: [u8; 64] = [
+    0, 1, 8, 16, 9, 2, 3, 10,
+    17, 24, 32, 25, 18, 11, 4, 5,
+    12, 19, 26, 33, 40, 48, 41, 34,
+    27, 20, 13, 6, 7, 14, 21, 28,
+    35, 42, 49, 56, 57, 50, 43, 36,
+    29, 22, 15, 23, 30, 37, 44, 51,
+    58, 59, 52, 45, 38, 31, 39, 46,
+    53, 60, 61, 54, 47, 55, 62, 63,
+];
+
+// C(u) / 2 * cos((2x + 1) * u * pi / 2N) << 12 for N output pixels per
+// block, row x, column u. Fewer outputs only look at the N x N lowest
+// frequencies, which is what scales the image down.
+const IDCT_8: [i32; 64] = [
+    1448, 2009, 1892, 1703, 1448, 1138, 784, 400,
+    1448, 1703, 784, -400, -1448, -2009, -1892, -1138,
+    1448, 1138, -784, -2009, -1448, 400, 1892, 1703,
+    1448, 400, -1892, -1138, 1448, 1703, -784, -2009,
+    1448, -400, -1892, 1138, 1448, -1703, -784, 2009,
+    1448, -1138, -784, 2009, -1448, -400, 1892, -1703,
+    1448, -1703, 784, 400, -1448, 2009, -1892, 1138,
+    1448, -2009, 1892, -1703, 1448, -1138, 784, -400,
+];
+
+const IDCT_4: [i32; 16] = [
+    1448, 1892, 1448, 784,
+    1448, 784, -1448, -1892,
+    1448, -784, -1448, 1892,
+    1448, -1892, 1448, -784,
+];
+
+const IDCT_2: [i32; 4] = [
+    1448, 1448,
+    1448, -1448,
+];
+
+// The example tables of the JPEG standard (K.3), which Motion JPEG
+// streams leave out and expect the decoder to know.
+const DC_LUMA_COUNTS: [u8; 16] = [0, 1, 5, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0];
+const DC_CHROMA_COUNTS: [u8; 16] = [0, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0];
+const DC_VALUES: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+const AC_LUMA_COUNTS: [u8; 16] = [0, 2, 1, 3, 3, 2, 4, 3, 5, 5, 4, 4, 0, 0, 1, 0x7D];
+const AC_LUMA_VALUES: [u8; 162] = [
+    0x01, 0x02, 0x03, 0x00, 0x04, 0x11, 0x05, 0x12, 0x21, 0x31, 0x41, 0x06,
+    0x13, 0x51, 0x61, 0x07, 0x22, 0x71, 0x14, 0x32, 0x81, 0x91, 0xA1, 0x08,
+    0x23, 0x42, 0xB1, 0xC1, 0x15, 0x52, 0xD1, 0xF0, 0x24, 0x33, 0x62, 0x72,
+    0x82, 0x09, 0x0A, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x25, 0x26, 0x27, 0x28,
+    0x29, 0x2A, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3A, 0x43, 0x44, 0x45,
+    0x46, 0x47, 0x48, 0x49, 0x4A, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59,
+    0x5A, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x73, 0x74, 0x75,
+    0x76, 0x77, 0x78, 0x79, 0x7A, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89,
+    0x8A, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9A, 0xA2, 0xA3,
+    0xA4, 0xA5, 0xA6, 0xA7, 0xA8, 0xA9, 0xAA, 0xB2, 0xB3, 0xB4, 0xB5, 0xB6,
+    0xB7, 0xB8, 0xB9, 0xBA, 0xC2, 0xC3, 0xC4, 0xC5, 0xC6, 0xC7, 0xC8, 0xC9,
+    0xCA, 0xD2, 0xD3, 0xD4, 0xD5, 0xD6, 0xD7, 0xD8, 0xD9, 0xDA, 0xE1, 0xE2,
+    0xE3, 0xE4, 0xE5, 0xE6, 0xE7, 0xE8, 0xE9, 0xEA, 0xF1, 0xF2, 0xF3, 0xF4,
+    0xF5, 0xF6, 0xF7, 0xF8, 0xF9, 0xFA,
+];
+
+const AC_CHROMA_COUNTS: [u8; 16] = [0, 2, 1, 2, 4, 4, 3, 4, 7, 5, 4, 4, 0, 1, 2, 0x77];
+const AC_CHROMA_VALUES: [u8; 162] = [
+    0x00, 0x01, 0x02, 0x03, 0x11, 0x04, 0x05, 0x21, 0x31, 0x06, 0x12, 0x41,
+    0x51, 0x07, 0x61, 0x71, 0x13, 0x22, 0x32, 0x81, 0x08, 0x14, 0x42, 0x91,
+    0xA1, 0xB1, 0xC1, 0x09, 0x23, 0x33, 0x52, 0xF0, 0x15, 0x62, 0x72, 0xD1,
+    0x0A, 0x16, 0x24, 0x34, 0xE1, 0x25, 0xF1, 0x17, 0x18, 0x19, 0x1A, 0x26,
+    0x27, 0x28, 0x29, 0x2A, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3A, 0x43, 0x44,
+    0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58,
+    0x59, 0x5A, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x73, 0x74,
+    0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87,
+    0x88, 0x89, 0x8A, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9A,
+    0xA2, 0xA3, 0xA4, 0xA5, 0xA6, 0xA7, 0xA8, 0xA9, 0xAA, 0xB2, 0xB3, 0xB4,
+    0xB5, 0xB6, 0xB7, 0xB8, 0xB9, 0xBA, 0xC2, 0xC3, 0xC4, 0xC5, 0xC6, 0xC7,
+    0xC8, 0xC9, 0xCA, 0xD2, 0xD3, 0xD4, 0xD5, 0xD6, 0xD7, 0xD8, 0xD9, 0xDA,
+    0xE2, 0xE3, 0xE4, 0xE5, 0xE6, 0xE7, 0xE8, 0xE9, 0xEA, 0xF2, 0xF3, 0xF4,
+    0xF5, 0xF6, 0xF7, 0xF8, 0xF9, 0xFA,
+];
+
+/// Output size relative to the encoded image.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Scale {
+    #[default]
+    Full,
+    Half,
+    Quarter,
+    Eighth,
+}
+
+impl Scale {
+    fn shift(self) -> u32 {
+        self as u32
+    }
+}
+
+/// One decoded MCU, `width` x `height` pixels row by row, at `x`, `y` in
+/// the (scaled) image. Tiles on the right and bottom edge are cut down to
+/// the image.
+#[derive(Clone, Copy, Debug)]
+pub struct Tile<'a> {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    pub pixels: &'a [Rgb565],
+}
+
+/// Canonical Huffman table: how many codes there are of each length and
+/// the symbols in code order.
+#[derive(Clone, Copy)]
+struct Huffman {
+    counts: [u8; 16],
+    values: [u8; 256],
+}
+
+impl Huffman {
+    const EMPTY: Huffman = Huffman { counts: [0; 16], values: [0; 256] };
+
+    fn new(counts: &[u8], values: &[u8]) -> Self {
+        let mut table = Huffman::EMPTY;
+        table.counts.copy_from_slice(counts);
+        table.values[..values.len()].copy_from_slice(values);
+        table
+    }
+
+    fn decode(&self, bits: &mut Bits) -> Result<u8, ImageError> {
+        // codes of each length follow on from the last code of the length
+        // before, so a code is known once it falls below the next first code
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0usize);
+        for &count in &self.counts {
+            code |= bits.bit()? as i32;
+            let count = count as i32;
+            if code - first < count {
+                return Ok(self.values[index + (code - first) as usize]);
+            }
+            index += count as usize;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(ImageError::Corrupt)
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Component {
+    id: u8,
+    /// Sampling factors, blocks per MCU across and down.
+    h: u8,
+    v: u8,
+    quant: u8,
+    dc: u8,
+    ac: u8,
+}
+
+/// Entropy coded data, with the 0x00 stuffed after every 0xFF taken out.
+struct Bits<'a> {
+    data: &'a [u8],
+    pos: usize,
+    acc: u32,
+    count: u32,
+}
+
+impl Bits<'_> {
+    fn bit(&mut self) -> Result<u32, ImageError> {
+        if self.count == 0 {
+            let byte = *self.data.get(self.pos).ok_or(ImageError::Truncated)?;
+            if byte != 0xFF {
+                self.pos += 1;
+                self.acc = byte as u32;
+            } else if self.data.get(self.pos + 1) == Some(&0) {
+                self.pos += 2;
+                self.acc = 0xFF;
+            } else {
+                // a marker: the scan ran short, pad with zeros like
+                // libjpeg does rather than reading into the next segment
+                self.acc = 0;
+            }
+            self.count = 8;
+        }
+        self.count -= 1;
+        Ok((self.acc >> self.count) & 1)
+    }
+
+    fn bits(&mut self, n: u8) -> Result<i32, ImageError> {
+        let mut value = 0;
+        for _ in 0..n {
+            value = (value << 1) | self.bit()? as i32;
+        }
+        Ok(value)
+    }
+
+    /// Skip to the RSTn marker ending a restart interval.
+    fn restart(&mut self) {
+        self.count = 0;
+        while self.data.get(self.pos) == Some(&0xFF) && self.data.get(self.pos + 1) == Some(&0xFF) {
+            self.pos += 1;
+        }
+        if self.data.get(self.pos) == Some(&0xFF) && matches!(self.data.get(self.pos + 1), Some(0xD0..=0xD7)) {
+            self.pos += 2;
+        }
+    }
+}
+
+/// `n` bits of a coefficient, the leading bit telling positive from
+/// negative.
+fn extend(value: i32, n: u8) -> i32 {
+    if n == 0 {
+        0
+    } else if value < 1 << (n - 1) {
+        value - (1 << n) + 1
+    } else {
+        value
+    }
+}
+
+fn clamp(value: i32) -> u8 {
+    value.clamp(0, 255) as u8
+}
+
+/// Inverse DCT of the lowest `n` x `n` frequencies of `coef` into an `n` x
+/// `n` block of samples in `out`.
+fn idct(coef: &[i32; 64], n: usize, out: &mut [u8], stride: usize) {
+    if n == 1 {
+        // the DC coefficient is 8 times the block average
+        out[0] = clamp(((coef[0] + 4) >> 3) + 128);
+        return;
+    }
+    let table: &[i32] = match n {
+        8 => &IDCT_8,
+        4 => &IDCT_4,
+        _ => &IDCT_2,
+    };
+    // rows, keeping 2 fractional bits, then columns
+    let mut tmp = [0i32; 64];
+    for v in 0..n {
+        for x in 0..n {
+            let sum: i32 = (0..n).map(|u| table[x * n + u] * coef[v * 8 + u]).sum();
+            tmp[v * 8 + x] = (sum + (1 << 9)) >> 10;
+        }
+    }
+    for y in 0..n {
+        for x in 0..n {
+            let sum: i32 = (0..n).map(|v| table[y * n + v] * tmp[v * 8 + x]).sum();
+            out[y * stride + x] = clamp(((sum + (1 << 13)) >> 14) + 128);
+        }
+    }
+}
+
+/// JFIF YCbCr to RGB, coefficients in 16 bit fixed point.
+fn ycc_to_rgb(y: u8, cb: u8, cr: u8) -> Rgb565 {
+    let (y, cb, cr) = (((y as i32) << 16) + (1 << 15), cb as i32 - 128, cr as i32 - 128);
+    Rgb565::from_rgb888(
+        clamp((y + 91881 * cr) >> 16),
+        clamp((y - 22554 * cb - 46802 * cr) >> 16),
+        clamp((y + 116130 * cb) >> 16),
+    )
+}
+
+/// Baseline JPEG decoder, greyscale or YCbCr with any chroma subsampling
+/// up to 2x2. Huffman tables missing from the file, as in Motion JPEG,
+/// default to the ones from the standard.
+pub struct Jpeg<'a> {
+    data: &'a [u8],
+    width: u16,
+    height: u16,
+    pub scale: Scale,
+    components: [Component; 3],
+    count: usize,
+    quant: [[u16; 64]; 4],
+    dc: [Huffman; 4],
+    ac: [Huffman; 4],
+    /// MCUs per restart interval, 0 for none.
+    restart: u16,
+    /// Start of the entropy coded data.
+    scan: usize,
+}
+
+impl<'a> Jpeg<'a> {
+    /// Read the headers up to the start of the image data.
+    pub fn new(data: &'a [u8]) -> Result<Self, ImageError> {
+        if data.get(0..2) != Some(&[0xFF, 0xD8]) {
+            return Err(ImageError::BadHeader);
+        }
+        let mut jpeg = Jpeg {
+            data,
+            width: 0,
+            height: 0,
+            scale: Scale::Full,
+            components: [Component::default(); 3],
+            count: 0,
+            quant: [[1; 64]; 4],
+            dc: [Huffman::EMPTY; 4],
+            ac: [Huffman::EMPTY; 4],
+            restart: 0,
+            scan: 0,
+        };
+        jpeg.dc[0] = Huffman::new(&DC_LUMA_COUNTS, &DC_VALUES);
+        jpeg.dc[1] = Huffman::new(&DC_CHROMA_COUNTS, &DC_VALUES);
+        jpeg.ac[0] = Huffman::new(&AC_LUMA_COUNTS, &AC_LUMA_VALUES);
+        jpeg.ac[1] = Huffman::new(&AC_CHROMA_COUNTS, &AC_CHROMA_VALUES);
+
+        let mut pos = 2;
+        loop {
+            // markers may be padded with any number of 0xFF
+            while data.get(pos) == Some(&0xFF) && data.get(pos + 1) == Some(&0xFF) {
+                pos += 1;
+            }
+            if *data.get(pos).ok_or(ImageError::Truncated)? != 0xFF {
+                return Err(ImageError::BadHeader);
+            }
+            let marker = *data.get(pos + 1).ok_or(ImageError::Truncated)?;
+            pos += 2;
+            if matches!(marker, 0x01 | 0xD0..=0xD7) {
+                continue;
+            }
+            let length = be16(data, pos)? as usize;
+            let segment = data.get(pos + 2..pos + length).ok_or(ImageError::Truncated)?;
+            match marker {
+                // baseline and extended sequential, Huffman coded
+                0xC0 | 0xC1 => jpeg.frame(segment)?,
+                // progressive, lossless and arithmetic coded
+                0xC2 | 0xC3 | 0xC5..=0xC7 | 0xC9..=0xCB | 0xCD..=0xCF => return Err(ImageError::Unsupported),
+                0xC4 => jpeg.huffman_tables(segment)?,
+                0xDB => jpeg.quant_tables(segment)?,
+                0xDD => jpeg.restart = be16(segment, 0)?,
+                0xDA => {
+                    jpeg.scan_header(segment)?;
+                    jpeg.scan = pos + length;
+                    return Ok(jpeg);
+                }
+                0xD9 => return Err(ImageError::BadHeader),
+                _ => {}
+            }
+            pos += length;
+        }
+    }
+
+    fn frame(&mut self, segment: &[u8]) -> Result<(), ImageError> {
+        let precision = *segment.first().ok_or(ImageError::Truncated)?;
+        self.height = be16(segment, 1)?;
+        self.width = be16(segment, 3)?;
+        self.count = *segment.get(5).ok_or(ImageError::Truncated)? as usize;
+        if precision != 8 {
+            return Err(ImageError::Unsupported);
+        }
+        // a height of 0 would be set later by a DNL marker
+        if self.width == 0 || self.height == 0 {
+            return Err(ImageError::Unsupported);
+        }
+        if self.count != 1 && self.count != 3 {
+            return Err(ImageError::Unsupported);
+        }
+        for i in 0..self.count {
+            let spec = segment.get(6 + i * 3..9 + i * 3).ok_or(ImageError::Truncated)?;
+            let (h, v) = (spec[1] >> 4, spec[1] & 0x0F);
+            if !(1..=2).contains(&h) || !(1..=2).contains(&v) || spec[2] > 3 {
+                return Err(ImageError::Unsupported);
+            }
+            self.components[i] = Component { id: spec[0], h, v, quant: spec[2], dc: 0, ac: 0 };
+        }
+        if self.count == 1 {
+            // a single component scan has one block per MCU whatever the
+            // sampling factors say
+            self.components[0].h = 1;
+            self.components[0].v = 1;
+        }
+        Ok(())
+    }
+
+    fn huffman_tables(&mut self, mut segment: &[u8]) -> Result<(), ImageError> {
+        while let Some((&info, rest)) = segment.split_first() {
+            let counts = rest.get(..16).ok_or(ImageError::Truncated)?;
+            let total: usize = counts.iter().map(|&c| c as usize).sum();
+            let values = rest.get(16..16 + total).ok_or(ImageError::Truncated)?;
+            let (class, id) = (info >> 4, (info & 0x0F) as usize);
+            if class > 1 || id > 3 || total > 256 {
+                return Err(ImageError::BadHeader);
+            }
+            let table = Huffman::new(counts, values);
+            if class == 0 {
+                self.dc[id] = table;
+            } else {
+                self.ac[id] = table;
+            }
+            segment = &rest[16 + total..];
+        }
+        Ok(())
+    }
+
+    fn quant_tables(&mut self, mut segment: &[u8]) -> Result<(), ImageError> {
+        while let Some((&info, rest)) = segment.split_first() {
+            let (wide, id) = (info >> 4 != 0, (info & 0x0F) as usize);
+            if id > 3 {
+                return Err(ImageError::BadHeader);
+            }
+            let size = if wide { 128 } else { 64 };
+            let values = rest.get(..size).ok_or(ImageError::Truncated)?;
+            for (k, q) in self.quant[id].iter_mut().enumerate() {
+                *q = if wide { u16::from_be_bytes([values[k * 2], values[k * 2 + 1]]) } else { values[k] as u16 };
+            }
+            segment = &rest[size..];
+        }
+        Ok(())
+    }
+
+    fn scan_header(&mut self, segment: &[u8]) -> Result<(), ImageError> {
+        if self.count == 0 {
+            return Err(ImageError::BadHeader);
+        }
+        // components in separate scans would need the whole image buffered
+        if *segment.first().ok_or(ImageError::Truncated)? as usize != self.count {
+            return Err(ImageError::Unsupported);
+        }
+        for i in 0..self.count {
+            let spec = segment.get(1 + i * 2..3 + i * 2).ok_or(ImageError::Truncated)?;
+            let component = self.components[..self.count].iter_mut().find(|c| c.id == spec[0]).ok_or(ImageError::BadHeader)?;
+            component.dc = spec[1] >> 4;
+            component.ac = spec[1] & 0x0F;
+            if component.dc > 3 || component.ac > 3 {
+                return Err(ImageError::BadHeader);
+            }
+        }
+        Ok(())
+    }
+
+    /// Width after scaling.
+    pub fn width(&self) -> u16 {
+        (self.width as u32).div_ceil(1 << self.scale.shift()) as u16
+    }
+
+    /// Height after scaling.
+    pub fn height(&self) -> u16 {
+        (self.height as u32).div_ceil(1 << self.scale.shift()) as u16
+    }
+
+    /// Pick the largest scale at which the image fits `width` x `height`,
+    /// or the smallest one if none does.
+    pub fn scale_to_fit(&mut self, width: u16, height: u16) {
+        for scale in [Scale::Full, Scale::Half, Scale::Quarter, Scale::Eighth] {
+            self.scale = scale;
+            if self.width() <= width && self.height() <= height {
+                return;
+            }
+        }
+    }
+
+    /// Decode the 64 coefficients of one block in natural order,
+    /// dequantized.
+    fn block(&self, bits: &mut Bits, component: &Component, pred: &mut i32, coef: &mut [i32; 64]) -> Result<(), ImageError> {
+        let quant = &self.quant[component.quant as usize];
+        coef.fill(0);
+        let size = self.dc[component.dc as usize].decode(bits)?;
+        if size > 11 {
+            return Err(ImageError::Corrupt);
+        }
+        // a valid DC coefficient fits in 11 bits, so a predictor beyond
+        // that only comes from a broken file and must not run away
+        *pred = (*pred + extend(bits.bits(size)?, size)).clamp(-2047, 2047);
+        // no valid file comes near the clamp, it only keeps the IDCT from
+        // overflowing on broken ones
+        coef[0] = pred.saturating_mul(quant[0] as i32).clamp(-4096, 4096);
+        let ac = &self.ac[component.ac as usize];
+        let mut k = 1;
+        while k < 64 {
+            let symbol = ac.decode(bits)?;
+            let (run, size) = ((symbol >> 4) as usize, symbol & 0x0F);
+            if size == 0 {
+                if run != 15 {
+                    // end of block
+                    break;
+                }
+                k += 16;
+                continue;
+            }
+            k += run;
+            // baseline AC coefficients take at most 10 bits
+            if k > 63 || size > 10 {
+                return Err(ImageError::Corrupt);
+            }
+            let value = extend(bits.bits(size)?, size);
+            coef[ZIGZAG[k] as usize] = value.saturating_mul(quant[k] as i32).clamp(-4096, 4096);
+            k += 1;
+        }
+        Ok(())
+    }
+
+    /// Decode the image, handing each tile to `f` left to right, top to
+    /// bottom. Tiles already handed out stay valid if decoding fails part
+    /// way.
+    pub fn decode<F: FnMut(Tile)>(&self, mut f: F) -> Result<(), ImageError> {
+        let components = &self.components[..self.count];
+        let h_max = components.iter().map(|c| c.h as usize).max().unwrap_or(1);
+        let v_max = components.iter().map(|c| c.v as usize).max().unwrap_or(1);
+        let n = 8 >> self.scale.shift();
+        let (mcu_w, mcu_h) = (h_max * n, v_max * n);
+        let mcus_x = (self.width as usize).div_ceil(h_max * 8);
+        let mcus_y = (self.height as usize).div_ceil(v_max * 8);
+        let (width, height) = (self.width() as usize, self.height() as usize);
+
+        let mut bits = Bits { data: self.data, pos: self.scan, acc: 0, count: 0 };
+        let mut preds = [0i32; 3];
+        let mut coef = [0i32; 64];
+        // samples of each component, up to 2x2 blocks
+        let mut planes = [[0u8; 256]; 3];
+        let mut tile = [Rgb565::default(); 256];
+        let mut left = self.restart;
+
+        for my in 0..mcus_y {
+            for mx in 0..mcus_x {
+                if self.restart != 0 {
+                    if left == 0 {
+                        bits.restart();
+                        preds = [0; 3];
+                        left = self.restart;
+                    }
+                    left -= 1;
+                }
+                for (i, component) in components.iter().enumerate() {
+                    let stride = component.h as usize * n;
+                    for by in 0..component.v as usize {
+                        for bx in 0..component.h as usize {
+                            self.block(&mut bits, component, &mut preds[i], &mut coef)?;
+                            idct(&coef, n, &mut planes[i][by * n * stride + bx * n..], stride);
+                        }
+                    }
+                }
+
+                let (x0, y0) = (mx * mcu_w, my * mcu_h);
+                let (tw, th) = (mcu_w.min(width - x0), mcu_h.min(height - y0));
+                // subsampled components are stretched over the MCU
+                let sample = |i: usize, x: usize, y: usize| {
+                    let c = &components[i];
+                    planes[i][(y * c.v as usize / v_max) * c.h as usize * n + x * c.h as usize / h_max]
+                };
+                for y in 0..th {
+                    for x in 0..tw {
+                        tile[y * tw + x] = if self.count == 1 {
+                            let l = sample(0, x, y);
+                            Rgb565::from_rgb888(l, l, l)
+                        } else {
+                            ycc_to_rgb(sample(0, x, y), sample(1, x, y), sample(2, x, y))
+                        };
+                    }
+                }
+                f(Tile { x: x0 as u16, y: y0 as u16, width: tw as u16, height: th as u16, pixels: &tile[..tw * th] });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I: LcdInterface> Lcd<I> {
+    /// Decode `jpeg` with its top left corner at `x`, `y`, which must be on
+    /// screen, each MCU going out through its own window as soon as it is
+    /// decoded. Cut off at the right and bottom edge; use `Jpeg::scale_to_fit` to make a large
+    /// photo fit the screen instead.
+    pub fn draw_jpeg(&mut self, x: u16, y: u16, jpeg: &Jpeg) -> Result<(), ImageError> {
+        self.ready()?;
+        if x >= self.max_x || y >= self.max_y {
+            return Err(LcdError::OutOfBounds.into());
+        }
+        let (max_x, max_y) = (self.max_x as u32, self.max_y as u32);
+        jpeg.decode(|tile| {
+            let (tx, ty) = (x as u32 + tile.x as u32, y as u32 + tile.y as u32);
+            if tx >= max_x || ty >= max_y {
+                return;
+            }
+            let w = (tile.width as u32).min(max_x - tx) as usize;
+            let h = (tile.height as u32).min(max_y - ty) as usize;
+            self.set_window(tx as u16, ty as u16, (tx as usize + w - 1) as u16, (ty as usize + h - 1) as u16);
+            let pixels = tile.pixels.chunks(tile.width as usize).take(h).flat_map(|row| row[..w].iter().copied());
+            self.write_pixels(pixels);
+        })
+    }
+}
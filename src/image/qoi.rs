@@ -0,0 +1,127 @@
+//! The Quite OK Image format, lossless and cheap to decode: every pixel is
+//! a few bytes of state away from the previous ones.
+
+use super::{be32, ImageDecoder, ImageError};
+use crate::color::Rgb565;
+use crate::constant::BLACK;
+
+const QOI_OP_RGB: u8 = 0xFE;
+const QOI_OP_RGBA: u8 = 0xFF;
+const QOI_OP_INDEX: u8 = 0x00;
+const QOI_OP_DIFF: u8 = 0x40;
+const QOI_OP_LUMA: u8 = 0x80;
+
+const HEADER: usize = 14;
+
+/// Streaming QOI decoder. Transparent pixels are blended over
+/// `background`, black unless set otherwise.
+pub struct Qoi<'a> {
+    data: &'a [u8],
+    width: u16,
+    height: u16,
+    pub background: Rgb565,
+    pos: usize,
+    /// Last pixel, RGBA.
+    px: [u8; 4],
+    /// Recently seen pixels, by hash.
+    index: [[u8; 4]; 64],
+    /// Repeats of `px` still to come.
+    run: u8,
+}
+
+impl<'a> Qoi<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, ImageError> {
+        if data.get(0..4) != Some(b"qoif") {
+            return Err(ImageError::BadHeader);
+        }
+        let (width, height) = (be32(data, 4)?, be32(data, 8)?);
+        let channels = *data.get(12).ok_or(ImageError::Truncated)?;
+        if width == 0 || height == 0 || !(3..=4).contains(&channels) {
+            return Err(ImageError::BadHeader);
+        }
+        if width > u16::MAX as u32 || height > u16::MAX as u32 {
+            return Err(ImageError::Unsupported);
+        }
+        Ok(Qoi {
+            data,
+            width: width as u16,
+            height: height as u16,
+            background: BLACK,
+            pos: HEADER,
+            px: [0, 0, 0, 255],
+            index: [[0; 4]; 64],
+            run: 0,
+        })
+    }
+
+    fn byte(&mut self) -> Result<u8, ImageError> {
+        let byte = *self.data.get(self.pos).ok_or(ImageError::Truncated)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn next_pixel(&mut self) -> Result<[u8; 4], ImageError> {
+        if self.run > 0 {
+            self.run -= 1;
+            return Ok(self.px);
+        }
+        let op = self.byte()?;
+        let [r, g, b, a] = self.px;
+        self.px = match op {
+            QOI_OP_RGB => [self.byte()?, self.byte()?, self.byte()?, a],
+            QOI_OP_RGBA => [self.byte()?, self.byte()?, self.byte()?, self.byte()?],
+            _ => match op & 0xC0 {
+                QOI_OP_INDEX => self.index[op as usize],
+                QOI_OP_DIFF => [
+                    r.wrapping_add((op >> 4) & 3).wrapping_sub(2),
+                    g.wrapping_add((op >> 2) & 3).wrapping_sub(2),
+                    b.wrapping_add(op & 3).wrapping_sub(2),
+                    a,
+                ],
+                QOI_OP_LUMA => {
+                    // green difference, red and blue relative to it
+                    let dg = (op & 0x3F).wrapping_sub(32);
+                    let rb = self.byte()?;
+                    [
+                        r.wrapping_add(dg).wrapping_add(rb >> 4).wrapping_sub(8),
+                        g.wrapping_add(dg),
+                        b.wrapping_add(dg).wrapping_add(rb & 0x0F).wrapping_sub(8),
+                        a,
+                    ]
+                }
+                _ => {
+                    // QOI_OP_RUN, this pixel is the first of the run
+                    self.run = op & 0x3F;
+                    self.px
+                }
+            },
+        };
+        let [r, g, b, a] = self.px;
+        let hash = (r as usize * 3 + g as usize * 5 + b as usize * 7 + a as usize * 11) % 64;
+        self.index[hash] = self.px;
+        Ok(self.px)
+    }
+}
+
+impl ImageDecoder for Qoi<'_> {
+    fn width(&self) -> u16 {
+        self.width
+    }
+
+    fn height(&self) -> u16 {
+        self.height
+    }
+
+    fn next_row(&mut self, row: &mut [Rgb565]) -> Result<(), ImageError> {
+        for pixel in row.iter_mut() {
+            let [r, g, b, a] = self.next_pixel()?;
+            let color = Rgb565::from_rgb888(r, g, b);
+            *pixel = match a {
+                255 => color,
+                0 => self.background,
+                _ => color.blend(self.background, a),
+            };
+        }
+        Ok(())
+    }
+}
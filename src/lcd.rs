@@ -1,121 +1,288 @@
+pub mod error;
+pub mod format;
+pub mod interface;
+pub mod panel;
+pub mod power;
+pub mod tuning;
+pub mod tearing;
+pub mod status;
+pub mod shapes;
+
 use alloc::vec;
 use alloc::vec::Vec;
 use alloc::str;
+use core::iter;
 
+use crate::hal::dmac::Channel;
 use crate::hal::gpiohs;
-use crate::hal::spi::{Spi, WORK_MODE_A, FRAME_FORMAT_A, AITM_A};
-use crate::hal::dmac::{Dmac, Channel};
-use crate::hal::utils::usleep;
+use crate::backlight::Backlight;
+use crate::color::Rgb565;
+use crate::font::{Font, Glyph};
 use crate::constant::*;
 
+pub use self::error::LcdError;
+pub use self::format::PixelFormat;
+pub use self::interface::{LcdInterface, SpiInterface, FrameTransfer};
+pub use self::panel::{Panel, InitCmd};
+pub use self::power::PowerState;
+pub use self::tuning::{GammaCurve, Porch, FrameRate, PanelTuning};
+pub use self::status::PanelId;
+
 const RST: usize = 21;
 const DCX: usize = 22;
 
 const CHIP_SELECT: u32 = 3;
 
-pub struct Lcd {
-    pub rst: gpiohs::Gpiohs,
-    pub dcx: gpiohs::Gpiohs,
+// words (two pixels each) collected before a DMA burst is issued
+const BURST_WORDS: usize = 1024;
+
+/// Words a `width` x `height` picture packs into, two pixels per word.
+pub(crate) fn packed_words(width: u16, height: u16) -> usize {
+    (width as usize * height as usize).div_ceil(2)
+}
+
+pub struct Lcd<I: LcdInterface = SpiInterface> {
+    pub interface: I,
+    pub panel: Panel,
+    pub dir: Dir,
+    /// Logical width and height in the current orientation.
     pub max_x: u16,
     pub max_y: u16,
+    format: PixelFormat,
+    x_offset: u16,
+    y_offset: u16,
+    power: PowerState,
+    /// Whether the init sequence has run since the last reset.
+    initialized: bool,
+    backlight: Option<Backlight>,
+    /// TE input, see `enable_tearing_effect`.
+    te: Option<gpiohs::Gpiohs>,
 }
 
-impl Lcd {
-    pub fn new_lcd(max_x: u16, max_y: u16, dir: Dir) -> Self {
-        // gpiohs initial
-        let mut rst = gpiohs::Gpiohs::new(RST);
-        let mut dcx = gpiohs::Gpiohs::new(DCX);
-        rst.set_output();
-        dcx.set_output();
-
-        // reset lcd
-        rst.set_high();
-        usleep(50_000);
-        rst.set_low();
-        usleep(50_000);
-        rst.set_high();
-
-        let mut dma = Dmac {};
-        dma.init();
-
-        let mut lcd = Lcd { rst, dcx, max_x, max_y };
-        
-        lcd.send_command(0x1);
-        usleep(50_000);
-        lcd.send_command(0x11);
-        usleep(50_000);
-        lcd.send_command(0x3A);
-        lcd.send_byte(0x55);
-        usleep(10_000);
-        lcd.send_command(0x21);
-        usleep(10_000);
-        lcd.send_command(0x36);
-        lcd.send_byte(dir as u8);
-        usleep(10_000);
-        lcd.send_command(0x29);
-        usleep(10_000);
-        lcd.fill_rectangle(0, 0, max_x, max_y, WHITE);
-        
+impl Lcd<SpiInterface> {
+    /// Probe the panel on SPI0 and bring it up at `max_x` x `max_y` as
+    /// seen through `dir`. A detected controller of that size gets its own
+    /// profile, anything else is driven as an ST7789.
+    pub fn new_lcd(max_x: u16, max_y: u16, dir: Dir) -> Result<Self, LcdError> {
+        let mut interface = SpiInterface::new(RST, DCX, CHIP_SELECT, Channel::Ch0);
+        let id = PanelId::probe(&mut interface)?;
+        let (width, height) = if dir.is_swapped() { (max_y, max_x) } else { (max_x, max_y) };
+        let mut lcd = match Panel::from_id(&id) {
+            Some(panel) if panel.width == width && panel.height == height => Lcd::with_panel(interface, panel, dir),
+            _ => Lcd::with_interface(interface, max_x, max_y, dir),
+        };
+        lcd.verify_init()?;
+        Ok(lcd)
+    }
+
+    /// Probe the panel on SPI0 and use the matching built-in profile.
+    pub fn new_lcd_auto(dir: Dir) -> Result<Self, LcdError> {
+        let interface = SpiInterface::new(RST, DCX, CHIP_SELECT, Channel::Ch0);
+        Lcd::detect(interface, dir)
+    }
+
+    pub fn new_lcd_with_panel(panel: Panel, dir: Dir) -> Self {
+        let interface = SpiInterface::new(RST, DCX, CHIP_SELECT, Channel::Ch0);
+        Lcd::with_panel(interface, panel, dir)
+    }
+
+    /// Like `lcd_draw_picture` but returns as soon as the DMA has started.
+    /// The buffer comes back from `FrameTransfer::wait`, so it can be
+    /// refilled (e.g. by the camera) while the previous frame goes out.
+    ///
+    /// Only RGB565 goes out straight from the buffer; in the other pixel
+    /// formats the picture is converted and sent before this returns. On
+    /// error nothing is sent and `data` comes back with it.
+    pub fn lcd_draw_picture_async(&mut self, x: u16, y: u16, width: u16, height: u16, data: Vec<u32>) -> Result<FrameTransfer<'_>, (LcdError, Vec<u32>)> {
+        if let Err(e) = self.check_picture(x, y, width, height, data.len()) {
+            return Err((e, data));
+        }
+        // an odd last pixel must not go out as a whole word
+        if self.format != PixelFormat::Rgb565 || (width as usize * height as usize) % 2 == 1 {
+            self.send_picture(x, y, width, height, &data);
+            return Ok(FrameTransfer::skipped(data));
+        }
+        self.wait_for_tear();
+        self.set_window(x, y, x + width - 1, y + height - 1);
+        Ok(self.interface.data32_async(data))
+    }
+}
+
+impl<I: LcdInterface> Lcd<I> {
+    pub fn with_interface(interface: I, max_x: u16, max_y: u16, dir: Dir) -> Self {
+        // the caller gives the size as seen through `dir`
+        let (width, height) = if dir.is_swapped() { (max_y, max_x) } else { (max_x, max_y) };
+        let panel = Panel { width, height, ..panel::ST7789 };
+        Lcd::with_panel(interface, panel, dir)
+    }
+
+    /// Read the controller ID, pick the matching built-in profile (ST7789
+    /// if it is unknown) and check that the init sequence took.
+    pub fn detect(mut interface: I, dir: Dir) -> Result<Self, LcdError> {
+        let id = PanelId::probe(&mut interface)?;
+        let panel = Panel::from_id(&id).unwrap_or(panel::ST7789);
+        let mut lcd = Lcd::with_panel(interface, panel, dir);
+        lcd.verify_init()?;
+        Ok(lcd)
+    }
+
+    pub fn with_panel(interface: I, panel: Panel, dir: Dir) -> Self {
+        debug_assert!(panel.col_offset + panel.width <= panel.ram_width);
+        debug_assert!(panel.row_offset + panel.height <= panel.ram_height);
+        let mut lcd = Lcd {
+            interface, panel, dir,
+            max_x: panel.width, max_y: panel.height,
+            format: PixelFormat::Rgb565,
+            x_offset: 0, y_offset: 0,
+            power: PowerState::RESET,
+            initialized: false,
+            backlight: None,
+            te: None,
+        };
+        lcd.interface.reset();
+        lcd.run_init();
         lcd
     }
 
-    pub fn send_command(&mut self, cmd: u8) {
-        self.dcx.set_low();
-        let buf = vec![cmd as u32];
-        let mut spi = Spi::new_spi0(WORK_MODE_A::MODE0, FRAME_FORMAT_A::OCTAL, 8, 0, 10_000_000);
-        spi.set_non_standard_mode(8, 0, 0, AITM_A::AS_FRAME_FORMAT);
-        // spi.send_data(CHIP_SELECT, &buf);
-        spi.send_data_dma(CHIP_SELECT, buf.as_ptr() as u64, buf.len() as u32, Channel::Ch0);
+    /// Hardware reset. The controller comes back asleep with its power-on
+    /// registers and the tearing effect line off, and everything else
+    /// returns `NotInitialized` until `init` has run.
+    pub fn reset(&mut self) -> Result<(), LcdError> {
+        self.interface.reset();
+        self.initialized = false;
+        self.power = PowerState::RESET;
+        self.te = None;
+        Ok(())
     }
 
-    pub fn send_byte(&mut self, cmd: u8) {
-        self.dcx.set_high();
-        let buf = vec![cmd as u32];
-        let mut spi = Spi::new_spi0(WORK_MODE_A::MODE0, FRAME_FORMAT_A::OCTAL, 8, 0, 10_000_000);
-        spi.set_non_standard_mode(0, 8, 0, AITM_A::AS_FRAME_FORMAT);
-        spi.send_data_dma(CHIP_SELECT, buf.as_ptr() as u64, buf.len() as u32, Channel::Ch0);
+    /// Run the panel's init sequence again, e.g. after `reset`, keeping the
+    /// orientation and pixel format. The screen is cleared to white.
+    pub fn init(&mut self) -> Result<(), LcdError> {
+        self.run_init();
+        Ok(())
     }
 
-    pub fn send_bytes(&mut self, data: &Vec<u8>) {
-        self.dcx.set_high();
-        let mut spi = Spi::new_spi0(WORK_MODE_A::MODE0, FRAME_FORMAT_A::OCTAL, 8, 0, 10_000_000);
-        spi.set_non_standard_mode(0, 8, 0, AITM_A::AS_FRAME_FORMAT);
-        
-        let mut buf = vec![0; data.len()];
-        for i in 0..data.len() {
-            buf[i] = data[i] as u32;
+    fn run_init(&mut self) {
+        for &(cmd, params, delay_ms) in self.panel.init {
+            self.write_command(cmd, params);
+            if delay_ms > 0 {
+                self.interface.delay_us(delay_ms as usize * 1000);
+            }
         }
-        spi.send_data_dma(CHIP_SELECT, buf.as_ptr() as u64, buf.len() as u32, Channel::Ch0);
+
+        self.write_command(0x3A, &[self.format as u8]);
+        self.interface.delay_us(10_000);
+        self.send_command(if self.panel.invert { 0x21 } else { 0x20 });
+        self.interface.delay_us(10_000);
+        self.write_orientation(self.dir);
+        self.interface.delay_us(10_000);
+        self.send_command(0x29);
+        self.interface.delay_us(10_000);
+        self.power = PowerState::AWAKE;
+        self.initialized = true;
+        self.fill_window(0, 0, self.max_x - 1, self.max_y - 1, WHITE);
     }
 
-    pub fn send_shorts(&mut self, data: &Vec<u16>) {
-        self.dcx.set_high();
-        let mut spi = Spi::new_spi0(WORK_MODE_A::MODE0, FRAME_FORMAT_A::OCTAL, 16, 0, 10_000_000);
-        spi.set_non_standard_mode(0, 16, 0, AITM_A::AS_FRAME_FORMAT);
-        
-        let mut buf = vec![0; data.len()];
-        for i in 0..data.len() {
-            buf[i] = data[i] as u32;
+    /// Rewrite MADCTL and recompute the logical size and RAM offsets.
+    /// GRAM content is not redrawn.
+    pub fn set_orientation(&mut self, dir: Dir) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.write_orientation(dir);
+        Ok(())
+    }
+
+    fn write_orientation(&mut self, dir: Dir) {
+        let madctl = dir as u8 | self.panel.madctl;
+        self.write_command(0x36, &[madctl]);
+        self.dir = dir;
+        if dir.is_swapped() {
+            self.max_x = self.panel.height;
+            self.max_y = self.panel.width;
+        } else {
+            self.max_x = self.panel.width;
+            self.max_y = self.panel.height;
         }
-        spi.send_data_dma(CHIP_SELECT, data.as_ptr() as u64, data.len() as u32, Channel::Ch0);
+        let (x_offset, y_offset) = self.panel.offsets(madctl);
+        self.x_offset = x_offset;
+        self.y_offset = y_offset;
+    }
+
+    /// Send a command `Lcd` has no method for, e.g. a vendor register,
+    /// followed by its parameter bytes. `Lcd` doesn't know what it does,
+    /// so one that changes state it tracks, like MADCTL, COLMOD or SLPIN,
+    /// leaves it out of step.
+    pub fn command(&mut self, cmd: u8, params: &[u8]) -> Result<(), LcdError> {
+        self.ready()?;
+        self.write_command(cmd, params);
+        Ok(())
     }
 
-    pub fn send_words(&mut self, data: &Vec<u32>) {
-        self.dcx.set_high();
-        let mut spi = Spi::new_spi0(WORK_MODE_A::MODE0, FRAME_FORMAT_A::OCTAL, 32, 0, 10_000_000);
-        spi.set_non_standard_mode(0, 32, 0, AITM_A::AS_FRAME_FORMAT);
-        spi.send_data_dma(CHIP_SELECT, data.as_ptr() as u64, data.len() as u32, Channel::Ch0);
+    /// Send a command followed by its parameter bytes.
+    pub(crate) fn write_command(&mut self, cmd: u8, params: &[u8]) {
+        self.send_command(cmd);
+        self.send_bytes(params);
     }
 
-    pub fn fill_data(&mut self, value: u32, len: usize) {
-        self.dcx.set_high();
-        let mut spi = Spi::new_spi0(WORK_MODE_A::MODE0, FRAME_FORMAT_A::OCTAL, 32, 0, 10_000_000);
-        spi.set_non_standard_mode(0, 32, 0, AITM_A::AS_FRAME_FORMAT);
-        let buf = vec![value; len];
-        spi.send_data_dma(CHIP_SELECT, buf.as_ptr() as u64, len as u32, Channel::Ch0);
+    pub(crate) fn send_command(&mut self, cmd: u8) {
+        self.interface.command(cmd);
     }
 
-    pub fn lcd_set_area(&mut self, x1: u16, y1: u16, x2: u16, y2: u16) {
+    pub(crate) fn send_bytes(&mut self, data: &[u8]) {
+        self.interface.data8(data);
+    }
+
+    pub(crate) fn send_words(&mut self, data: &[u32]) {
+        if !self.can_write() { return };
+        self.interface.data32(data);
+    }
+
+    pub(crate) fn fill_data(&mut self, value: u32, len: usize) {
+        if !self.can_write() { return };
+        self.interface.fill(value, len);
+    }
+
+    /// VSCRDEF: split the RAM rows into a fixed top band, a scrolling band
+    /// and a fixed bottom band, which together must cover the RAM height.
+    /// Scrolling runs along the native rows whatever the orientation.
+    pub fn define_scroll_area(&mut self, top_fixed: u16, scroll_height: u16, bottom_fixed: u16) -> Result<(), LcdError> {
+        self.initialized()?;
+        if top_fixed as u32 + scroll_height as u32 + bottom_fixed as u32 != self.panel.ram_height as u32 {
+            return Err(LcdError::OutOfBounds);
+        }
+        self.write_command(0x33, &[
+            (top_fixed >> 8) as u8, top_fixed as u8,
+            (scroll_height >> 8) as u8, scroll_height as u8,
+            (bottom_fixed >> 8) as u8, bottom_fixed as u8,
+        ]);
+        Ok(())
+    }
+
+    /// VSCRSADD: RAM row shown on the first line of the scrolling band.
+    pub fn scroll_to(&mut self, line: u16) -> Result<(), LcdError> {
+        self.initialized()?;
+        if line >= self.panel.ram_height {
+            return Err(LcdError::OutOfBounds);
+        }
+        self.write_command(0x37, &[(line >> 8) as u8, line as u8]);
+        Ok(())
+    }
+
+    /// CASET/RASET/RAMWR: open `x1..=x2` x `y1..=y2` for `send_pixels` or
+    /// `fill_color`. The window has to lie on screen in the current
+    /// orientation.
+    pub fn lcd_set_area(&mut self, x1: u16, y1: u16, x2: u16, y2: u16) -> Result<(), LcdError> {
+        self.ready()?;
+        if x1 > x2 || y1 > y2 || x2 >= self.max_x || y2 >= self.max_y {
+            return Err(LcdError::OutOfBounds);
+        }
+        self.set_window(x1, y1, x2, y2);
+        Ok(())
+    }
+
+    /// `lcd_set_area` for a window already clipped to the screen.
+    pub(crate) fn set_window(&mut self, x1: u16, y1: u16, x2: u16, y2: u16) {
+        let (x1, x2) = (x1 + self.x_offset, x2 + self.x_offset);
+        let (y1, y2) = (y1 + self.y_offset, y2 + self.y_offset);
         let mut buf: Vec<u8> = vec![0; 4];
         buf[0] = (x1 >> 8) as u8;
         buf[1] = x1 as u8;
@@ -134,45 +301,125 @@ impl Lcd {
         self.send_command(0x2C);
     }
 
-    pub fn fill_rectangle(&mut self, x1: u16, y1: u16, x2: u16, y2: u16, color: u16) {
-        if x1 == x2 || y1 == y2 {return};
-        self.lcd_set_area(x1, y1, x2-1, y2-1);
-        let color = (color as u32) << 16 | color as u32;
-        self.fill_data(color as u32, (((x2 - x1) as u32) * ((y2 - y1) as u32) / 2) as usize);
+    /// Fill `x1..x2` x `y1..y2`. The far corner has to be below and right
+    /// of the near one and no further out than the screen edge.
+    pub fn fill_rectangle(&mut self, x1: u16, y1: u16, x2: u16, y2: u16, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if x1 >= x2 || y1 >= y2 || x2 > self.max_x || y2 > self.max_y {
+            return Err(LcdError::OutOfBounds);
+        }
+        self.fill_window(x1, y1, x2 - 1, y2 - 1, color);
+        Ok(())
     }
 
-    pub fn lcd_draw_point(&mut self, x: u16, y: u16, color: u16) {
-        self.lcd_set_area(x, y, x, y);
-        let buf = vec![(color >> 8) as u8, color as u8];
-        self.send_bytes(&buf)
+    /// Fill a window already clipped to the screen, corners inclusive.
+    pub(crate) fn fill_window(&mut self, x1: u16, y1: u16, x2: u16, y2: u16, color: Rgb565) {
+        self.set_window(x1, y1, x2, y2);
+        self.write_color(color, (x2 - x1 + 1) as usize * (y2 - y1 + 1) as usize);
     }
 
-    pub fn lcd_draw_char(&mut self, x: u16, y: u16, c: char, color: u16) {
-        let mut my = y;
-        for i in 0..16 {
-            let index = (c as usize) * 16 + i;
-            let mut data = ASCII0816[index];
-            for j in 0..8 {
-                if data & 0x80 > 0 {
-                    self.lcd_draw_point(x + j, my, color);
-                }
-                data = data << 1;
-            }
-            my += 1;
+    pub fn lcd_draw_point(&mut self, x: u16, y: u16, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if x >= self.max_x || y >= self.max_y {
+            return Err(LcdError::OutOfBounds);
         }
+        self.set_window(x, y, x, y);
+        self.send_pixel(color);
+        Ok(())
     }
 
-    pub fn lcd_draw_string(&mut self, x: u16, y: u16, s: &str, color: u16) {
-        let mut mx = x;
-        for &c in s.as_bytes() {
-            self.lcd_draw_char(mx, y, c as char, color);
-            mx = mx + 8;
+    pub fn lcd_draw_char<F: Font + ?Sized>(&mut self, x: u16, y: u16, c: char, font: &F, fg: Rgb565, bg: Rgb565) -> Result<(), LcdError> {
+        let mut buf = [0u8; 4];
+        self.draw_text(x, y, c.encode_utf8(&mut buf), font, fg, bg, 1)
+    }
+
+    /// Draw `s` in `font`, set bits in `fg` and the rest of each cell in
+    /// `bg`. Anti-aliased fonts blend their edges from one into the other,
+    /// so `bg` should match what is around the text. Characters the font
+    /// lacks get its fallback glyph, see `Font::fallback`. The whole line
+    /// goes out as one window, cut off at the right and bottom edge; `x`,
+    /// `y` itself has to be on screen.
+    pub fn lcd_draw_string<F: Font + ?Sized>(&mut self, x: u16, y: u16, s: &str, font: &F, fg: Rgb565, bg: Rgb565) -> Result<(), LcdError> {
+        self.draw_text(x, y, s, font, fg, bg, 1)
+    }
+
+    /// `lcd_draw_string` with every font pixel drawn as a `scale` x `scale`
+    /// block.
+    #[allow(clippy::too_many_arguments)]
+    pub fn lcd_draw_string_scaled<F: Font + ?Sized>(&mut self, x: u16, y: u16, s: &str, font: &F, fg: Rgb565, bg: Rgb565, scale: u16) -> Result<(), LcdError> {
+        self.draw_text(x, y, s, font, fg, bg, scale)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw_text<F: Font + ?Sized>(&mut self, x: u16, y: u16, text: &str, font: &F, fg: Rgb565, bg: Rgb565, scale: u16) -> Result<(), LcdError> {
+        self.ready()?;
+        if x >= self.max_x || y >= self.max_y {
+            return Err(LcdError::OutOfBounds);
+        }
+        let fallback = font.fallback();
+        let glyphs: Vec<Glyph> = text.chars().filter_map(|c| font.glyph(c).or(fallback)).collect();
+        let width: usize = glyphs.iter().map(|g| g.width as usize).sum();
+        if scale == 0 || width == 0 || font.height() == 0 { return Ok(()) };
+        let scale = scale as usize;
+        let x2 = (x as usize + width * scale).min(self.max_x as usize) as u16;
+        let y2 = (y as usize + font.height() as usize * scale).min(self.max_y as usize) as u16;
+        self.set_window(x, y, x2 - 1, y2 - 1);
+
+        // rasterized row by row straight into the DMA bursts
+        let glyphs = &glyphs;
+        let pixels = (0..(y2 - y) as usize).flat_map(move |row| {
+            let row = (row / scale) as u8;
+            glyphs
+                .iter()
+                .flat_map(move |g| (0..g.width).map(move |col| g.alpha(col, row)))
+                .flat_map(move |alpha| {
+                    let color = match alpha {
+                        0 => bg,
+                        255 => fg,
+                        _ => fg.blend(bg, alpha),
+                    };
+                    iter::repeat_n(color, scale)
+                })
+                .take((x2 - x) as usize)
+        });
+        self.write_pixels(pixels);
+        Ok(())
+    }
+
+    /// `data` holds RGB565 pixels packed two per word, left pixel in the
+    /// high half: `width * height` pixels, the last word half used if that
+    /// is odd. The picture has to fit on screen. Other pixel formats are
+    /// converted on the fly.
+    pub fn lcd_draw_picture(&mut self, x:u16, y:u16, width: u16, height: u16, data: &[u32]) -> Result<(), LcdError> {
+        self.check_picture(x, y, width, height, data.len())?;
+        self.send_picture(x, y, width, height, data);
+        Ok(())
+    }
+
+    fn check_picture(&self, x: u16, y: u16, width: u16, height: u16, words: usize) -> Result<(), LcdError> {
+        self.ready()?;
+        if width == 0 || height == 0
+            || x as u32 + width as u32 > self.max_x as u32
+            || y as u32 + height as u32 > self.max_y as u32
+        {
+            return Err(LcdError::OutOfBounds);
+        }
+        if words != packed_words(width, height) {
+            return Err(LcdError::BufferSizeMismatch);
         }
+        Ok(())
     }
 
-    pub fn lcd_draw_picture(&mut self, x:u16, y:u16, width: u16, height: u16, data: &Vec<u32>) {
-        self.lcd_set_area(x, y, x + width - 1, y + height - 1);
-        self.send_words(data);
+    /// `lcd_draw_picture` once `check_picture` has passed.
+    fn send_picture(&mut self, x:u16, y:u16, width: u16, height: u16, data: &[u32]) {
+        self.wait_for_tear();
+        self.set_window(x, y, x + width - 1, y + height - 1);
+        let pixels = width as usize * height as usize;
+        if self.format != PixelFormat::Rgb565 || pixels % 2 == 1 {
+            self.write_pixels(data.iter().flat_map(|&w| [Rgb565((w >> 16) as u16), Rgb565(w as u16)]).take(pixels));
+        } else {
+            self.send_words(data);
+        }
     }
     
 }
@@ -0,0 +1,22 @@
+/// Why an `Lcd` operation failed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LcdError {
+    /// The interface cannot read from the controller.
+    BusError,
+    /// Nothing answered the ID and status reads.
+    NoPanel,
+    /// The controller answered, but did not come out of the init sequence
+    /// awake with the display on.
+    InitFailed,
+    /// A coordinate or window reaches past the screen in the current
+    /// orientation, or its end comes before its start.
+    OutOfBounds,
+    /// A pixel buffer doesn't hold exactly the pixels of its window.
+    BufferSizeMismatch,
+    /// The controller was reset and hasn't been through its init sequence
+    /// since, see `Lcd::init`.
+    NotInitialized,
+    /// The controller is asleep and isn't taking pixel data, see
+    /// `Lcd::wake`.
+    Asleep,
+}
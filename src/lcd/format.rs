@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+
+use super::{Lcd, LcdError, LcdInterface};
+use crate::color::Rgb565;
+use crate::constant::BLACK;
+
+/// Interface pixel format, the value written to COLMOD (3Ah).
+///
+/// Pixels are always handed to `Lcd` as `Rgb565` and converted on the way
+/// out, so the same drawing code works in every format.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PixelFormat {
+    /// 12 bit, two pixels in three bytes. Least bus traffic, for UIs.
+    Rgb444 = 0x53,
+    /// 16 bit, two pixels per 32 bit DMA word.
+    Rgb565 = 0x55,
+    /// 18 bit, three bytes per pixel, for photographic content.
+    Rgb666 = 0x66,
+}
+
+impl PixelFormat {
+    pub fn bits_per_pixel(self) -> u8 {
+        match self {
+            PixelFormat::Rgb444 => 12,
+            PixelFormat::Rgb565 => 16,
+            PixelFormat::Rgb666 => 18,
+        }
+    }
+}
+
+impl<I: LcdInterface> Lcd<I> {
+    pub fn pixel_format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// COLMOD. Only changes how later pixels are sent, GRAM is kept.
+    pub fn set_pixel_format(&mut self, format: PixelFormat) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.write_command(0x3A, &[format as u8]);
+        self.format = format;
+        Ok(())
+    }
+
+    /// Send `count` pixels of `color` into the window opened by
+    /// `lcd_set_area`.
+    pub fn fill_color(&mut self, color: Rgb565, count: usize) -> Result<(), LcdError> {
+        self.ready()?;
+        self.write_color(color, count);
+        Ok(())
+    }
+
+    /// Stream pixels into the window opened by `lcd_set_area` in the
+    /// current pixel format.
+    pub fn send_pixels<P: IntoIterator<Item = Rgb565>>(&mut self, pixels: P) -> Result<(), LcdError> {
+        self.ready()?;
+        self.write_pixels(pixels);
+        Ok(())
+    }
+
+    /// `fill_color` for callers that already checked `ready`.
+    pub(crate) fn write_color(&mut self, color: Rgb565, count: usize) {
+        if !self.can_write() { return };
+        match self.format {
+            PixelFormat::Rgb565 => self.fill_data(Rgb565::pack(color, color), count / 2),
+            PixelFormat::Rgb444 => self.interface.fill24(Rgb565::pack444(color, color), count / 2),
+            PixelFormat::Rgb666 => {
+                self.interface.fill24(color.pack666(), count);
+                return;
+            }
+        }
+        if count % 2 == 1 {
+            self.send_pixel(color);
+        }
+    }
+
+    /// `send_pixels` for callers that already checked `ready`.
+    pub(crate) fn write_pixels<P: IntoIterator<Item = Rgb565>>(&mut self, pixels: P) {
+        if !self.can_write() { return };
+        let format = self.format;
+        let mut buf: Vec<u32> = Vec::with_capacity(super::BURST_WORDS);
+        let mut pending: Option<Rgb565> = None;
+        for color in pixels {
+            let word = match (format, pending.take()) {
+                (PixelFormat::Rgb666, _) => color.pack666(),
+                (_, None) => {
+                    pending = Some(color);
+                    continue;
+                }
+                (PixelFormat::Rgb565, Some(first)) => Rgb565::pack(first, color),
+                (PixelFormat::Rgb444, Some(first)) => Rgb565::pack444(first, color),
+            };
+            buf.push(word);
+            if buf.len() == super::BURST_WORDS {
+                self.send_packed(&buf);
+                buf.clear();
+            }
+        }
+        if !buf.is_empty() {
+            self.send_packed(&buf);
+        }
+        if let Some(last) = pending {
+            self.send_pixel(last);
+        }
+    }
+
+    /// A single pixel on its own, e.g. the odd one at the end of a window.
+    pub(crate) fn send_pixel(&mut self, color: Rgb565) {
+        match self.format {
+            PixelFormat::Rgb565 => self.send_bytes(&color.to_be_bytes()),
+            PixelFormat::Rgb666 => self.send_bytes(&color.pack666().to_be_bytes()[1..]),
+            // the first 12 bits complete the pixel, the rest is dropped
+            PixelFormat::Rgb444 => self.send_bytes(&Rgb565::pack444(color, BLACK).to_be_bytes()[1..3]),
+        }
+    }
+
+    /// Words as built by `send_pixels`: 32 bit for RGB565, 24 bit otherwise.
+    fn send_packed(&mut self, words: &[u32]) {
+        match self.format {
+            PixelFormat::Rgb565 => self.send_words(words),
+            _ => self.interface.data24(words),
+        }
+    }
+}
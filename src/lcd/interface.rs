@@ -0,0 +1,237 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::hal::gpiohs;
+use crate::hal::spi::{Spi, DmaTransfer, WORK_MODE_A, FRAME_FORMAT_A, AITM_A, TMOD_A};
+use crate::hal::dmac::{Dmac, Channel};
+use crate::hal::utils::usleep;
+use super::LcdError;
+
+// reads are specified much slower than writes
+const READ_BAUD: u32 = 2_000_000;
+
+/// Bus used by `Lcd` to talk to the panel controller.
+///
+/// `command` is sent with DCX low, every data method with DCX high. Pixel
+/// words are sent most significant half first, so a `u32` carries two
+/// RGB565 pixels with the left one in the high half.
+pub trait LcdInterface {
+    /// Pulse the hardware reset line, if the bus has one.
+    fn reset(&mut self) {}
+
+    fn delay_us(&mut self, usec: usize);
+
+    fn command(&mut self, cmd: u8);
+
+    fn data8(&mut self, data: &[u8]);
+
+    fn data16(&mut self, data: &[u16]);
+
+    fn data32(&mut self, data: &[u32]);
+
+    /// Send `value` as a 32 bit data word `len` times.
+    fn fill(&mut self, value: u32, len: usize);
+
+    /// Send the low 24 bits of every item, most significant byte first.
+    /// Used by the RGB666 and RGB444 pixel formats.
+    fn data24(&mut self, data: &[u32]) {
+        let mut bytes = Vec::with_capacity(data.len() * 3);
+        for &v in data {
+            bytes.extend_from_slice(&v.to_be_bytes()[1..]);
+        }
+        self.data8(&bytes);
+    }
+
+    /// Send `value` as a 24 bit data word `len` times.
+    fn fill24(&mut self, value: u32, len: usize) {
+        self.data24(&vec![value; len]);
+    }
+
+    /// Send a read command and fill `buf` with the parameter bytes the
+    /// controller returns, skipping the dummy clock cycles some reads
+    /// start with. Write only buses keep the default.
+    fn read(&mut self, _cmd: u8, _buf: &mut [u8]) -> Result<(), LcdError> {
+        Err(LcdError::BusError)
+    }
+}
+
+/// Clock cycles the controller leaves between a read command and its
+/// data on the serial interface: one before the 24 and 32 bit answers of
+/// RDDID and RDDST, none before the single byte ones.
+fn dummy_cycles(cmd: u8) -> u8 {
+    match cmd {
+        0x04 | 0x09 => 1,
+        _ => 0,
+    }
+}
+
+/// SPI0 in octal mode with DMA, RST and DCX on GPIOHS pins.
+pub struct SpiInterface {
+    pub rst: gpiohs::Gpiohs,
+    pub dcx: gpiohs::Gpiohs,
+    pub cs: u32,
+    pub channel: Channel,
+    pub baud: u32,
+}
+
+impl SpiInterface {
+    pub fn new(rst: usize, dcx: usize, cs: u32, channel: Channel) -> Self {
+        // gpiohs initial
+        let mut rst = gpiohs::Gpiohs::new(rst);
+        let mut dcx = gpiohs::Gpiohs::new(dcx);
+        rst.set_output();
+        dcx.set_output();
+        rst.set_high();
+
+        let mut dma = Dmac {};
+        dma.init();
+
+        SpiInterface { rst, dcx, cs, channel, baud: 10_000_000 }
+    }
+
+    fn spi(&self, frame_len: u8, instruction_len: u32, addr_len: u32) -> Spi {
+        let mut spi = Spi::new_spi0(WORK_MODE_A::MODE0, FRAME_FORMAT_A::OCTAL, frame_len, 0, self.baud);
+        spi.set_non_standard_mode(instruction_len, addr_len, 0, AITM_A::AS_FRAME_FORMAT);
+        spi
+    }
+
+    /// Start sending `data` as 32 bit data words and return straight away.
+    /// The bus stays borrowed until the returned transfer is finished.
+    pub fn data32_async(&mut self, data: Vec<u32>) -> FrameTransfer<'_> {
+        self.dcx.set_high();
+        let spi = self.spi(32, 0, 32);
+        FrameTransfer {
+            transfer: Pending::Dma(spi.send_data_dma_async(self.cs, data, self.channel)),
+            _bus: PhantomData,
+        }
+    }
+}
+
+enum Pending {
+    Dma(DmaTransfer),
+    /// Already sent without DMA, e.g. in a pixel format that needs
+    /// converting.
+    Skipped(Vec<u32>),
+}
+
+/// Pixel data on its way to the panel, see `SpiInterface::data32_async`.
+pub struct FrameTransfer<'a> {
+    transfer: Pending,
+    _bus: PhantomData<&'a mut SpiInterface>,
+}
+
+impl<'a> FrameTransfer<'a> {
+    /// A transfer that already finished synchronously and just holds on
+    /// to the buffer.
+    pub(crate) fn skipped(data: Vec<u32>) -> Self {
+        FrameTransfer { transfer: Pending::Skipped(data), _bus: PhantomData }
+    }
+
+    pub fn is_done(&mut self) -> bool {
+        match &mut self.transfer {
+            Pending::Dma(transfer) => transfer.is_done(),
+            Pending::Skipped(_) => true,
+        }
+    }
+
+    /// Block until the panel has the whole buffer and hand it back.
+    pub fn wait(self) -> Vec<u32> {
+        match self.transfer {
+            Pending::Dma(transfer) => transfer.wait(),
+            Pending::Skipped(data) => data,
+        }
+    }
+}
+
+impl LcdInterface for SpiInterface {
+    fn reset(&mut self) {
+        self.rst.set_high();
+        usleep(50_000);
+        self.rst.set_low();
+        usleep(50_000);
+        self.rst.set_high();
+    }
+
+    fn delay_us(&mut self, usec: usize) {
+        usleep(usec);
+    }
+
+    fn command(&mut self, cmd: u8) {
+        self.dcx.set_low();
+        let buf = [cmd as u32];
+        let mut spi = self.spi(8, 8, 0);
+        spi.send_data_dma(self.cs, buf.as_ptr() as u64, buf.len() as u32, self.channel);
+    }
+
+    fn data8(&mut self, data: &[u8]) {
+        if data.is_empty() { return };
+        self.dcx.set_high();
+        let mut spi = self.spi(8, 0, 8);
+
+        let mut buf = vec![0u32; data.len()];
+        for i in 0..data.len() {
+            buf[i] = data[i] as u32;
+        }
+        spi.send_data_dma(self.cs, buf.as_ptr() as u64, buf.len() as u32, self.channel);
+    }
+
+    fn data16(&mut self, data: &[u16]) {
+        if data.is_empty() { return };
+        self.dcx.set_high();
+        let mut spi = self.spi(16, 0, 16);
+
+        // dma always moves 32 bit items, one frame each
+        let mut buf = vec![0u32; data.len()];
+        for i in 0..data.len() {
+            buf[i] = data[i] as u32;
+        }
+        spi.send_data_dma(self.cs, buf.as_ptr() as u64, buf.len() as u32, self.channel);
+    }
+
+    fn data32(&mut self, data: &[u32]) {
+        if data.is_empty() { return };
+        self.dcx.set_high();
+        let mut spi = self.spi(32, 0, 32);
+        spi.send_data_dma(self.cs, data.as_ptr() as u64, data.len() as u32, self.channel);
+    }
+
+    fn fill(&mut self, value: u32, len: usize) {
+        if len == 0 { return };
+        self.dcx.set_high();
+        let mut spi = self.spi(32, 0, 32);
+        let buf = vec![value; len];
+        spi.send_data_dma(self.cs, buf.as_ptr() as u64, len as u32, self.channel);
+    }
+
+    fn data24(&mut self, data: &[u32]) {
+        if data.is_empty() { return };
+        self.dcx.set_high();
+        let mut spi = self.spi(24, 0, 24);
+        spi.send_data_dma(self.cs, data.as_ptr() as u64, data.len() as u32, self.channel);
+    }
+
+    fn fill24(&mut self, value: u32, len: usize) {
+        if len == 0 { return };
+        self.dcx.set_high();
+        let mut spi = self.spi(24, 0, 24);
+        let buf = vec![value; len];
+        spi.send_data_dma(self.cs, buf.as_ptr() as u64, len as u32, self.channel);
+    }
+
+    fn read(&mut self, cmd: u8, buf: &mut [u8]) -> Result<(), LcdError> {
+        if buf.is_empty() { return Ok(()) };
+        // DCX stays low, the controller ignores it while driving the bus
+        self.dcx.set_low();
+        let mut spi = Spi::new_spi0(WORK_MODE_A::MODE0, FRAME_FORMAT_A::OCTAL, 8, 0, self.baud.min(READ_BAUD));
+        spi.set_non_standard_mode(8, 0, dummy_cycles(cmd), AITM_A::AS_FRAME_FORMAT);
+        spi.set_transfer_mode(TMOD_A::RECV);
+
+        let mut rx = vec![0u32; buf.len()];
+        spi.recv_data(self.cs, &[cmd as u32], &mut rx).map_err(|_| LcdError::BusError)?;
+        for i in 0..buf.len() {
+            buf[i] = rx[i] as u8;
+        }
+        Ok(())
+    }
+}
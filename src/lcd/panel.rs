@@ -0,0 +1,253 @@
+use super::status::PanelId;
+
+/// One step of a controller init sequence: command, parameters and the
+/// time to wait afterwards in milliseconds.
+pub type InitCmd = (u8, &'static [u8], u32);
+
+/// Everything `Lcd` needs to know about a display controller and its glass.
+///
+/// The init table only has to wake the controller and load its vendor
+/// registers; `Lcd` sends COLMOD, INVON/INVOFF, MADCTL and DISPON itself.
+/// A custom profile can start from a built-in one:
+///
+/// ```ignore
+/// const MY_INIT: [InitCmd; 3] = [(0x01, &[], 150), (0x11, &[], 120), (0xB2, &[0x0C, 0x0C, 0x00, 0x33, 0x33], 0)];
+/// let panel = Panel { name: "my st7789", init: &MY_INIT, ..ST7789 };
+/// ```
+#[derive(Clone, Copy)]
+pub struct Panel {
+    pub name: &'static str,
+    pub init: &'static [InitCmd],
+    /// Glass needs INVON to show true colours (IPS panels).
+    pub invert: bool,
+    /// Extra MADCTL bits OR-ed onto the `Dir` value, e.g. 0x08 for BGR.
+    pub madctl: u8,
+    /// Where the visible area starts inside the controller RAM.
+    pub col_offset: u16,
+    pub row_offset: u16,
+    /// Visible resolution with MADCTL = 0.
+    pub width: u16,
+    pub height: u16,
+    /// Size of the controller frame memory, which can be larger than the
+    /// glass. Mirrored orientations address the far end of it.
+    pub ram_width: u16,
+    pub ram_height: u16,
+}
+
+impl Panel {
+    /// Column and row address of the visible origin for a MADCTL value.
+    pub fn offsets(&self, madctl: u8) -> (u16, u16) {
+        let mx = madctl & 0x40 != 0;
+        let my = madctl & 0x80 != 0;
+        let mv = madctl & 0x20 != 0;
+        // with MV the column address runs along the native rows
+        let flip_x = if mv { my } else { mx };
+        let flip_y = if mv { mx } else { my };
+        let x = if flip_x { self.ram_width - self.width - self.col_offset } else { self.col_offset };
+        let y = if flip_y { self.ram_height - self.height - self.row_offset } else { self.row_offset };
+        if mv { (y, x) } else { (x, y) }
+    }
+
+    /// Built-in profile for a controller that answered RDDID or RDID4
+    /// with `id`. The glass size can't be read back, so modules sharing a
+    /// controller get its most common size.
+    pub fn from_id(id: &PanelId) -> Option<Panel> {
+        match id.ic {
+            0x9341 => return Some(ILI9341),
+            0x9488 => return Some(ILI9488),
+            _ => {}
+        }
+        match (id.version, id.driver) {
+            (0x85, 0x52) => Some(ST7789),
+            (0x80, 0x66) => Some(ILI9488),
+            (0x9A, 0x01) => Some(GC9A01),
+            (0x89, 0xF0) => Some(ST7735),
+            _ => None,
+        }
+    }
+}
+
+const ST7789_INIT: [InitCmd; 2] = [
+    (0x01, &[], 50),
+    (0x11, &[], 50),
+];
+
+const ILI9341_INIT: [InitCmd; 19] = [
+    (0x01, &[], 150),
+    (0xEF, &[0x03, 0x80, 0x02], 0),
+    (0xCF, &[0x00, 0xC1, 0x30], 0),
+    (0xED, &[0x64, 0x03, 0x12, 0x81], 0),
+    (0xE8, &[0x85, 0x00, 0x78], 0),
+    (0xCB, &[0x39, 0x2C, 0x00, 0x34, 0x02], 0),
+    (0xF7, &[0x20], 0),
+    (0xEA, &[0x00, 0x00], 0),
+    (0xC0, &[0x23], 0),
+    (0xC1, &[0x10], 0),
+    (0xC5, &[0x3E, 0x28], 0),
+    (0xC7, &[0x86], 0),
+    (0xB1, &[0x00, 0x18], 0),
+    (0xB6, &[0x08, 0x82, 0x27], 0),
+    (0xF2, &[0x00], 0),
+    (0x26, &[0x01], 0),
+    (0xE0, &[0x0F, 0x31, 0x2B, 0x0C, 0x0E, 0x08, 0x4E, 0xF1, 0x37, 0x07, 0x10, 0x03, 0x0E, 0x09, 0x00], 0),
+    (0xE1, &[0x00, 0x0E, 0x14, 0x03, 0x11, 0x07, 0x31, 0xC1, 0x48, 0x08, 0x0F, 0x0C, 0x31, 0x36, 0x0F], 0),
+    (0x11, &[], 120),
+];
+
+const ILI9488_INIT: [InitCmd; 13] = [
+    (0x01, &[], 120),
+    (0xE0, &[0x00, 0x03, 0x09, 0x08, 0x16, 0x0A, 0x3F, 0x78, 0x4C, 0x09, 0x0A, 0x08, 0x16, 0x1A, 0x0F], 0),
+    (0xE1, &[0x00, 0x16, 0x19, 0x03, 0x0F, 0x05, 0x32, 0x45, 0x46, 0x04, 0x0E, 0x0D, 0x35, 0x37, 0x0F], 0),
+    (0xC0, &[0x17, 0x15], 0),
+    (0xC1, &[0x41], 0),
+    (0xC5, &[0x00, 0x12, 0x80], 0),
+    (0xB0, &[0x00], 0),
+    (0xB1, &[0xA0], 0),
+    (0xB4, &[0x02], 0),
+    (0xB6, &[0x02, 0x02, 0x3B], 0),
+    (0xB7, &[0xC6], 0),
+    (0xF7, &[0xA9, 0x51, 0x2C, 0x82], 0),
+    (0x11, &[], 120),
+];
+
+const GC9A01_INIT: [InitCmd; 45] = [
+    (0xEF, &[], 0),
+    (0xEB, &[0x14], 0),
+    (0xFE, &[], 0),
+    (0xEF, &[], 0),
+    (0xEB, &[0x14], 0),
+    (0x84, &[0x40], 0),
+    (0x85, &[0xFF], 0),
+    (0x86, &[0xFF], 0),
+    (0x87, &[0xFF], 0),
+    (0x88, &[0x0A], 0),
+    (0x89, &[0x21], 0),
+    (0x8A, &[0x00], 0),
+    (0x8B, &[0x80], 0),
+    (0x8C, &[0x01], 0),
+    (0x8D, &[0x01], 0),
+    (0x8E, &[0xFF], 0),
+    (0x8F, &[0xFF], 0),
+    (0xB6, &[0x00, 0x20], 0),
+    (0x90, &[0x08, 0x08, 0x08, 0x08], 0),
+    (0xBD, &[0x06], 0),
+    (0xBC, &[0x00], 0),
+    (0xFF, &[0x60, 0x01, 0x04], 0),
+    (0xC3, &[0x13], 0),
+    (0xC4, &[0x13], 0),
+    (0xC9, &[0x22], 0),
+    (0xBE, &[0x11], 0),
+    (0xE1, &[0x10, 0x0E], 0),
+    (0xDF, &[0x21, 0x0C, 0x02], 0),
+    (0xF0, &[0x45, 0x09, 0x08, 0x08, 0x26, 0x2A], 0),
+    (0xF1, &[0x43, 0x70, 0x72, 0x36, 0x37, 0x6F], 0),
+    (0xF2, &[0x45, 0x09, 0x08, 0x08, 0x26, 0x2A], 0),
+    (0xF3, &[0x43, 0x70, 0x72, 0x36, 0x37, 0x6F], 0),
+    (0xED, &[0x1B, 0x0B], 0),
+    (0xAE, &[0x77], 0),
+    (0xCD, &[0x63], 0),
+    (0x70, &[0x07, 0x07, 0x04, 0x0E, 0x0F, 0x09, 0x07, 0x08, 0x03], 0),
+    (0xE8, &[0x34], 0),
+    (0x62, &[0x18, 0x0D, 0x71, 0xED, 0x70, 0x70, 0x18, 0x0F, 0x71, 0xEF, 0x70, 0x70], 0),
+    (0x63, &[0x18, 0x11, 0x71, 0xF1, 0x70, 0x70, 0x18, 0x13, 0x71, 0xF3, 0x70, 0x70], 0),
+    (0x64, &[0x28, 0x29, 0xF1, 0x01, 0xF1, 0x00, 0x07], 0),
+    (0x66, &[0x3C, 0x00, 0xCD, 0x67, 0x45, 0x45, 0x10, 0x00, 0x00, 0x00], 0),
+    (0x67, &[0x00, 0x3C, 0x00, 0x00, 0x00, 0x01, 0x54, 0x10, 0x32, 0x98], 0),
+    (0x74, &[0x10, 0x85, 0x80, 0x00, 0x00, 0x4E, 0x00], 0),
+    (0x98, &[0x3E, 0x07], 0),
+    (0x11, &[], 120),
+];
+
+const ST7735_INIT: [InitCmd; 15] = [
+    (0x01, &[], 150),
+    (0x11, &[], 120),
+    (0xB1, &[0x01, 0x2C, 0x2D], 0),
+    (0xB2, &[0x01, 0x2C, 0x2D], 0),
+    (0xB3, &[0x01, 0x2C, 0x2D, 0x01, 0x2C, 0x2D], 0),
+    (0xB4, &[0x07], 0),
+    (0xC0, &[0xA2, 0x02, 0x84], 0),
+    (0xC1, &[0xC5], 0),
+    (0xC2, &[0x0A, 0x00], 0),
+    (0xC3, &[0x8A, 0x2A], 0),
+    (0xC4, &[0x8A, 0xEE], 0),
+    (0xC5, &[0x0E], 0),
+    (0xE0, &[0x02, 0x1C, 0x07, 0x12, 0x37, 0x32, 0x29, 0x2D, 0x29, 0x25, 0x2B, 0x39, 0x00, 0x01, 0x03, 0x10], 0),
+    (0xE1, &[0x03, 0x1D, 0x07, 0x06, 0x2E, 0x2C, 0x29, 0x2D, 0x2E, 0x2E, 0x37, 0x3F, 0x00, 0x00, 0x02, 0x10], 0),
+    (0x13, &[], 10),
+];
+
+/// 240x320 ST7789V IPS, the panel `Lcd::new_lcd` drives.
+pub const ST7789: Panel = Panel {
+    name: "ST7789",
+    init: &ST7789_INIT,
+    invert: true,
+    madctl: 0x00,
+    col_offset: 0,
+    row_offset: 0,
+    width: 240,
+    height: 320,
+    ram_width: 240,
+    ram_height: 320,
+};
+
+/// 240x240 ST7789 module, the glass covers the top of the 240x320 RAM.
+pub const ST7789_240X240: Panel = Panel {
+    name: "ST7789 240x240",
+    width: 240,
+    height: 240,
+    ..ST7789
+};
+
+pub const ILI9341: Panel = Panel {
+    name: "ILI9341",
+    init: &ILI9341_INIT,
+    invert: false,
+    madctl: 0x08,
+    col_offset: 0,
+    row_offset: 0,
+    width: 240,
+    height: 320,
+    ram_width: 240,
+    ram_height: 320,
+};
+
+pub const ILI9488: Panel = Panel {
+    name: "ILI9488",
+    init: &ILI9488_INIT,
+    invert: false,
+    madctl: 0x08,
+    col_offset: 0,
+    row_offset: 0,
+    width: 320,
+    height: 480,
+    ram_width: 320,
+    ram_height: 480,
+};
+
+/// 240x240 round IPS.
+pub const GC9A01: Panel = Panel {
+    name: "GC9A01",
+    init: &GC9A01_INIT,
+    invert: true,
+    madctl: 0x08,
+    col_offset: 0,
+    row_offset: 0,
+    width: 240,
+    height: 240,
+    ram_width: 240,
+    ram_height: 240,
+};
+
+/// 128x160 ST7735R ("red tab").
+pub const ST7735: Panel = Panel {
+    name: "ST7735",
+    init: &ST7735_INIT,
+    invert: false,
+    madctl: 0x08,
+    col_offset: 0,
+    row_offset: 0,
+    width: 128,
+    height: 160,
+    ram_width: 132,
+    ram_height: 162,
+};
@@ -0,0 +1,163 @@
+use super::{Lcd, LcdError, LcdInterface};
+use crate::backlight::Backlight;
+
+// backlight ramp when the panel goes to sleep or wakes up
+const FADE_MS: u32 = 200;
+
+/// Controller power and display modes as last set through `Lcd`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PowerState {
+    pub sleeping: bool,
+    pub display_on: bool,
+    /// Eight colour mode.
+    pub idle: bool,
+    /// Only the PTLAR rows are driven.
+    pub partial: bool,
+}
+
+impl PowerState {
+    /// State right after `Lcd` has run the init sequence.
+    pub const AWAKE: PowerState = PowerState {
+        sleeping: false,
+        display_on: true,
+        idle: false,
+        partial: false,
+    };
+
+    /// State after a hardware reset, before the init sequence.
+    pub const RESET: PowerState = PowerState {
+        sleeping: true,
+        display_on: false,
+        idle: false,
+        partial: false,
+    };
+}
+
+impl<I: LcdInterface> Lcd<I> {
+    /// Let `sleep`/`wake` and `display_off`/`display_on` fade the
+    /// backlight as well.
+    pub fn attach_backlight(&mut self, backlight: Backlight) {
+        self.backlight = Some(backlight);
+    }
+
+    pub fn backlight(&mut self) -> Option<&mut Backlight> {
+        self.backlight.as_mut()
+    }
+
+    pub fn power(&self) -> PowerState {
+        self.power
+    }
+
+    pub fn is_sleeping(&self) -> bool {
+        self.power.sleeping
+    }
+
+    /// SLPIN. GRAM is kept, but drawing returns `Asleep` until `wake`.
+    pub fn sleep(&mut self) -> Result<(), LcdError> {
+        self.initialized()?;
+        if self.power.sleeping {
+            return Ok(());
+        }
+        if let Some(backlight) = self.backlight.as_mut() {
+            backlight.fade_out(FADE_MS);
+        }
+        self.send_command(0x10);
+        // 5ms before the next command, and SLPOUT must not follow within 120ms
+        self.interface.delay_us(120_000);
+        self.power.sleeping = true;
+        Ok(())
+    }
+
+    /// SLPOUT.
+    pub fn wake(&mut self) -> Result<(), LcdError> {
+        self.initialized()?;
+        if !self.power.sleeping {
+            return Ok(());
+        }
+        self.send_command(0x11);
+        // 5ms before the next command, and SLPIN must not follow within 120ms
+        self.interface.delay_us(120_000);
+        self.power.sleeping = false;
+        if self.power.display_on {
+            if let Some(backlight) = self.backlight.as_mut() {
+                backlight.fade_in(FADE_MS);
+            }
+        }
+        Ok(())
+    }
+
+    /// DISPON / DISPOFF. With the display off the panel shows blank while
+    /// GRAM can still be written.
+    pub fn set_display_on(&mut self, on: bool) -> Result<(), LcdError> {
+        self.initialized()?;
+        if !on {
+            if let Some(backlight) = self.backlight.as_mut() {
+                backlight.fade_out(FADE_MS);
+            }
+        }
+        self.send_command(if on { 0x29 } else { 0x28 });
+        self.power.display_on = on;
+        if on && !self.power.sleeping {
+            if let Some(backlight) = self.backlight.as_mut() {
+                backlight.fade_in(FADE_MS);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn display_on(&mut self) -> Result<(), LcdError> {
+        self.set_display_on(true)
+    }
+
+    pub fn display_off(&mut self) -> Result<(), LcdError> {
+        self.set_display_on(false)
+    }
+
+    /// IDMON / IDMOFF: eight colour mode, lowering panel power.
+    pub fn set_idle(&mut self, idle: bool) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.send_command(if idle { 0x39 } else { 0x38 });
+        self.power.idle = idle;
+        Ok(())
+    }
+
+    /// PTLAR: native RAM rows `start_row..=end_row` stay lit in partial mode.
+    /// A start below the end wraps around through the last row.
+    pub fn set_partial_area(&mut self, start_row: u16, end_row: u16) -> Result<(), LcdError> {
+        self.initialized()?;
+        if start_row >= self.panel.ram_height || end_row >= self.panel.ram_height {
+            return Err(LcdError::OutOfBounds);
+        }
+        self.write_command(0x30, &[
+            (start_row >> 8) as u8, start_row as u8,
+            (end_row >> 8) as u8, end_row as u8,
+        ]);
+        Ok(())
+    }
+
+    /// PTLON / NORON.
+    pub fn set_partial(&mut self, partial: bool) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.send_command(if partial { 0x12 } else { 0x13 });
+        self.power.partial = partial;
+        Ok(())
+    }
+
+    /// Drawing is refused until the init sequence has run and while the
+    /// controller sleeps.
+    pub(crate) fn can_write(&self) -> bool {
+        self.initialized && !self.power.sleeping
+    }
+
+    /// `can_write` for the methods that report it.
+    pub(crate) fn ready(&self) -> Result<(), LcdError> {
+        self.initialized()?;
+        if self.power.sleeping { Err(LcdError::Asleep) } else { Ok(()) }
+    }
+
+    /// Commands other than drawing only need the init sequence to have
+    /// run, they are taken while asleep.
+    pub(crate) fn initialized(&self) -> Result<(), LcdError> {
+        if self.initialized { Ok(()) } else { Err(LcdError::NotInitialized) }
+    }
+}
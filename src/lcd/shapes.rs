@@ -0,0 +1,535 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use super::{Lcd, LcdError, LcdInterface};
+use crate::color::Rgb565;
+
+/// sin of 0..=90 degrees, scaled by 1 << 14.
+const SIN: [i32; 91] = [
+    0, 286, 572, 857, 1143, 1428, 1713, 1997, 2280, 2563, 2845, 3126,
+    3406, 3686, 3964, 4240, 4516, 4790, 5063, 5334, 5604, 5872, 6138, 6402,
+    6664, 6924, 7182, 7438, 7692, 7943, 8192, 8438, 8682, 8923, 9162, 9397,
+    9630, 9860, 10087, 10311, 10531, 10749, 10963, 11174, 11381, 11585, 11786, 11982,
+    12176, 12365, 12551, 12733, 12911, 13085, 13255, 13421, 13583, 13741, 13894, 14044,
+    14189, 14330, 14466, 14598, 14726, 14849, 14968, 15082, 15191, 15296, 15396, 15491,
+    15582, 15668, 15749, 15826, 15897, 15964, 16026, 16083, 16135, 16182, 16225, 16262,
+    16294, 16322, 16344, 16362, 16374, 16382, 16384,
+];
+
+fn sin(deg: i32) -> i32 {
+    let deg = deg.rem_euclid(360) as usize;
+    match deg {
+        0..=90 => SIN[deg],
+        91..=180 => SIN[180 - deg],
+        181..=270 => -SIN[deg - 180],
+        _ => -SIN[360 - deg],
+    }
+}
+
+fn cos(deg: i32) -> i32 {
+    sin(deg + 90)
+}
+
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // a power of two no smaller than the root, Newton comes down from it
+    let mut x = 1 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// `a / b` rounded to the nearest integer, `b` > 0.
+fn div_round(a: i64, b: i64) -> i64 {
+    if a >= 0 { (a + b / 2) / b } else { (a - b / 2) / b }
+}
+
+/// Midpoint circle: every (dx, dy) on the outline of radius `r`, once per
+/// octant.
+fn circle_points(r: i32, mut f: impl FnMut(i64, i64)) {
+    let r = r as i64;
+    let (mut x, mut y, mut err) = (r, 0, 1 - r);
+    while x >= y {
+        for (dx, dy) in [(x, y), (y, x), (-y, x), (-x, y), (-x, -y), (-y, -x), (y, -x), (x, -y)] {
+            f(dx, dy);
+        }
+        y += 1;
+        if err < 0 {
+            err += 2 * y + 1;
+        } else {
+            x -= 1;
+            err += 2 * (y - x) + 1;
+        }
+    }
+}
+
+/// Half width of a filled circle of radius `r` on row `dy` in `0..=r`, the
+/// widest point `circle_points` sets there. The octant next to the x axis
+/// has the largest x with x² - x + dy² < r², the one next to the y axis the
+/// largest with x² + dy² - dy < r², so no row needs walking the outline.
+fn circle_half(r: i32, dy: i64) -> i64 {
+    let r = r as i64;
+    let k = r * r - dy * dy;
+    let flat = if k > 0 {
+        let s = isqrt(k as u128 - 1) as i64;
+        if (s + 1) * s < k { s + 1 } else { s }
+    } else {
+        0
+    };
+    let steep = if k + dy > 0 { isqrt((k + dy - 1) as u128) as i64 } else { 0 };
+    flat.max(steep)
+}
+
+/// `a` where the line from (a0, b0) to (a1, b1) crosses `b`, rounded;
+/// `b0` != `b1`.
+fn intercept(a0: i64, b0: i64, a1: i64, b1: i64, b: i64) -> i64 {
+    let (mut num, mut den) = ((a1 - a0) as i128 * (b - b0) as i128, (b1 - b0) as i128);
+    if den < 0 {
+        num = -num;
+        den = -den;
+    }
+    let step = if num >= 0 { (num + den / 2) / den } else { (num - den / 2) / den };
+    a0 + step as i64
+}
+
+/// Cohen–Sutherland: the part of the line from `p0` to `p1` inside
+/// `0..width` x `0..height`, `None` if the line misses it.
+fn clip_line(p0: (i64, i64), p1: (i64, i64), width: i32, height: i32) -> Option<((i64, i64), (i64, i64))> {
+    const LEFT: u8 = 1;
+    const RIGHT: u8 = 2;
+    const TOP: u8 = 4;
+    const BOTTOM: u8 = 8;
+    let (right, bottom) = (width as i64 - 1, height as i64 - 1);
+    let outcode = |(x, y): (i64, i64)| {
+        let mut code = 0;
+        if x < 0 {
+            code |= LEFT;
+        } else if x > right {
+            code |= RIGHT;
+        }
+        if y < 0 {
+            code |= TOP;
+        } else if y > bottom {
+            code |= BOTTOM;
+        }
+        code
+    };
+    let mut p = [p0, p1];
+    loop {
+        let codes = [outcode(p[0]), outcode(p[1])];
+        if codes[0] | codes[1] == 0 {
+            return Some((p[0], p[1]));
+        }
+        if codes[0] & codes[1] != 0 {
+            return None;
+        }
+        // move an end that is outside onto the edge it is beyond
+        let i = if codes[0] != 0 { 0 } else { 1 };
+        let ((x0, y0), (x1, y1)) = (p[0], p[1]);
+        p[i] = if codes[i] & TOP != 0 {
+            (intercept(x0, y0, x1, y1, 0), 0)
+        } else if codes[i] & BOTTOM != 0 {
+            (intercept(x0, y0, x1, y1, bottom), bottom)
+        } else if codes[i] & LEFT != 0 {
+            (0, intercept(y0, x0, y1, x1, 0))
+        } else {
+            (right, intercept(y0, x0, y1, x1, right))
+        };
+    }
+}
+
+/// Midpoint ellipse: the points of the bottom right quadrant, from the
+/// bottom of the ellipse round to its right end.
+fn ellipse_quadrant(rx: i32, ry: i32, mut f: impl FnMut(i64, i64)) {
+    let (rx, ry) = (rx as i64, ry as i64);
+    let (a2, b2) = (rx * rx, ry * ry);
+    let (mut x, mut y) = (0i64, ry);
+    let (mut dx, mut dy) = (0, 2 * a2 * y);
+
+    // the flat part, stepping x
+    let mut d = b2 - a2 * ry + a2 / 4;
+    while dx < dy {
+        f(x, y);
+        x += 1;
+        dx += 2 * b2;
+        if d < 0 {
+            d += dx + b2;
+        } else {
+            y -= 1;
+            dy -= 2 * a2;
+            d += dx - dy + b2;
+        }
+    }
+
+    // the steep part, stepping y
+    let mut d = b2 * (2 * x + 1) * (2 * x + 1) / 4 + a2 * (y - 1) * (y - 1) - a2 * b2;
+    while y >= 0 {
+        f(x, y);
+        y -= 1;
+        dy -= 2 * a2;
+        if d > 0 {
+            d += a2 - dy;
+        } else {
+            x += 1;
+            dx += 2 * b2;
+            d += dx - dy + a2;
+        }
+    }
+}
+
+
+impl<I: LcdInterface> Lcd<I> {
+    /// Fill `x0..=x1` x `y0..=y1` as far as it is on screen. The corners
+    /// are i64 so that sizes can be added to any i32 position.
+    fn fill_clipped(&mut self, x0: i64, y0: i64, x1: i64, y1: i64, color: Rgb565) {
+        let (x0, y0) = (x0.max(0), y0.max(0));
+        let (x1, y1) = (x1.min(self.max_x as i64 - 1), y1.min(self.max_y as i64 - 1));
+        if x0 > x1 || y0 > y1 {
+            return;
+        }
+        self.fill_window(x0 as u16, y0 as u16, x1 as u16, y1 as u16, color);
+    }
+
+    /// Rows of the screen within `cy - r..=cy + r`.
+    fn rows_within(&self, cy: i32, r: i32) -> RangeInclusive<i64> {
+        let top = (cy as i64 - r as i64).max(0);
+        let bottom = (cy as i64 + r as i64).min(self.max_y as i64 - 1);
+        top..=bottom
+    }
+
+    /// Whether `cx - rx..=cx + rx` x `cy - ry..=cy + ry` misses the screen.
+    fn off_screen(&self, cx: i32, cy: i32, rx: i32, ry: i32) -> bool {
+        let (cx, cy, rx, ry) = (cx as i64, cy as i64, rx as i64, ry as i64);
+        cx + rx < 0 || cy + ry < 0 || cx - rx >= self.max_x as i64 || cy - ry >= self.max_y as i64
+    }
+
+    fn plot(&mut self, x: i64, y: i64, color: Rgb565) {
+        if x >= 0 && y >= 0 && x < self.max_x as i64 && y < self.max_y as i64 {
+            self.set_window(x as u16, y as u16, x as u16, y as u16);
+            self.send_pixel(color);
+        }
+    }
+
+    /// `width` pixels to the right of (x, y).
+    pub fn draw_hline(&mut self, x: i32, y: i32, width: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        let (x, y) = (x as i64, y as i64);
+        self.fill_clipped(x, y, x + width as i64 - 1, y, color);
+        Ok(())
+    }
+
+    /// `height` pixels down from (x, y).
+    pub fn draw_vline(&mut self, x: i32, y: i32, height: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        let (x, y) = (x as i64, y as i64);
+        self.fill_clipped(x, y, x, y + height as i64 - 1, color);
+        Ok(())
+    }
+
+    /// Bresenham line, both ends included, clipped to the screen first.
+    /// Straight runs go out as one rectangle each rather than pixel by
+    /// pixel.
+    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        self.line((x0 as i64, y0 as i64), (x1 as i64, y1 as i64), color);
+        Ok(())
+    }
+
+    fn line(&mut self, p0: (i64, i64), p1: (i64, i64), color: Rgb565) {
+        let ((x0, y0), (x1, y1)) = match clip_line(p0, p1, self.max_x as i32, self.max_y as i32) {
+            Some(line) => line,
+            None => return,
+        };
+        let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+        let (sx, sy) = (if x0 < x1 { 1 } else { -1 }, if y0 < y1 { 1 } else { -1 });
+        let steep = -dy > dx;
+        let (mut x, mut y, mut err) = (x0, y0, dx + dy);
+        let (mut run_x, mut run_y) = (x, y);
+        loop {
+            let end = x == x1 && y == y1;
+            let e2 = 2 * err;
+            let (mut nx, mut ny) = (x, y);
+            if e2 >= dy {
+                err += dy;
+                nx += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                ny += sy;
+            }
+            // a run ends where the line steps sideways
+            if end || (steep && nx != x) || (!steep && ny != y) {
+                self.fill_clipped(run_x.min(x), run_y.min(y), run_x.max(x), run_y.max(y), color);
+                run_x = nx;
+                run_y = ny;
+            }
+            if end {
+                break;
+            }
+            x = nx;
+            y = ny;
+        }
+    }
+
+    /// Line `width` pixels wide, with square ends.
+    pub fn draw_thick_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, width: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if width <= 1 {
+            return self.draw_line(x0, y0, x1, y1, color);
+        }
+        let (x0, y0, x1, y1) = (x0 as i64, y0 as i64, x1 as i64, y1 as i64);
+        let (dx, dy) = (x1 - x0, y1 - y0);
+        let len = isqrt((dx as i128 * dx as i128 + dy as i128 * dy as i128) as u128) as i64;
+        let w = width as i64 - 1;
+        if len == 0 {
+            let r = w / 2;
+            self.fill_clipped(x0 - r, y0 - r, x0 - r + w, y0 - r + w, color);
+            return Ok(());
+        }
+        // half the width across the line
+        let ox = div_round(-dy * w, 2 * len);
+        let oy = div_round(dx * w, 2 * len);
+        self.polygon(&[(x0 + ox, y0 + oy), (x1 + ox, y1 + oy), (x1 - ox, y1 - oy), (x0 - ox, y0 - oy)], color);
+        Ok(())
+    }
+
+    /// Outline of the `width` x `height` rectangle at (x, y).
+    pub fn draw_rectangle(&mut self, x: i32, y: i32, width: i32, height: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if width <= 0 || height <= 0 {
+            return Ok(());
+        }
+        let (left, top) = (x as i64, y as i64);
+        let (right, bottom) = (left + width as i64 - 1, top + height as i64 - 1);
+        self.fill_clipped(left, top, right, top, color);
+        self.fill_clipped(left, bottom, right, bottom, color);
+        self.fill_clipped(left, top + 1, left, bottom - 1, color);
+        self.fill_clipped(right, top + 1, right, bottom - 1, color);
+        Ok(())
+    }
+
+    pub fn draw_circle(&mut self, cx: i32, cy: i32, r: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        // the outline takes O(r) steps, so skip it when none are visible
+        if r < 0 || self.off_screen(cx, cy, r, r) {
+            return Ok(());
+        }
+        let (cx, cy) = (cx as i64, cy as i64);
+        circle_points(r, |dx, dy| self.plot(cx + dx, cy + dy, color));
+        Ok(())
+    }
+
+    pub fn fill_circle(&mut self, cx: i32, cy: i32, r: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if r < 0 {
+            return Ok(());
+        }
+        let rows = self.rows_within(cy, r);
+        let (cx, cy) = (cx as i64, cy as i64);
+        for y in rows {
+            let half = circle_half(r, (y - cy).abs());
+            self.fill_clipped(cx - half, y, cx + half, y, color);
+        }
+        Ok(())
+    }
+
+    /// Part of a circle from `start` to `end` degrees, measured clockwise
+    /// from three o'clock. A sweep of 360 degrees or more is the whole
+    /// circle, one of none just the point at `start`.
+    pub fn draw_arc(&mut self, cx: i32, cy: i32, r: i32, start: i32, end: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if r < 0 || self.off_screen(cx, cy, r, r) {
+            return Ok(());
+        }
+        let sweep = end as i64 - start as i64;
+        if sweep >= 360 {
+            return self.draw_circle(cx, cy, r, color);
+        }
+        let (cx, cy) = (cx as i64, cy as i64);
+        // y points down, so positive cross products turn clockwise
+        let (ax, ay) = (cos(start) as i64, sin(start) as i64);
+        if sweep.rem_euclid(360) == 0 {
+            // the outline point closest to the direction of `start`
+            let mut nearest = (i64::MIN, 0, 0);
+            circle_points(r, |dx, dy| {
+                let dot = ax * dx + ay * dy;
+                if dot > nearest.0 {
+                    nearest = (dot, dx, dy);
+                }
+            });
+            self.plot(cx + nearest.1, cy + nearest.2, color);
+            return Ok(());
+        }
+        let (bx, by) = (cos(end) as i64, sin(end) as i64);
+        let small = sweep.rem_euclid(360) <= 180;
+        circle_points(r, |dx, dy| {
+            let from_start = ax * dy - ay * dx;
+            let to_end = dx * by - dy * bx;
+            let inside = if small {
+                from_start >= 0 && to_end >= 0
+            } else {
+                // everything but the short way back from end to start
+                !(from_start < 0 && to_end < 0)
+            };
+            if inside {
+                self.plot(cx + dx, cy + dy, color);
+            }
+        });
+        Ok(())
+    }
+
+    pub fn draw_ellipse(&mut self, cx: i32, cy: i32, rx: i32, ry: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if rx < 0 || ry < 0 || self.off_screen(cx, cy, rx, ry) {
+            return Ok(());
+        }
+        let (cx, cy) = (cx as i64, cy as i64);
+        ellipse_quadrant(rx, ry, |x, y| {
+            self.plot(cx + x, cy + y, color);
+            self.plot(cx - x, cy + y, color);
+            self.plot(cx + x, cy - y, color);
+            self.plot(cx - x, cy - y, color);
+        });
+        Ok(())
+    }
+
+    pub fn fill_ellipse(&mut self, cx: i32, cy: i32, rx: i32, ry: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if rx < 0 || ry < 0 {
+            return Ok(());
+        }
+        // spans only for the distances from `cy` that fall on screen
+        let rows = self.rows_within(cy, ry);
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let (cx, cy) = (cx as i64, cy as i64);
+        let (top, bottom) = ((rows.start() - cy).abs(), (rows.end() - cy).abs());
+        let near = if rows.contains(&cy) { 0 } else { top.min(bottom) };
+        let far = top.max(bottom);
+        let mut half = vec![0; (far - near) as usize + 1];
+        ellipse_quadrant(rx, ry, |x, y| {
+            if (near..=far).contains(&y) {
+                let i = (y - near) as usize;
+                half[i] = half[i].max(x);
+            }
+        });
+        for (dy, &half) in (near..).zip(half.iter()) {
+            self.fill_clipped(cx - half, cy - dy, cx + half, cy - dy, color);
+            if dy > 0 {
+                self.fill_clipped(cx - half, cy + dy, cx + half, cy + dy, color);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn draw_triangle(&mut self, p0: (i32, i32), p1: (i32, i32), p2: (i32, i32), color: Rgb565) -> Result<(), LcdError> {
+        self.draw_polygon(&[p0, p1, p2], color)
+    }
+
+    pub fn fill_triangle(&mut self, p0: (i32, i32), p1: (i32, i32), p2: (i32, i32), color: Rgb565) -> Result<(), LcdError> {
+        self.fill_polygon(&[p0, p1, p2], color)
+    }
+
+    /// Closed outline through `points`.
+    pub fn draw_polygon(&mut self, points: &[(i32, i32)], color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        for (i, &(x0, y0)) in points.iter().enumerate() {
+            let (x1, y1) = points[(i + 1) % points.len()];
+            self.line((x0 as i64, y0 as i64), (x1 as i64, y1 as i64), color);
+        }
+        Ok(())
+    }
+
+    /// Polygon filled with the even-odd rule, so self-intersecting shapes
+    /// get holes. The outline is included.
+    pub fn fill_polygon(&mut self, points: &[(i32, i32)], color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        let points: Vec<(i64, i64)> = points.iter().map(|&(x, y)| (x as i64, y as i64)).collect();
+        self.polygon(&points, color);
+        Ok(())
+    }
+
+    /// `fill_polygon` for corners `draw_thick_line` may have pushed past
+    /// the i32 range.
+    fn polygon(&mut self, points: &[(i64, i64)], color: Rgb565) {
+        if points.is_empty() {
+            return;
+        }
+        let top = points.iter().map(|p| p.1).min().unwrap_or(0).max(0);
+        let bottom = points.iter().map(|p| p.1).max().unwrap_or(0).min(self.max_y as i64 - 1);
+        let mut crossings: Vec<i64> = Vec::with_capacity(points.len());
+        for y in top..=bottom {
+            crossings.clear();
+            for (i, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points[(i + 1) % points.len()];
+                // half open, so a vertex shared by two edges counts once
+                if (y0 <= y) != (y1 <= y) {
+                    crossings.push(intercept(x0, y0, x1, y1, y));
+                }
+            }
+            crossings.sort_unstable();
+            for pair in crossings.chunks_exact(2) {
+                self.fill_clipped(pair[0], y, pair[1], y, color);
+            }
+        }
+        // the half open rule leaves out bottom edges and the right side
+        // of steep ones
+        for (i, &p0) in points.iter().enumerate() {
+            self.line(p0, points[(i + 1) % points.len()], color);
+        }
+    }
+
+    /// Outline of a rectangle with quarter circle corners of radius `r`.
+    pub fn draw_round_rect(&mut self, x: i32, y: i32, width: i32, height: i32, r: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if width <= 0 || height <= 0 {
+            return Ok(());
+        }
+        let r = r.clamp(0, (width.min(height) - 1) / 2);
+        let (x, y, width, height) = (x as i64, y as i64, width as i64, height as i64);
+        let (left, right) = (x + r as i64, x + width - 1 - r as i64);
+        let (top, bottom) = (y + r as i64, y + height - 1 - r as i64);
+        self.fill_clipped(left, y, right, y, color);
+        self.fill_clipped(left, y + height - 1, right, y + height - 1, color);
+        self.fill_clipped(x, top, x, bottom, color);
+        self.fill_clipped(x + width - 1, top, x + width - 1, bottom, color);
+        circle_points(r, |dx, dy| {
+            let cx = if dx < 0 { left } else { right };
+            let cy = if dy < 0 { top } else { bottom };
+            self.plot(cx + dx, cy + dy, color);
+        });
+        Ok(())
+    }
+
+    pub fn fill_round_rect(&mut self, x: i32, y: i32, width: i32, height: i32, r: i32, color: Rgb565) -> Result<(), LcdError> {
+        self.ready()?;
+        if width <= 0 || height <= 0 {
+            return Ok(());
+        }
+        let r = r.clamp(0, (width.min(height) - 1) / 2);
+        let (x, y, width, height) = (x as i64, y as i64, width as i64, height as i64);
+        let (left, right) = (x + r as i64, x + width - 1 - r as i64);
+        let (top, bottom) = (y + r as i64, y + height - 1 - r as i64);
+        self.fill_clipped(x, top, x + width - 1, bottom, color);
+        for row in y.max(0)..=(y + height - 1).min(self.max_y as i64 - 1) {
+            let dy = if row < top {
+                top - row
+            } else if row > bottom {
+                row - bottom
+            } else {
+                continue;
+            };
+            let half = circle_half(r, dy);
+            self.fill_clipped(left - half, row, right + half, row, color);
+        }
+        Ok(())
+    }
+}
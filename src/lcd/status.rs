@@ -0,0 +1,100 @@
+use super::{Lcd, LcdError, LcdInterface, PowerState};
+
+const RDDID: u8 = 0x04;
+const RDDST: u8 = 0x09;
+const RDDPM: u8 = 0x0A;
+const RDDMADCTL: u8 = 0x0B;
+const RDDCOLMOD: u8 = 0x0C;
+const RDID4: u8 = 0xD3;
+
+/// RDDID (04h) answer, along with the IC model from RDID4 (D3h).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PanelId {
+    pub manufacturer: u8,
+    pub version: u8,
+    pub driver: u8,
+    /// Part number, e.g. 0x9341 for an ILI9341. Controllers without
+    /// RDID4, such as the ST7789, read back zero or garbage here.
+    pub ic: u16,
+}
+
+/// RDDID and RDID4. Ilitek parts leave RDDID unprogrammed, so RDID4 is
+/// the only way to tell them apart.
+fn read_panel_id<I: LcdInterface>(interface: &mut I) -> Result<PanelId, LcdError> {
+    let mut id = [0u8; 3];
+    interface.read(RDDID, &mut id)?;
+    // a dummy parameter, then the IC version and the two model bytes
+    let mut ic = [0u8; 4];
+    interface.read(RDID4, &mut ic)?;
+    Ok(PanelId { manufacturer: id[0], version: id[1], driver: id[2], ic: u16::from_be_bytes([ic[2], ic[3]]) })
+}
+
+impl PanelId {
+    /// Reset the controller and read its ID. A bus that reads back all
+    /// zeros or all ones for both RDDID and RDDST has no panel on it.
+    pub fn probe<I: LcdInterface>(interface: &mut I) -> Result<PanelId, LcdError> {
+        interface.reset();
+        // 5ms after reset before the first command
+        interface.delay_us(5_000);
+
+        let id = read_panel_id(interface)?;
+        let mut status = [0u8; 4];
+        interface.read(RDDST, &mut status)?;
+        let floating = |bytes: &[u8]| bytes.iter().all(|&b| b == 0x00) || bytes.iter().all(|&b| b == 0xFF);
+        if floating(&[id.manufacturer, id.version, id.driver]) && floating(&status) {
+            return Err(LcdError::NoPanel);
+        }
+        Ok(id)
+    }
+}
+
+impl<I: LcdInterface> Lcd<I> {
+    /// RDDID (04h) and RDID4 (D3h).
+    pub fn read_id(&mut self) -> Result<PanelId, LcdError> {
+        read_panel_id(&mut self.interface)
+    }
+
+    /// RDDST (09h), the four status bytes as sent.
+    pub fn read_status(&mut self) -> Result<[u8; 4], LcdError> {
+        let mut status = [0u8; 4];
+        self.interface.read(RDDST, &mut status)?;
+        Ok(status)
+    }
+
+    /// RDDPM (0Ah), decoded into the same form `power` reports.
+    pub fn read_power_mode(&mut self) -> Result<PowerState, LcdError> {
+        let mut mode = [0u8; 1];
+        self.interface.read(RDDPM, &mut mode)?;
+        let mode = mode[0];
+        Ok(PowerState {
+            sleeping: mode & 0x10 == 0,
+            display_on: mode & 0x04 != 0,
+            idle: mode & 0x40 != 0,
+            partial: mode & 0x20 != 0,
+        })
+    }
+
+    /// RDDMADCTL (0Bh).
+    pub fn read_madctl(&mut self) -> Result<u8, LcdError> {
+        let mut madctl = [0u8; 1];
+        self.interface.read(RDDMADCTL, &mut madctl)?;
+        Ok(madctl[0])
+    }
+
+    /// RDDCOLMOD (0Ch).
+    pub fn read_colmod(&mut self) -> Result<u8, LcdError> {
+        let mut colmod = [0u8; 1];
+        self.interface.read(RDDCOLMOD, &mut colmod)?;
+        Ok(colmod[0])
+    }
+
+    /// Check that the init sequence left the controller awake with the
+    /// display on.
+    pub fn verify_init(&mut self) -> Result<(), LcdError> {
+        let mode = self.read_power_mode()?;
+        if mode.sleeping || !mode.display_on {
+            return Err(LcdError::InitFailed);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use super::{Lcd, LcdError, LcdInterface};
+use crate::hal::gpiohs;
+
+// a little over two frames at 60Hz
+const TE_TIMEOUT_US: usize = 40_000;
+const TE_POLL_US: usize = 10;
+
+impl<I: LcdInterface> Lcd<I> {
+    /// TEON (V-blank only) and watch the panel's TE output on GPIOHS
+    /// `te_pin`. From then on `lcd_draw_picture` starts each transfer on
+    /// a TE edge, so the write pointer stays behind the refresh.
+    pub fn enable_tearing_effect(&mut self, te_pin: usize) -> Result<(), LcdError> {
+        self.initialized()?;
+        let mut te = gpiohs::Gpiohs::new(te_pin);
+        te.set_input();
+        self.write_command(0x35, &[0x00]);
+        self.te = Some(te);
+        Ok(())
+    }
+
+    /// TEOFF.
+    pub fn disable_tearing_effect(&mut self) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.send_command(0x34);
+        self.te = None;
+        Ok(())
+    }
+
+    /// STE: scanline at which the TE pulse is raised, for updates that
+    /// should start part way down the frame.
+    pub fn set_tear_scanline(&mut self, line: u16) -> Result<(), LcdError> {
+        self.initialized()?;
+        if line >= self.panel.ram_height {
+            return Err(LcdError::OutOfBounds);
+        }
+        self.write_command(0x44, &[(line >> 8) as u8, line as u8]);
+        Ok(())
+    }
+
+    /// Wait for the next rising edge on the TE line. Returns false if TE
+    /// is not enabled or no edge arrived within two frames.
+    pub fn wait_for_tear(&mut self) -> bool {
+        let te = match self.te.as_ref() {
+            Some(te) => te,
+            None => return false,
+        };
+        let mut waited = 0;
+        // let a pulse that is already high go by first
+        while te.is_high() {
+            if waited >= TE_TIMEOUT_US {
+                return false;
+            }
+            self.interface.delay_us(TE_POLL_US);
+            waited += TE_POLL_US;
+        }
+        while !te.is_high() {
+            if waited >= TE_TIMEOUT_US {
+                return false;
+            }
+            self.interface.delay_us(TE_POLL_US);
+            waited += TE_POLL_US;
+        }
+        true
+    }
+}
@@ -0,0 +1,145 @@
+use super::{Lcd, LcdError, LcdInterface};
+
+/// PVGAMCTRL (E0h) and NVGAMCTRL (E1h) payloads.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GammaCurve {
+    pub positive: [u8; 14],
+    pub negative: [u8; 14],
+}
+
+impl GammaCurve {
+    /// ST7789V power-on values.
+    pub const DEFAULT: GammaCurve = GammaCurve {
+        positive: [0xD0, 0x00, 0x02, 0x07, 0x0B, 0x1A, 0x31, 0x54, 0x40, 0x29, 0x12, 0x12, 0x12, 0x17],
+        negative: [0xD0, 0x00, 0x02, 0x07, 0x05, 0x25, 0x2D, 0x44, 0x45, 0x1C, 0x18, 0x16, 0x1C, 0x1D],
+    };
+
+    /// Steeper mid tones, for glass that looks washed out at the default.
+    pub const CONTRAST: GammaCurve = GammaCurve {
+        positive: [0xD0, 0x04, 0x0D, 0x11, 0x13, 0x2B, 0x3F, 0x54, 0x4C, 0x18, 0x0D, 0x0B, 0x1F, 0x23],
+        negative: [0xD0, 0x04, 0x0C, 0x11, 0x13, 0x2C, 0x3F, 0x44, 0x51, 0x2F, 0x1F, 0x1F, 0x20, 0x23],
+    };
+
+    /// Softer curve used by many 1.3" and 1.54" IPS modules.
+    pub const SOFT: GammaCurve = GammaCurve {
+        positive: [0xD0, 0x00, 0x02, 0x07, 0x0A, 0x28, 0x32, 0x44, 0x42, 0x06, 0x0E, 0x12, 0x14, 0x17],
+        negative: [0xD0, 0x00, 0x02, 0x07, 0x0A, 0x28, 0x31, 0x54, 0x47, 0x0E, 0x1C, 0x17, 0x1B, 0x1E],
+    };
+}
+
+/// PORCTRL (B2h): back and front porch in lines for normal, idle and
+/// partial mode.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Porch {
+    /// 1..=127 lines.
+    pub back: u8,
+    /// 1..=127 lines.
+    pub front: u8,
+    /// Use `idle` and `partial` instead of the normal porch in those modes.
+    pub separate: bool,
+    /// Back porch in the high nibble, front porch in the low nibble, in
+    /// units of four lines.
+    pub idle: u8,
+    pub partial: u8,
+}
+
+impl Porch {
+    pub const DEFAULT: Porch = Porch { back: 0x0C, front: 0x0C, separate: false, idle: 0x33, partial: 0x33 };
+}
+
+/// FRCTRL2 (C6h) frame rate in normal mode.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FrameRate {
+    Hz119 = 0x00,
+    Hz99 = 0x03,
+    Hz90 = 0x05,
+    Hz75 = 0x09,
+    Hz60 = 0x0F,
+    Hz50 = 0x15,
+    Hz39 = 0x1F,
+}
+
+/// Set of tuning registers applied together with `Lcd::apply_tuning`.
+/// `None` leaves the register as it is.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PanelTuning {
+    pub gamma: Option<GammaCurve>,
+    pub porch: Option<Porch>,
+    pub frame_rate: Option<FrameRate>,
+    /// Raw VCOMS value, see `Lcd::set_vcom`.
+    pub vcom: Option<u8>,
+}
+
+impl PanelTuning {
+    /// Controller power-on values.
+    pub const DEFAULT: PanelTuning = PanelTuning {
+        gamma: Some(GammaCurve::DEFAULT),
+        porch: Some(Porch::DEFAULT),
+        frame_rate: Some(FrameRate::Hz60),
+        vcom: Some(0x20),
+    };
+}
+
+impl<I: LcdInterface> Lcd<I> {
+    /// PVGAMCTRL (E0h).
+    pub fn set_positive_gamma(&mut self, curve: &[u8; 14]) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.write_command(0xE0, curve);
+        Ok(())
+    }
+
+    /// NVGAMCTRL (E1h).
+    pub fn set_negative_gamma(&mut self, curve: &[u8; 14]) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.write_command(0xE1, curve);
+        Ok(())
+    }
+
+    pub fn set_gamma(&mut self, gamma: &GammaCurve) -> Result<(), LcdError> {
+        self.set_positive_gamma(&gamma.positive)?;
+        self.set_negative_gamma(&gamma.negative)
+    }
+
+    /// PORCTRL (B2h).
+    pub fn set_porch(&mut self, porch: &Porch) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.write_command(0xB2, &[
+            porch.back & 0x7F,
+            porch.front & 0x7F,
+            porch.separate as u8,
+            porch.idle,
+            porch.partial,
+        ]);
+        Ok(())
+    }
+
+    /// FRCTRL2 (C6h), with dot inversion.
+    pub fn set_frame_rate(&mut self, rate: FrameRate) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.write_command(0xC6, &[rate as u8]);
+        Ok(())
+    }
+
+    /// VCOMS (BBh): 0.1V plus 25mV per step, 0x20 is 0.9V.
+    pub fn set_vcom(&mut self, vcom: u8) -> Result<(), LcdError> {
+        self.initialized()?;
+        self.write_command(0xBB, &[vcom & 0x3F]);
+        Ok(())
+    }
+
+    pub fn apply_tuning(&mut self, tuning: &PanelTuning) -> Result<(), LcdError> {
+        if let Some(porch) = &tuning.porch {
+            self.set_porch(porch)?;
+        }
+        if let Some(vcom) = tuning.vcom {
+            self.set_vcom(vcom)?;
+        }
+        if let Some(rate) = tuning.frame_rate {
+            self.set_frame_rate(rate)?;
+        }
+        if let Some(gamma) = &tuning.gamma {
+            self.set_gamma(gamma)?;
+        }
+        Ok(())
+    }
+}
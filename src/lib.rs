@@ -2,7 +2,20 @@
 
 pub mod hal;
 pub mod constant;
+pub mod color;
+pub mod font;
 pub mod lcd;
 pub mod camera;
+pub mod framebuffer;
+pub mod console;
+pub mod backlight;
+pub mod image;
+pub mod sprite;
+#[cfg(feature = "embedded-graphics")]
+pub mod graphics;
+#[cfg(feature = "std")]
+pub mod sim;
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 pub use k210_hal;
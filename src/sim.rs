@@ -0,0 +1,452 @@
+//! Host side model of an ST7789 controller.
+//!
+//! `Simulator` is an `LcdInterface`, so a whole `Lcd` can run on the build
+//! machine and the resulting GRAM can be compared against golden images.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::vec;
+use std::vec::Vec;
+
+use crate::color::Rgb565;
+use crate::lcd::{LcdError, LcdInterface};
+
+const SWRESET: u8 = 0x01;
+const RDDID: u8 = 0x04;
+const RDDST: u8 = 0x09;
+const RDDPM: u8 = 0x0A;
+const RDDMADCTL: u8 = 0x0B;
+const RDDCOLMOD: u8 = 0x0C;
+const SLPIN: u8 = 0x10;
+const SLPOUT: u8 = 0x11;
+const PTLON: u8 = 0x12;
+const NORON: u8 = 0x13;
+const INVOFF: u8 = 0x20;
+const INVON: u8 = 0x21;
+const DISPOFF: u8 = 0x28;
+const DISPON: u8 = 0x29;
+const CASET: u8 = 0x2A;
+const RASET: u8 = 0x2B;
+const RAMWR: u8 = 0x2C;
+const PTLAR: u8 = 0x30;
+const VSCRDEF: u8 = 0x33;
+const TEOFF: u8 = 0x34;
+const TEON: u8 = 0x35;
+const MADCTL: u8 = 0x36;
+const VSCRSADD: u8 = 0x37;
+const IDMOFF: u8 = 0x38;
+const IDMON: u8 = 0x39;
+const COLMOD: u8 = 0x3A;
+const RDID4: u8 = 0xD3;
+
+const MADCTL_MY: u8 = 0x80;
+const MADCTL_MX: u8 = 0x40;
+const MADCTL_MV: u8 = 0x20;
+
+/// Which way round `Simulator::image` lays out the pixels.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    /// GRAM as it sits on the glass, native width by native height.
+    Panel,
+    /// GRAM as addressed through the current MADCTL, i.e. in the same
+    /// coordinates `Lcd` draws with.
+    Addressed,
+}
+
+pub struct Simulator {
+    width: u16,
+    height: u16,
+    gram: Vec<Rgb565>,
+    /// RDDID answer, an ST7789V by default.
+    pub id: [u8; 3],
+    /// Model number in the RDID4 answer, e.g. 0x9341. The ST7789 has no
+    /// RDID4 and reads back zeros.
+    pub ic: u16,
+    /// IPS glass shows true colours only with INVON set.
+    pub panel_inverted: bool,
+    pub madctl: u8,
+    pub colmod: u8,
+    pub inverted: bool,
+    pub sleeping: bool,
+    pub display_on: bool,
+    pub idle: bool,
+    pub tearing: bool,
+    /// Partial mode and the lit native rows, inclusive.
+    pub partial: bool,
+    pub partial_start: u16,
+    pub partial_end: u16,
+    /// Vertical scrolling: fixed top rows, scrolling rows and the RAM row
+    /// shown first in the scrolling band.
+    pub scroll_top: u16,
+    pub scroll_height: u16,
+    pub scroll_start: u16,
+    /// Every command byte received, in order.
+    pub commands: Vec<u8>,
+    /// Sum of all requested delays.
+    pub elapsed_us: u64,
+    cmd: u8,
+    params: Vec<u8>,
+    col_start: u16,
+    col_end: u16,
+    row_start: u16,
+    row_end: u16,
+    col: u16,
+    row: u16,
+}
+
+impl Simulator {
+    /// A controller whose glass is `width` x `height` pixels in its native
+    /// (MADCTL = 0) orientation.
+    pub fn new(width: u16, height: u16) -> Self {
+        let mut sim = Simulator {
+            width,
+            height,
+            gram: vec![Rgb565(0); width as usize * height as usize],
+            id: [0x85, 0x85, 0x52],
+            ic: 0,
+            panel_inverted: true,
+            madctl: 0,
+            colmod: 0,
+            inverted: false,
+            sleeping: true,
+            display_on: false,
+            idle: false,
+            tearing: false,
+            partial: false,
+            partial_start: 0,
+            partial_end: height - 1,
+            scroll_top: 0,
+            scroll_height: height,
+            scroll_start: 0,
+            commands: Vec::new(),
+            elapsed_us: 0,
+            cmd: 0,
+            params: Vec::new(),
+            col_start: 0,
+            col_end: 0,
+            row_start: 0,
+            row_end: 0,
+            col: 0,
+            row: 0,
+        };
+        sim.software_reset();
+        sim
+    }
+
+    /// The 240x320 ST7789 driven by `Lcd::new_lcd`.
+    pub fn st7789() -> Self {
+        Simulator::new(240, 320)
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Raw GRAM content at native panel coordinates.
+    pub fn pixel(&self, x: u16, y: u16) -> Rgb565 {
+        self.gram[y as usize * self.width as usize + x as usize]
+    }
+
+    /// What the glass shows for a GRAM value, taking display state, idle
+    /// mode and inversion into account.
+    pub fn visible(&self, color: Rgb565) -> Rgb565 {
+        if self.sleeping || !self.display_on {
+            return Rgb565(0x0000);
+        }
+        let color = if self.inverted == self.panel_inverted { color.0 } else { !color.0 };
+        if self.idle {
+            // only the top bit of each channel survives
+            Rgb565((if color & 0x8000 != 0 { 0xF800 } else { 0 })
+                | (if color & 0x0400 != 0 { 0x07E0 } else { 0 })
+                | (if color & 0x0010 != 0 { 0x001F } else { 0 }))
+        } else {
+            Rgb565(color)
+        }
+    }
+
+    /// RAM row the glass shows on native row `y`, after vertical scrolling.
+    pub fn scrolled_row(&self, y: u16) -> u16 {
+        // VSCRDEF takes any 16 bit values, so the sums can pass u16::MAX
+        let (y, top, height) = (y as u32, self.scroll_top as u32, self.scroll_height as u32);
+        if y < top || y >= top + height || height == 0 {
+            return y as u16;
+        }
+        let start = (self.scroll_start as u32).max(top) - top;
+        (top + (start + y - top) % height) as u16
+    }
+
+    /// Visible pixels row by row, along with the image size.
+    pub fn image(&self, view: View) -> (u16, u16, Vec<Rgb565>) {
+        let mut panel = Vec::with_capacity(self.gram.len());
+        for y in 0..self.height {
+            let row = self.scrolled_row(y);
+            let lit = !self.partial || (self.partial_start <= y && y <= self.partial_end);
+            for x in 0..self.width {
+                panel.push(if lit { self.visible(self.pixel(x, row)) } else { Rgb565(0x0000) });
+            }
+        }
+        match view {
+            View::Panel => (self.width, self.height, panel),
+            View::Addressed => {
+                let (cols, rows) = self.addressable();
+                let mut data = Vec::with_capacity(cols as usize * rows as usize);
+                for row in 0..rows {
+                    for col in 0..cols {
+                        let (x, y) = self.physical(col, row).unwrap();
+                        data.push(panel[y as usize * self.width as usize + x as usize]);
+                    }
+                }
+                (cols, rows, data)
+            }
+        }
+    }
+
+    /// Visible pixels as packed 8 bit RGB, along with the image size.
+    pub fn rgb888(&self, view: View) -> (u16, u16, Vec<u8>) {
+        let (width, height, data) = self.image(view);
+        let mut rgb = Vec::with_capacity(data.len() * 3);
+        for c in data {
+            let (r, g, b) = c.to_rgb888();
+            rgb.extend_from_slice(&[r, g, b]);
+        }
+        (width, height, rgb)
+    }
+
+    pub fn save_ppm<P: AsRef<Path>>(&self, path: P, view: View) -> io::Result<()> {
+        let (width, height, rgb) = self.rgb888(view);
+        let mut w = BufWriter::new(File::create(path)?);
+        write!(w, "P6\n{} {}\n255\n", width, height)?;
+        w.write_all(&rgb)?;
+        w.flush()
+    }
+
+    pub fn save_png<P: AsRef<Path>>(&self, path: P, view: View) -> io::Result<()> {
+        let (width, height, rgb) = self.rgb888(view);
+        let w = BufWriter::new(File::create(path)?);
+        let mut encoder = png::Encoder::new(w, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .map_err(io::Error::other)?;
+        writer
+            .write_image_data(&rgb)
+            .map_err(io::Error::other)
+    }
+
+    fn software_reset(&mut self) {
+        self.madctl = 0;
+        self.colmod = 0x66;
+        self.inverted = false;
+        self.sleeping = true;
+        self.display_on = false;
+        self.idle = false;
+        self.tearing = false;
+        self.partial = false;
+        self.partial_start = 0;
+        self.partial_end = self.height - 1;
+        self.scroll_top = 0;
+        self.scroll_height = self.height;
+        self.scroll_start = 0;
+        self.cmd = 0;
+        self.params.clear();
+        let (cols, rows) = self.addressable();
+        self.col_start = 0;
+        self.col_end = cols - 1;
+        self.row_start = 0;
+        self.row_end = rows - 1;
+    }
+
+    /// Column and row count as seen through MADCTL.
+    fn addressable(&self) -> (u16, u16) {
+        if self.madctl & MADCTL_MV != 0 {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        }
+    }
+
+    fn physical(&self, col: u16, row: u16) -> Option<(u16, u16)> {
+        let (cols, rows) = self.addressable();
+        if col >= cols || row >= rows {
+            return None;
+        }
+        let c = if self.madctl & MADCTL_MX != 0 { cols - 1 - col } else { col };
+        let r = if self.madctl & MADCTL_MY != 0 { rows - 1 - row } else { row };
+        if self.madctl & MADCTL_MV != 0 {
+            Some((r, c))
+        } else {
+            Some((c, r))
+        }
+    }
+
+    /// RDDPM bits for the current state; the booster runs while awake.
+    fn power_mode(&self) -> u8 {
+        (if self.sleeping { 0 } else { 0x90 })
+            | (if self.idle { 0x40 } else { 0 })
+            | (if self.partial { 0x20 } else { 0x08 })
+            | (if self.display_on { 0x04 } else { 0 })
+    }
+
+    fn write_pixel(&mut self, color: Rgb565) {
+        if let Some((x, y)) = self.physical(self.col, self.row) {
+            self.gram[y as usize * self.width as usize + x as usize] = color;
+        }
+        if self.col >= self.col_end {
+            self.col = self.col_start;
+            self.row = if self.row >= self.row_end { self.row_start } else { self.row + 1 };
+        } else {
+            self.col += 1;
+        }
+    }
+
+    fn data_byte(&mut self, byte: u8) {
+        self.params.push(byte);
+        let p = &self.params;
+        match self.cmd {
+            CASET if p.len() == 4 => {
+                self.col_start = (p[0] as u16) << 8 | p[1] as u16;
+                self.col_end = (p[2] as u16) << 8 | p[3] as u16;
+            }
+            RASET if p.len() == 4 => {
+                self.row_start = (p[0] as u16) << 8 | p[1] as u16;
+                self.row_end = (p[2] as u16) << 8 | p[3] as u16;
+            }
+            PTLAR if p.len() == 4 => {
+                self.partial_start = (p[0] as u16) << 8 | p[1] as u16;
+                self.partial_end = (p[2] as u16) << 8 | p[3] as u16;
+            }
+            VSCRDEF if p.len() == 6 => {
+                self.scroll_top = (p[0] as u16) << 8 | p[1] as u16;
+                self.scroll_height = (p[2] as u16) << 8 | p[3] as u16;
+            }
+            VSCRSADD if p.len() == 2 => self.scroll_start = (p[0] as u16) << 8 | p[1] as u16,
+            MADCTL if p.len() == 1 => self.madctl = p[0],
+            COLMOD if p.len() == 1 => self.colmod = p[0],
+            // GRAM is kept as RGB565, deeper formats lose their low bits
+            RAMWR => match self.colmod & 0x07 {
+                0x06 if p.len() == 3 => {
+                    let color = Rgb565::new(p[0] >> 3, p[1] >> 2, p[2] >> 3);
+                    self.params.clear();
+                    self.write_pixel(color);
+                }
+                // two pixels in three bytes, the first is done after 12 bits
+                0x03 if p.len() == 2 => {
+                    let color = rgb444(p[0] >> 4, p[0] & 0x0F, p[1] >> 4);
+                    self.write_pixel(color);
+                }
+                0x03 if p.len() == 3 => {
+                    let color = rgb444(p[1] & 0x0F, p[2] >> 4, p[2] & 0x0F);
+                    self.params.clear();
+                    self.write_pixel(color);
+                }
+                0x05 if p.len() == 2 => {
+                    let color = Rgb565::from_be_bytes([p[0], p[1]]);
+                    self.params.clear();
+                    self.write_pixel(color);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+fn rgb444(r: u8, g: u8, b: u8) -> Rgb565 {
+    Rgb565::new(r << 1 | r >> 3, g << 2 | g >> 2, b << 1 | b >> 3)
+}
+
+impl LcdInterface for Simulator {
+    fn reset(&mut self) {
+        self.software_reset();
+    }
+
+    fn delay_us(&mut self, usec: usize) {
+        self.elapsed_us += usec as u64;
+    }
+
+    fn command(&mut self, cmd: u8) {
+        self.commands.push(cmd);
+        self.cmd = cmd;
+        self.params.clear();
+        match cmd {
+            SWRESET => self.software_reset(),
+            SLPIN => self.sleeping = true,
+            SLPOUT => self.sleeping = false,
+            PTLON => self.partial = true,
+            NORON => self.partial = false,
+            IDMOFF => self.idle = false,
+            IDMON => self.idle = true,
+            INVOFF => self.inverted = false,
+            INVON => self.inverted = true,
+            DISPOFF => self.display_on = false,
+            DISPON => self.display_on = true,
+            TEOFF => self.tearing = false,
+            TEON => self.tearing = true,
+            RAMWR => {
+                self.col = self.col_start;
+                self.row = self.row_start;
+            }
+            _ => {}
+        }
+    }
+
+    fn data8(&mut self, data: &[u8]) {
+        for &b in data {
+            self.data_byte(b);
+        }
+    }
+
+    fn data16(&mut self, data: &[u16]) {
+        for &v in data {
+            for b in v.to_be_bytes() {
+                self.data_byte(b);
+            }
+        }
+    }
+
+    fn data32(&mut self, data: &[u32]) {
+        for &v in data {
+            for b in v.to_be_bytes() {
+                self.data_byte(b);
+            }
+        }
+    }
+
+    fn fill(&mut self, value: u32, len: usize) {
+        for _ in 0..len {
+            for b in value.to_be_bytes() {
+                self.data_byte(b);
+            }
+        }
+    }
+
+    fn read(&mut self, cmd: u8, buf: &mut [u8]) -> Result<(), LcdError> {
+        self.commands.push(cmd);
+        let mode = self.power_mode();
+        let answer = match cmd {
+            RDDID => self.id.to_vec(),
+            // dummy parameter and IC version first
+            RDID4 => [0, 0, (self.ic >> 8) as u8, self.ic as u8].to_vec(),
+            RDDST => vec![
+                (mode & 0x80) | (self.madctl >> 1 & 0x7E),
+                (self.colmod & 0x07) << 4 | (mode >> 3 & 0x0F),
+                (if self.inverted { 0x20 } else { 0 }) | (mode & 0x04) | (if self.tearing { 0x02 } else { 0 }),
+                0x00,
+            ],
+            RDDPM => vec![mode],
+            RDDMADCTL => vec![self.madctl],
+            RDDCOLMOD => vec![self.colmod],
+            _ => Vec::new(),
+        };
+        for (i, b) in buf.iter_mut().enumerate() {
+            *b = answer.get(i).copied().unwrap_or(0);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use crate::color::Rgb565;
+use crate::lcd::LcdError;
+
+/// Which pixels of a `Sprite` let the background through.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Transparency<'a> {
+    Opaque,
+    /// Pixels of this colour are skipped.
+    Key(Rgb565),
+    /// Coverage per pixel, 0 for see-through to 255 for solid, laid out
+    /// like the colour data and just as long.
+    Alpha(&'a [u8]),
+}
+
+/// Image with transparent parts, e.g. a HUD icon drawn over the camera
+/// preview.
+///
+/// The pixels are borrowed, so a sprite can live in flash. Flips and
+/// rotations only change how they are read when drawing; `size` is the
+/// size on screen after them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Sprite<'a> {
+    /// Stored size, before any rotation.
+    pub width: u16,
+    pub height: u16,
+    /// Row by row, exactly `width` x `height` pixels.
+    pub data: &'a [Rgb565],
+    pub transparency: Transparency<'a>,
+    // applied to screen coordinates in this order: mirror x, mirror y,
+    // then swap x and y to find the stored pixel
+    flip_x: bool,
+    flip_y: bool,
+    transpose: bool,
+}
+
+impl<'a> Sprite<'a> {
+    pub const fn new(width: u16, height: u16, data: &'a [Rgb565]) -> Self {
+        Sprite { width, height, data, transparency: Transparency::Opaque, flip_x: false, flip_y: false, transpose: false }
+    }
+
+    pub const fn with_key(mut self, key: Rgb565) -> Self {
+        self.transparency = Transparency::Key(key);
+        self
+    }
+
+    pub const fn with_alpha(mut self, alpha: &'a [u8]) -> Self {
+        self.transparency = Transparency::Alpha(alpha);
+        self
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(mut self) -> Self {
+        self.flip_x = !self.flip_x;
+        self
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(mut self) -> Self {
+        self.flip_y = !self.flip_y;
+        self
+    }
+
+    /// Turn a quarter clockwise.
+    pub fn rotate_cw(self) -> Sprite<'a> {
+        Sprite { flip_x: !self.flip_y, flip_y: self.flip_x, transpose: !self.transpose, ..self }
+    }
+
+    /// Turn a quarter counterclockwise.
+    pub fn rotate_ccw(self) -> Sprite<'a> {
+        Sprite { flip_x: self.flip_y, flip_y: !self.flip_x, transpose: !self.transpose, ..self }
+    }
+
+    pub fn rotate_180(self) -> Sprite<'a> {
+        self.flip_horizontal().flip_vertical()
+    }
+
+    /// Width and height as drawn.
+    pub fn size(&self) -> (u16, u16) {
+        if self.transpose {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        }
+    }
+
+    /// Index into `data` of the pixel drawn at `x`, `y`.
+    fn index(&self, x: usize, y: usize) -> usize {
+        let (w, h) = self.size();
+        let x = if self.flip_x { w as usize - 1 - x } else { x };
+        let y = if self.flip_y { h as usize - 1 - y } else { y };
+        let (sx, sy) = if self.transpose { (y, x) } else { (x, y) };
+        sy * self.width as usize + sx
+    }
+
+    /// `BufferSizeMismatch` unless `data`, and the alpha plane if there is
+    /// one, hold exactly `width` x `height` pixels.
+    pub fn check(&self) -> Result<(), LcdError> {
+        let len = self.width as usize * self.height as usize;
+        let alpha_len = match self.transparency {
+            Transparency::Alpha(alpha) => alpha.len(),
+            _ => len,
+        };
+        if self.data.len() != len || alpha_len != len {
+            return Err(LcdError::BufferSizeMismatch);
+        }
+        Ok(())
+    }
+
+    /// Visit the pixels that land inside a `target_w` x `target_h` target
+    /// with the top left corner at `x`, `y`, as target position, colour and
+    /// coverage. Fully transparent pixels are left out. Returns the area
+    /// covered, clipped, or `None` if the sprite is entirely off the target.
+    pub(crate) fn composite<F: FnMut(usize, usize, Rgb565, u8)>(&self, x: i32, y: i32, target_w: u16, target_h: u16, mut f: F) -> Result<Option<(u16, u16, u16, u16)>, LcdError> {
+        self.check()?;
+        let (w, h) = self.size();
+        let x0 = x.max(0);
+        let y0 = y.max(0);
+        let x1 = x.saturating_add(w as i32).min(target_w as i32);
+        let y1 = y.saturating_add(h as i32).min(target_h as i32);
+        if x0 >= x1 || y0 >= y1 {
+            return Ok(None);
+        }
+        for ty in y0..y1 {
+            for tx in x0..x1 {
+                let i = self.index((tx - x) as usize, (ty - y) as usize);
+                let color = self.data[i];
+                let alpha = match self.transparency {
+                    Transparency::Opaque => 255,
+                    Transparency::Key(key) => if color == key { 0 } else { 255 },
+                    Transparency::Alpha(alpha) => alpha[i],
+                };
+                if alpha > 0 {
+                    f(tx as usize, ty as usize, color, alpha);
+                }
+            }
+        }
+        Ok(Some((x0 as u16, y0 as u16, (x1 - x0) as u16, (y1 - y0) as u16)))
+    }
+
+    /// Draw into a buffer packed like `Lcd::lcd_draw_picture` expects, two
+    /// pixels per word with the left one in the high half, e.g. the
+    /// camera's `buf` before it goes out to the panel. `width` is the
+    /// buffer's width in pixels and must be even; the sprite is clipped to
+    /// the buffer. Refused like `FrameBuffer::draw_sprite` if the sprite's
+    /// pixels don't match its size.
+    pub fn blit_packed(&self, buf: &mut [u32], width: u16, x: i32, y: i32) -> Result<(), LcdError> {
+        if width == 0 {
+            return self.check();
+        }
+        let height = (buf.len() * 2 / width as usize).min(u16::MAX as usize) as u16;
+        self.composite(x, y, width, height, |px, py, color, alpha| {
+            let i = py * width as usize + px;
+            let word = &mut buf[i / 2];
+            let shift = if i % 2 == 1 { 0 } else { 16 };
+            let color = match alpha {
+                255 => color,
+                _ => color.blend(Rgb565((*word >> shift) as u16), alpha),
+            };
+            *word = (*word & !(0xFFFF << shift)) | ((color.0 as u32) << shift);
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+//! Decoding the images in `tests/data`, which `gen.py` there writes along
+//! with the pixels each should come out as.
+
+use k210_lcd::color::Rgb565;
+use k210_lcd::image::{Bmp, ImageDecoder, ImageError};
+
+const WIDTH: usize = 29;
+const HEIGHT: usize = 19;
+
+/// All pixels, top row first whichever order they are stored in.
+fn decode(image: &mut impl ImageDecoder) -> Result<Vec<Rgb565>, ImageError> {
+    let mut rows = vec![vec![Rgb565::default(); image.width() as usize]; image.height() as usize];
+    for row in rows.iter_mut() {
+        image.next_row(row)?;
+    }
+    if image.bottom_up() {
+        rows.reverse();
+    }
+    Ok(rows.concat())
+}
+
+fn expected(rgb: &[u8]) -> Vec<Rgb565> {
+    rgb.chunks(3).map(|p| Rgb565::from_rgb888(p[0], p[1], p[2])).collect()
+}
+
+fn check(name: &str, bmp: &[u8], rgb: &[u8]) {
+    let mut image = Bmp::new(bmp).unwrap_or_else(|e| panic!("{}: {:?}", name, e));
+    assert_eq!((image.width() as usize, image.height() as usize), (WIDTH, HEIGHT), "{}", name);
+    assert!(decode(&mut image).unwrap() == expected(rgb), "{}: wrong pixels", name);
+}
+
+#[test]
+fn palette_images() {
+    check("pal1", include_bytes!("data/pal1.bmp"), include_bytes!("data/pal1.rgb"));
+    check("pal4", include_bytes!("data/pal4.bmp"), include_bytes!("data/pal4.rgb"));
+    check("pal8", include_bytes!("data/pal8.bmp"), include_bytes!("data/pal8.rgb"));
+    // OS/2 header, 3 byte palette entries
+    check("core8", include_bytes!("data/core8.bmp"), include_bytes!("data/core8.rgb"));
+}
+
+#[test]
+fn rle_with_delta_and_end_of_bitmap() {
+    // the pixels the delta jumps over and the rows after the end are black
+    check("rle8", include_bytes!("data/rle8.bmp"), include_bytes!("data/rle8.rgb"));
+    check("rle4", include_bytes!("data/rle4.bmp"), include_bytes!("data/rle4.rgb"));
+}
+
+#[test]
+fn true_colour_images() {
+    // 5-5-5 without BI_BITFIELDS, 5-6-5 masks in a V4 header
+    check("rgb555", include_bytes!("data/rgb555.bmp"), include_bytes!("data/rgb555.rgb"));
+    check("rgb565", include_bytes!("data/rgb565.bmp"), include_bytes!("data/rgb565.rgb"));
+    check("rgb24", include_bytes!("data/rgb24.bmp"), include_bytes!("data/rgb24.rgb"));
+    // BGRX without BI_BITFIELDS, XBGR masks after a 40 byte header
+    check("rgb32", include_bytes!("data/rgb32.bmp"), include_bytes!("data/rgb32.rgb"));
+    check("rgb32bf", include_bytes!("data/rgb32bf.bmp"), include_bytes!("data/rgb32bf.rgb"));
+}
+
+#[test]
+fn bottom_up_and_top_down() {
+    let bottom_up = Bmp::new(include_bytes!("data/rgb24.bmp")).unwrap();
+    let top_down = Bmp::new(include_bytes!("data/rgb24td.bmp")).unwrap();
+    assert!(bottom_up.bottom_up());
+    assert!(!top_down.bottom_up());
+    check("rgb24td", include_bytes!("data/rgb24td.bmp"), include_bytes!("data/rgb24td.rgb"));
+}
+
+#[test]
+fn rle_has_to_be_bottom_up() {
+    let mut data = include_bytes!("data/rle8.bmp").to_vec();
+    data[22..26].copy_from_slice(&(-(HEIGHT as i32)).to_le_bytes());
+    assert_eq!(Bmp::new(&data).err(), Some(ImageError::BadHeader));
+}
+
+#[test]
+fn truncated_input() {
+    let pal8 = include_bytes!("data/pal8.bmp");
+    // in the header, then in the palette
+    assert_eq!(Bmp::new(&pal8[..30]).err(), Some(ImageError::Truncated));
+    assert_eq!(Bmp::new(&pal8[..14 + 40 + 100]).err(), Some(ImageError::Truncated));
+    // uncompressed pixels are checked up front
+    let rgb24 = include_bytes!("data/rgb24.bmp");
+    assert_eq!(Bmp::new(&rgb24[..rgb24.len() - 1]).err(), Some(ImageError::Truncated));
+    // RLE only finds out on the way
+    for rle in [&include_bytes!("data/rle8.bmp")[..], &include_bytes!("data/rle4.bmp")[..]] {
+        let mut image = Bmp::new(&rle[..rle.len() - 10]).unwrap();
+        assert_eq!(decode(&mut image).err(), Some(ImageError::Truncated));
+    }
+}
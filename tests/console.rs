@@ -0,0 +1,133 @@
+//! `Console` drawing into the simulator, read back off the glass one
+//! character cell at a time.
+
+use core::fmt::Write;
+
+use k210_lcd::console::Console;
+use k210_lcd::constant::*;
+use k210_lcd::font::{Font, FONT_8X16};
+use k210_lcd::lcd::Lcd;
+use k210_lcd::sim::{Simulator, View};
+
+fn lcd(dir: Dir) -> Lcd<Simulator> {
+    let (w, h) = if dir.is_swapped() { (320, 240) } else { (240, 320) };
+    Lcd::with_interface(Simulator::st7789(), w, h, dir)
+}
+
+/// The text on screen, black on white in 8x16 cells, one string per row
+/// with the trailing spaces cut off. Cells that match no printable ASCII
+/// character read as `#`.
+fn text(lcd: &Lcd<Simulator>) -> Vec<String> {
+    let (width, height, pixels) = lcd.interface.image(View::Addressed);
+    let cell = |col: u16, row: u16| {
+        let shows = |c: char| {
+            let glyph = FONT_8X16.glyph(c).unwrap();
+            (0..16).all(|y| {
+                (0..8).all(|x| {
+                    let p = pixels[(row * 16 + y) as usize * width as usize + (col * 8 + x) as usize];
+                    p == if glyph.pixel(x as u8, y as u8) { BLACK } else { WHITE }
+                })
+            })
+        };
+        (' '..='~').find(|&c| shows(c)).unwrap_or('#')
+    };
+    (0..height / 16).map(|row| (0..width / 8).map(|col| cell(col, row)).collect::<String>().trim_end().to_string()).collect()
+}
+
+#[test]
+fn newline_and_carriage_return() {
+    let mut lcd = lcd(Dir::XyRlud);
+    let mut console = Console::new(&mut lcd, BLACK, WHITE).unwrap();
+    assert_eq!(console.size(), (30, 20));
+    write!(console, "hello\nworld").unwrap();
+    assert_eq!(console.cursor(), (5, 1));
+    write!(console, "\nabcdef\rXY").unwrap();
+    assert_eq!(console.cursor(), (2, 2));
+    assert_eq!(text(&lcd)[..4], ["hello", "world", "XYcdef", ""]);
+}
+
+#[test]
+fn write_line_clears_what_a_carriage_return_left() {
+    let mut lcd = lcd(Dir::XyRlud);
+    let mut console = Console::new(&mut lcd, BLACK, WHITE).unwrap();
+    write!(console, "abcdef\r").unwrap();
+    console.write_line("XY").unwrap();
+    // a line of its own, even after text
+    write!(console, "one").unwrap();
+    console.write_line("two").unwrap();
+    assert_eq!(console.cursor(), (0, 3));
+    assert_eq!(text(&lcd)[..4], ["XY", "one", "two", ""]);
+}
+
+#[test]
+fn tabs_go_to_the_next_multiple_of_eight() {
+    let mut lcd = lcd(Dir::XyRlud);
+    let mut console = Console::new(&mut lcd, BLACK, WHITE).unwrap();
+    write!(console, "a\tb\n12345678\tc\n\t\t\td").unwrap();
+    // the last tab stop is cut short by the edge
+    write!(console, "\t").unwrap();
+    assert_eq!(console.cursor(), (30, 2));
+    assert_eq!(text(&lcd)[..3], ["a       b", "12345678        c", "                        d"]);
+}
+
+#[test]
+fn long_text_wraps_at_the_right_edge() {
+    let mut lcd = lcd(Dir::XyRlud);
+    let mut console = Console::new(&mut lcd, BLACK, WHITE).unwrap();
+    write!(console, "012345678901234567890123456789abcde").unwrap();
+    assert_eq!(console.cursor(), (5, 1));
+    assert_eq!(text(&lcd)[..3], ["012345678901234567890123456789", "abcde", ""]);
+}
+
+#[test]
+fn anything_but_printable_ascii_is_a_question_mark() {
+    let mut lcd = lcd(Dir::XyRlud);
+    let mut console = Console::new(&mut lcd, BLACK, WHITE).unwrap();
+    write!(console, "h\u{e9}llo \u{4e2d}\u{1f600}!\x07").unwrap();
+    // control characters too, and one cell per character however many
+    // bytes it takes
+    console.write_line("a\tb\nc\u{e9}").unwrap();
+    console.write_line(&"\u{e9}".repeat(40)).unwrap();
+    assert_eq!(console.cursor(), (0, 3));
+    assert_eq!(text(&lcd)[..4], ["h?llo ??!?", "a?b?c?", &"?".repeat(30), ""]);
+}
+
+#[test]
+fn full_screen_scrolls_in_hardware() {
+    let mut lcd = lcd(Dir::XyRlud);
+    let mut console = Console::new(&mut lcd, BLACK, WHITE).unwrap();
+    for i in 0..25 {
+        writeln!(console, "line {}", i).unwrap();
+    }
+    assert_eq!(console.cursor(), (0, 19));
+    let expected: Vec<String> = (5..25).map(|i| format!("line {}", i)).collect();
+    assert_eq!(text(&lcd), expected);
+}
+
+#[test]
+fn scrolling_waits_for_the_next_character() {
+    let mut lcd = lcd(Dir::XyRlud);
+    let mut console = Console::new(&mut lcd, BLACK, WHITE).unwrap();
+    for i in 0..20 {
+        writeln!(console, "line {}", i).unwrap();
+    }
+    // the line after the last one is only made room for here
+    write!(console, "last").unwrap();
+    let text = text(&lcd);
+    assert_eq!(text[0], "line 1");
+    assert_eq!(text[18..], ["line 19", "last"]);
+}
+
+#[test]
+fn full_screen_starts_again_without_hardware_scrolling() {
+    // rows run across the native columns, hardware scrolling can't help
+    let mut lcd = lcd(Dir::YxLrud);
+    let mut console = Console::new(&mut lcd, BLACK, WHITE).unwrap();
+    assert_eq!(console.size(), (40, 15));
+    for i in 0..20 {
+        writeln!(console, "line {}", i).unwrap();
+    }
+    let text = text(&lcd);
+    assert_eq!(text[..5], ["line 15", "line 16", "line 17", "line 18", "line 19"]);
+    assert!(text[5..].iter().all(|row| row.is_empty()));
+}
@@ -0,0 +1,178 @@
+"""Writes the BMP and QOI test images in this directory, each with the
+pixels it should decode to in a .rgb file (8 bit RGB, top row first) or,
+for images with alpha, a .rgba file. Plain Python, no imaging library, so
+the expected pixels don't come from the code under test:
+
+    cd tests/data && python3 gen.py
+"""
+import struct
+
+W, H = 29, 19
+
+def disc(x, y):
+    return (x - 10) ** 2 + (y - 9) ** 2 < 25
+
+def src(x, y):
+    r = x * 255 // (W - 1); g = y * 255 // (H - 1); b = 255 - r
+    if disc(x, y): return (255, 255, 255)
+    return (r, g, b)
+img = [[src(x, y) for x in range(W)] for y in range(H)]
+
+def bmp(name, bpp, rows_bytes, palette=b'', compression=0, top_down=False, masks=b'', header=40):
+    if header == 12:
+        dib = struct.pack('<IHHHH', 12, W, H, 1, bpp)
+    else:
+        dib = struct.pack('<IiiHHIIiiII', header, W, -H if top_down else H, 1, bpp, compression, 0, 2835, 2835, len(palette) // 4 if palette and header != 12 else 0, 0)
+        dib += masks
+        dib += b'\0' * (header - len(dib)) if header > 40 else b''
+        if header > 40 and masks:  # masks live inside the longer header
+            dib = dib[:40] + masks + dib[40 + len(masks):][:header - 40 - len(masks)]
+            dib = dib[:header]
+    off = 14 + len(dib) + len(palette)
+    body = b''.join(rows_bytes)
+    f = b'BM' + struct.pack('<IHHI', off + len(body), 0, 0, off) + dib + palette + body
+    open(name, 'wb').write(f)
+
+def pad(b):
+    return b + b'\0' * (-len(b) % 4)
+
+def stored(rows, top_down=False):
+    return rows if top_down else rows[::-1]
+
+def expect(name, pix, ext='.rgb'):
+    open(name + ext, 'wb').write(bytes(c for row in pix for p in row for c in p))
+
+# 24 bit, bottom up and top down
+rows = [pad(bytes(c for p in row for c in p[::-1])) for row in img]
+bmp('rgb24.bmp', 24, stored(rows)); expect('rgb24', img)
+bmp('rgb24td.bmp', 24, stored(rows, True), top_down=True); expect('rgb24td', img)
+# 32 bit BGRX
+rows = [bytes(c for p in row for c in (p[2], p[1], p[0], 0)) for row in img]
+bmp('rgb32.bmp', 32, stored(rows)); expect('rgb32', img)
+# 32 bit XBGR bitfields, masks after a 40 byte header
+rows = [bytes(c for p in row for c in (0, p[2], p[1], p[0])) for row in img]
+bmp('rgb32bf.bmp', 32, stored(rows), compression=3, masks=struct.pack('<III', 0xFF000000, 0x00FF0000, 0x0000FF00)); expect('rgb32bf', img)
+# 16 bit 555 and 565 bitfields, v4 header for the latter
+def s555(p): return ((p[0] >> 3) << 10) | ((p[1] >> 3) << 5) | (p[2] >> 3)
+rows = [pad(b''.join(struct.pack('<H', s555(p)) for p in row)) for row in img]
+bmp('rgb555.bmp', 16, stored(rows))
+expect('rgb555', [[tuple(((c >> 3) * 255) // 31 for c in p) for p in row] for row in img])
+def s565(p): return ((p[0] >> 3) << 11) | ((p[1] >> 2) << 5) | (p[2] >> 3)
+rows = [pad(b''.join(struct.pack('<H', s565(p)) for p in row)) for row in img]
+bmp('rgb565.bmp', 16, stored(rows), compression=3, masks=struct.pack('<III', 0xF800, 0x07E0, 0x001F), header=108)
+expect('rgb565', [[(((p[0] >> 3) * 255) // 31, ((p[1] >> 2) * 255) // 63, ((p[2] >> 3) * 255) // 31) for p in row] for row in img])
+
+# palette images
+def pal_img(n):
+    pal = [((i * 97 + 40) % 256, (i * 53 + 20) % 256, (i * 181 + 60) % 256) for i in range(n)]
+    idx = [[(x * n // W + y) % n if not disc(x, y) else 0 for x in range(W)] for y in range(H)]
+    return pal, idx
+def palbytes(pal, entry=4):
+    return b''.join(bytes((b, g, r) + ((0,) if entry == 4 else ())) for r, g, b in pal)
+def packed(idx, bpp):
+    out = []
+    for row in idx:
+        bits = 0; n = 0; b = bytearray()
+        for i in row:
+            bits = bits << bpp | i; n += bpp
+            if n == 8: b.append(bits); bits = 0; n = 0
+        if n: b.append(bits << (8 - n))
+        out.append(pad(bytes(b)))
+    return out
+for bpp in (1, 4, 8):
+    pal, idx = pal_img(1 << bpp)
+    bmp('pal%d.bmp' % bpp, bpp, stored(packed(idx, bpp)), palbytes(pal))
+    expect('pal%d' % bpp, [[pal[i] for i in row] for row in idx])
+# OS/2 core header, 8 bit
+pal, idx = pal_img(256)
+bmp('core8.bmp', 8, stored(packed(idx, 8)), palbytes(pal, 3), header=12)
+expect('core8', [[pal[i] for i in row] for row in idx])
+
+# RLE: runs, literals, one delta and an early end of bitmap
+def rle(idx, nib):
+    out = bytearray()
+    rows = idx[::-1]
+    y = 0
+    expected = [[None] * W for _ in range(H)]
+    while y < len(rows):
+        row = rows[y]
+        if y == 5:
+            # delta: skip 3 columns right and 2 rows up from x = 10
+            x = 0
+            out += bytes((10, row[0] << 4 | row[0] if nib else row[0]))
+            for i in range(10): expected[y][i] = row[0]
+            out += bytes((0, 2, 3, 2))
+            y += 2
+            row = rows[y]
+            x = 13
+        else:
+            x = 0
+        while x < W:
+            n = 1
+            while x + n < W and row[x + n] == row[x] and n < 255: n += 1
+            if n >= 3 or W - x < 3:
+                n = min(n, W - x)
+                out += bytes((n, row[x] << 4 | row[x] if nib else row[x]))
+                for i in range(n): expected[y][x + i] = row[x]
+                x += n
+            else:
+                n = min(W - x, 20)
+                vals = row[x:x + n]
+                out += bytes((0, n))
+                if nib:
+                    lit = bytearray((vals[i] << 4) | (vals[i + 1] if i + 1 < n else 0) for i in range(0, n, 2))
+                else:
+                    lit = bytearray(vals)
+                if len(lit) % 2: lit.append(0)
+                out += lit
+                for i in range(n): expected[y][x + i] = vals[i]
+                x += n
+        y += 1
+        if y == H - 3:
+            out += bytes((0, 1)); break
+        out += bytes((0, 0))
+    return bytes(out), expected[::-1]
+for bpp, comp in ((8, 1), (4, 2)):
+    pal, idx = pal_img(1 << bpp)
+    data, exp = rle(idx, bpp == 4)
+    bmp('rle%d.bmp' % bpp, bpp, [data], palbytes(pal), compression=comp)
+    expect('rle%d' % bpp, [[pal[i] if i is not None else (0, 0, 0) for i in row] for row in exp])
+
+# QOI, reference encoder
+def qoi(name, pix, channels):
+    out = bytearray(b'qoif' + struct.pack('>IIBB', W, H, channels, 0))
+    index = [(0, 0, 0, 0)] * 64
+    prev = (0, 0, 0, 255); run = 0
+    flat = [p for row in pix for p in row]
+    for i, px in enumerate(flat):
+        if px == prev:
+            run += 1
+            if run == 62 or i == len(flat) - 1:
+                out.append(0xC0 | (run - 1)); run = 0
+            continue
+        if run: out.append(0xC0 | (run - 1)); run = 0
+        h = (px[0] * 3 + px[1] * 5 + px[2] * 7 + px[3] * 11) % 64
+        if index[h] == px:
+            out.append(h)
+        else:
+            index[h] = px
+            if px[3] == prev[3]:
+                dr = (px[0] - prev[0] + 128) % 256 - 128
+                dg = (px[1] - prev[1] + 128) % 256 - 128
+                db = (px[2] - prev[2] + 128) % 256 - 128
+                if -2 <= dr <= 1 and -2 <= dg <= 1 and -2 <= db <= 1:
+                    out.append(0x40 | (dr + 2) << 4 | (dg + 2) << 2 | (db + 2))
+                elif -32 <= dg <= 31 and -8 <= dr - dg <= 7 and -8 <= db - dg <= 7:
+                    out += bytes((0x80 | (dg + 32), (dr - dg + 8) << 4 | (db - dg + 8)))
+                else:
+                    out += bytes((0xFE,) + px[:3])
+            else:
+                out += bytes((0xFF,) + px)
+        prev = px
+    out += b'\0' * 7 + b'\1'
+    open(name + '.qoi', 'wb').write(out)
+qoi('qoi_rgb', [[p + (255,) for p in row] for row in img], 3); expect('qoi_rgb', img)
+# alpha ramp left to right over a black background
+apix = [[p + (x * 255 // (W - 1),) for x, p in enumerate(row)] for row in img]
+qoi('qoi_rgba', apix, 4)
+expect('qoi_rgba', apix, '.rgba')
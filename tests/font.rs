@@ -0,0 +1,77 @@
+use k210_lcd::constant::*;
+use k210_lcd::font::{Font, FontStack, Glyph, CJK_16, FONT_8X16, SANS_16};
+use k210_lcd::framebuffer::FrameBuffer;
+
+/// Whether `fb` shows `glyph` in black at `x`, `y` on white.
+fn shows(fb: &FrameBuffer, x: u16, y: u16, glyph: &Glyph, height: u8) -> bool {
+    (0..height).all(|row| {
+        (0..glyph.width).all(|col| {
+            let expected = if glyph.pixel(col, row) { BLACK } else { WHITE };
+            fb.get_pixel(x + col as u16, y + row as u16) == Some(expected)
+        })
+    })
+}
+
+#[test]
+fn unicode_font_finds_every_character() {
+    // the characters the font was cut down to, in code point order
+    let chars = "一丁三上下中二人十口大小文日月田";
+    for (i, c) in chars.chars().enumerate() {
+        let glyph = CJK_16.glyph(c).unwrap_or_else(|| panic!("no glyph for {}", c));
+        assert_eq!(glyph.width, 16);
+        assert_eq!(glyph.bitmap.as_ptr(), CJK_16.data[i * 32..].as_ptr(), "{}", c);
+    }
+    // bottom stroke of 上
+    assert_eq!(&CJK_16.glyph('上').unwrap().bitmap[24..26], &[0x7F, 0xFE]);
+}
+
+#[test]
+fn unicode_font_misses_between_ranges() {
+    // U+4E00-4E01 and U+4E09-4E0B are the only runs longer than one
+    for code in [0, 0x3F, 0x4DFF, 0x4E02, 0x4E08, 0x4E0C, 0x4E2C, 0x4E2E, 0x7531, 0xFFFF, 0x10FFFF] {
+        let c = char::from_u32(code).unwrap();
+        assert!(CJK_16.glyph(c).is_none(), "U+{:04X}", code);
+    }
+    for code in [0x4E00, 0x4E01, 0x4E09, 0x4E0B, 0x7530] {
+        assert!(CJK_16.glyph(char::from_u32(code).unwrap()).is_some(), "U+{:04X}", code);
+    }
+}
+
+#[test]
+fn unicode_font_falls_back_to_its_box() {
+    let fallback = CJK_16.fallback().unwrap();
+    assert_eq!(fallback.width, 8);
+    assert_eq!(fallback.bitmap.as_ptr(), CJK_16.data[16 * 32..].as_ptr());
+    assert_eq!(CJK_16.text_width("中?文"), 16 + 8 + 16);
+}
+
+#[test]
+fn font_stack_takes_what_the_primary_lacks_from_the_secondary() {
+    let stack = FontStack::new(FONT_8X16, CJK_16);
+    assert_eq!(stack.height(), 16);
+    assert_eq!(stack.glyph('A').unwrap().bitmap.as_ptr(), FONT_8X16.glyph('A').unwrap().bitmap.as_ptr());
+    assert_eq!(stack.glyph('中').unwrap().bitmap.as_ptr(), CJK_16.glyph('中').unwrap().bitmap.as_ptr());
+    assert!(stack.glyph('€').is_none());
+    // the secondary's fallback wins, the box rather than '?'
+    assert_eq!(stack.fallback().unwrap().bitmap.as_ptr(), CJK_16.fallback().unwrap().bitmap.as_ptr());
+    assert_eq!(stack.text_width("A中b€"), 8 + 16 + 8 + 8);
+}
+
+#[test]
+fn draw_string_decodes_utf8() {
+    let stack = FontStack::new(SANS_16, CJK_16);
+    let mut fb = FrameBuffer::new(80, 16);
+    // one, two, three and four byte sequences, the last one missing
+    let s = "aé中\u{20000}";
+    assert_eq!(s.len(), 1 + 2 + 3 + 4);
+    fb.draw_string(0, 0, s, &stack, BLACK);
+    let a = SANS_16.glyph('a').unwrap();
+    let e = SANS_16.glyph('é').unwrap();
+    let zhong = CJK_16.glyph('中').unwrap();
+    assert!(shows(&fb, 0, 0, &a, 16));
+    let x = a.width as u16;
+    assert!(shows(&fb, x, 0, &e, 16));
+    let x = x + e.width as u16;
+    assert!(shows(&fb, x, 0, &zhong, 16));
+    assert!(shows(&fb, x + 16, 0, &stack.fallback().unwrap(), 16));
+}
@@ -0,0 +1,145 @@
+use k210_lcd::color::Rgb565;
+use k210_lcd::constant::*;
+use k210_lcd::framebuffer::{FrameBuffer, Rect};
+use k210_lcd::lcd::{Lcd, LcdError};
+use k210_lcd::sim::Simulator;
+
+// CASET, RASET, RAMWR: one window
+const WINDOW: [u8; 3] = [0x2A, 0x2B, 0x2C];
+
+fn lcd() -> Lcd<Simulator> {
+    Lcd::with_interface(Simulator::st7789(), 240, 320, Dir::XyRlud)
+}
+
+/// Fill `rect` in the buffer's memory and mark it dirty.
+fn paint(fb: &mut FrameBuffer, rect: Rect, color: Rgb565) {
+    for y in rect.y..rect.bottom() {
+        for x in rect.x..rect.right() {
+            fb.data[y as usize * fb.width as usize + x as usize] = color;
+        }
+    }
+    fb.mark_dirty(rect);
+}
+
+#[test]
+fn draw_picture_needs_every_pixel() {
+    let mut fb = FrameBuffer::new(16, 8);
+    assert_eq!(fb.draw_picture(0, 0, 3, 3, &[0; 4]), Err(LcdError::BufferSizeMismatch));
+    assert_eq!(fb.draw_picture(0, 0, 3, 3, &[0; 6]), Err(LcdError::BufferSizeMismatch));
+    assert!(!fb.is_dirty());
+    assert!(fb.data.iter().all(|&p| p == WHITE));
+}
+
+#[test]
+fn draw_picture_is_cut_off_at_the_edge() {
+    let mut fb = FrameBuffer::new(16, 8);
+    // 3 x 3, the last word half used
+    let data = [0xF800_07E0, 0x001F_F800, 0x07E0_001F, 0xF800_07E0, 0x001F_0000];
+    fb.draw_picture(14, 6, 3, 3, &data).unwrap();
+    assert_eq!(fb.get_pixel(14, 6), Some(RED));
+    assert_eq!(fb.get_pixel(15, 6), Some(GREEN));
+    assert_eq!(fb.get_pixel(14, 7), Some(RED));
+    assert_eq!(fb.dirty(), &[Rect::new(14, 6, 2, 2)]);
+}
+
+#[test]
+fn mark_dirty_merges_overlapping_regions() {
+    let mut fb = FrameBuffer::new(64, 64);
+    fb.mark_dirty(Rect::new(0, 0, 10, 10));
+    fb.mark_dirty(Rect::new(5, 5, 10, 10));
+    assert_eq!(fb.dirty(), &[Rect::new(0, 0, 15, 15)]);
+    // one inside another adds nothing
+    fb.mark_dirty(Rect::new(2, 3, 4, 4));
+    assert_eq!(fb.dirty(), &[Rect::new(0, 0, 15, 15)]);
+}
+
+#[test]
+fn mark_dirty_merges_adjacent_regions() {
+    let mut fb = FrameBuffer::new(64, 64);
+    fb.mark_dirty(Rect::new(0, 0, 10, 10));
+    fb.mark_dirty(Rect::new(10, 0, 5, 10));
+    assert_eq!(fb.dirty(), &[Rect::new(0, 0, 15, 10)]);
+    fb.mark_dirty(Rect::new(0, 10, 15, 2));
+    assert_eq!(fb.dirty(), &[Rect::new(0, 0, 15, 12)]);
+}
+
+#[test]
+fn mark_dirty_keeps_apart_what_does_not_touch() {
+    let mut fb = FrameBuffer::new(64, 64);
+    fb.mark_dirty(Rect::new(0, 0, 5, 5));
+    fb.mark_dirty(Rect::new(10, 0, 5, 5));
+    assert_eq!(fb.dirty(), &[Rect::new(0, 0, 5, 5), Rect::new(10, 0, 5, 5)]);
+    // a region bridging both swallows them, and they it
+    fb.mark_dirty(Rect::new(4, 20, 7, 2));
+    assert_eq!(fb.dirty().len(), 3);
+    fb.mark_dirty(Rect::new(4, 4, 7, 17));
+    assert_eq!(fb.dirty(), &[Rect::new(0, 0, 15, 22)]);
+}
+
+#[test]
+fn mark_dirty_clips_and_collapses() {
+    let mut fb = FrameBuffer::new(40, 8);
+    fb.mark_dirty(Rect::new(38, 6, 10, 10));
+    assert_eq!(fb.dirty(), &[Rect::new(38, 6, 2, 2)]);
+    fb.mark_dirty(Rect::new(40, 0, 5, 5));
+    fb.mark_dirty(Rect::new(0, 0, 0, 5));
+    assert_eq!(fb.dirty().len(), 1);
+
+    // past 16 separate regions they become one
+    let mut fb = FrameBuffer::new(40, 8);
+    for i in 0..16 {
+        fb.mark_dirty(Rect::new(i * 2, 0, 1, 1));
+    }
+    assert_eq!(fb.dirty().len(), 16);
+    fb.mark_dirty(Rect::new(32, 0, 1, 1));
+    assert_eq!(fb.dirty(), &[Rect::new(0, 0, 33, 1)]);
+}
+
+#[test]
+fn flush_sends_only_the_dirty_regions() {
+    let mut lcd = lcd();
+    let mut fb = FrameBuffer::for_lcd(&lcd);
+    paint(&mut fb, Rect::new(10, 10, 10, 10), RED);
+    paint(&mut fb, Rect::new(100, 200, 10, 5), BLUE);
+    // changed behind the buffer's back, so not sent
+    fb.data[50 * 240 + 50] = GREEN;
+
+    let before = lcd.interface.commands.len();
+    fb.flush(&mut lcd).unwrap();
+    assert_eq!(lcd.interface.commands[before..], [WINDOW, WINDOW].concat());
+    assert!(!fb.is_dirty());
+    let sim = &lcd.interface;
+    for y in 0..320 {
+        for x in 0..240 {
+            let expected = if (10..20).contains(&x) && (10..20).contains(&y) {
+                RED
+            } else if (100..110).contains(&x) && (200..205).contains(&y) {
+                BLUE
+            } else {
+                WHITE
+            };
+            assert_eq!(sim.pixel(x, y), expected, "{}, {}", x, y);
+        }
+    }
+
+    // nothing left to send
+    let before = lcd.interface.commands.len();
+    fb.flush(&mut lcd).unwrap();
+    assert_eq!(lcd.interface.commands.len(), before);
+    // until the next change
+    fb.invalidate();
+    fb.flush(&mut lcd).unwrap();
+    assert_eq!(lcd.interface.commands[before..], WINDOW);
+    assert_eq!(lcd.interface.pixel(50, 50), GREEN);
+}
+
+#[test]
+fn flush_keeps_what_the_panel_refused() {
+    let mut lcd = lcd();
+    // taller than the screen
+    let mut fb = FrameBuffer::new(240, 400);
+    paint(&mut fb, Rect::new(0, 0, 4, 4), RED);
+    paint(&mut fb, Rect::new(0, 350, 4, 4), RED);
+    assert_eq!(fb.flush(&mut lcd), Err(LcdError::OutOfBounds));
+    assert_eq!(fb.dirty().len(), 2);
+}
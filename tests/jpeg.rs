@@ -0,0 +1,193 @@
+//! Baseline JPEGs in `tests/data` against reference decodes written by
+//! `tools/jpegref`. The decoder works in integer arithmetic, hands out
+//! RGB565 and repeats subsampled chroma where the reference interpolates
+//! it, so pixels only have to come within the tolerance in `SCALES`.
+
+use k210_lcd::image::jpeg::Scale;
+use k210_lcd::image::{ImageError, Jpeg};
+
+const WIDTH: usize = 61;
+const HEIGHT: usize = 45;
+
+/// Every scale with its divisor and tolerance: the largest error of any
+/// channel out of 255 and the mean error. Repeated chroma strays further
+/// from interpolated chroma the more pixels one sample covers.
+const SCALES: [(Scale, usize, u8, f64); 4] = [
+    (Scale::Full, 1, 10, 2.5),
+    (Scale::Half, 2, 12, 3.0),
+    (Scale::Quarter, 4, 16, 4.0),
+    (Scale::Eighth, 8, 24, 7.0),
+];
+
+/// The image at `scale` as 8 bit RGB, checking that the tiles cover every
+/// pixel once.
+fn decode(data: &[u8], scale: Scale) -> (usize, usize, Vec<u8>) {
+    let mut jpeg = Jpeg::new(data).unwrap();
+    jpeg.scale = scale;
+    let (width, height) = (jpeg.width() as usize, jpeg.height() as usize);
+    let mut pixels = vec![None; width * height];
+    jpeg.decode(|tile| {
+        for (i, &p) in tile.pixels.iter().enumerate() {
+            let (x, y) = (tile.x as usize + i % tile.width as usize, tile.y as usize + i / tile.width as usize);
+            assert!(x < width && y < height, "tile at {},{} sticks out", tile.x, tile.y);
+            assert!(pixels[y * width + x].replace(p).is_none(), "{},{} decoded twice", x, y);
+        }
+    })
+    .unwrap();
+    let rgb = pixels.iter().flat_map(|p| {
+        let (r, g, b) = p.expect("pixel left out").to_rgb888();
+        [r, g, b]
+    });
+    (width, height, rgb.collect())
+}
+
+fn check(name: &str, data: &[u8], references: [&[u8]; 4]) {
+    for ((scale, n, max_error, mean_error), reference) in SCALES.into_iter().zip(references) {
+        let (width, height, rgb) = decode(data, scale);
+        assert_eq!((width, height), (WIDTH.div_ceil(n), HEIGHT.div_ceil(n)), "{} at 1/{}", name, n);
+        let errors: Vec<u8> = rgb.iter().zip(reference).map(|(a, b)| a.abs_diff(*b)).collect();
+        let max = errors.iter().copied().max().unwrap();
+        let mean = errors.iter().map(|&e| e as f64).sum::<f64>() / errors.len() as f64;
+        assert!(max <= max_error && mean <= mean_error, "{} at 1/{}: max error {}, mean {:.2}", name, n, max, mean);
+    }
+}
+
+macro_rules! references {
+    ($name:literal) => {
+        [
+            include_bytes!(concat!("data/jpeg_", $name, "_1.rgb")),
+            include_bytes!(concat!("data/jpeg_", $name, "_2.rgb")),
+            include_bytes!(concat!("data/jpeg_", $name, "_4.rgb")),
+            include_bytes!(concat!("data/jpeg_", $name, "_8.rgb")),
+        ]
+    };
+}
+
+#[test]
+fn baseline_444() {
+    check("444", include_bytes!("data/jpeg_444.jpg"), references!("444"));
+}
+
+#[test]
+fn baseline_422() {
+    check("422", include_bytes!("data/jpeg_422.jpg"), references!("422"));
+}
+
+#[test]
+fn baseline_420() {
+    check("420", include_bytes!("data/jpeg_420.jpg"), references!("420"));
+}
+
+#[test]
+fn restart_interval() {
+    let data = include_bytes!("data/jpeg_420_restart.jpg");
+    check("420_restart", data, references!("420_restart"));
+    // same image, the markers must not change a pixel
+    for (scale, ..) in SCALES {
+        assert!(decode(data, scale) == decode(include_bytes!("data/jpeg_420.jpg"), scale));
+    }
+}
+
+#[test]
+fn progressive_is_refused() {
+    assert_eq!(Jpeg::new(include_bytes!("data/jpeg_progressive.jpg")).err(), Some(ImageError::Unsupported));
+}
+
+/// `data` with its quantization tables widened to 16 bits at their
+/// largest, the Huffman tables swapped for `huffman` if there is one, and
+/// the scan replaced by `scan`.
+fn rebuilt(data: &[u8], huffman: Option<&[u8]>, scan: &[u8]) -> Vec<u8> {
+    let mut out = data[..2].to_vec();
+    let mut segment_out = |marker: u8, body: &[u8]| {
+        out.extend([0xFF, marker]);
+        out.extend((body.len() as u16 + 2).to_be_bytes());
+        out.extend(body);
+    };
+    let mut pos = 2;
+    loop {
+        let (marker, length) = (data[pos + 1], u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize);
+        let segment = &data[pos + 4..pos + 2 + length];
+        match (marker, huffman) {
+            (0xDB, _) => {
+                let tables: Vec<u8> = segment.chunks(65).flat_map(|table| [&[0x10 | table[0]][..], &[0xFF; 128]].concat()).collect();
+                segment_out(0xDB, &tables);
+            }
+            (0xC4, Some(_)) => {}
+            (0xDA, Some(huffman)) => {
+                segment_out(0xC4, huffman);
+                segment_out(0xDA, segment);
+            }
+            _ => segment_out(marker, segment),
+        }
+        pos += 2 + length;
+        if marker == 0xDA {
+            break;
+        }
+    }
+    out.extend(scan);
+    out.extend([0xFF, 0xD9]);
+    out
+}
+
+#[test]
+fn corrupted_scan_is_an_error_not_a_panic() {
+    let data = include_bytes!("data/jpeg_420.jpg");
+    for seed in 0..200u32 {
+        // an LCG, with 0xFF left out so no byte starts a marker
+        let mut state = seed;
+        let scan: Vec<u8> = (0..1000)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                ((state >> 24) as u8).min(0xFE)
+            })
+            .collect();
+        let data = rebuilt(data, None, &scan);
+        let mut jpeg = Jpeg::new(&data).unwrap();
+        for (scale, ..) in SCALES {
+            jpeg.scale = scale;
+            let result = jpeg.decode(|_| {});
+            assert!(matches!(result, Ok(()) | Err(ImageError::Corrupt)), "seed {}: {:?}", seed, result);
+        }
+    }
+}
+
+/// The 4:2:0 file with a scan that repeats `block` for every block, coded
+/// with one Huffman code per table, "0", standing for `dc` and `ac`.
+fn single_code_scan(dc: u8, ac: u8, block: &[bool]) -> Vec<u8> {
+    let mut huffman = Vec::new();
+    for (class, symbol) in [(0x00, dc), (0x01, dc), (0x10, ac), (0x11, ac)] {
+        huffman.push(class);
+        huffman.extend([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        huffman.push(symbol);
+    }
+    let bits: Vec<bool> = (0..300).flat_map(|_| block.iter().copied()).collect();
+    let mut scan = Vec::new();
+    for byte in bits.chunks(8) {
+        let byte = byte.iter().enumerate().fold(0u8, |acc, (i, &bit)| acc | (bit as u8) << (7 - i));
+        scan.push(byte);
+        if byte == 0xFF {
+            scan.push(0);
+        }
+    }
+    rebuilt(include_bytes!("data/jpeg_420.jpg"), Some(&huffman), &scan)
+}
+
+#[test]
+fn runaway_dc_predictor_is_clamped() {
+    // an 11 bit DC difference of 2047, then end of block
+    let block: Vec<bool> = [false].into_iter().chain([true; 11]).chain([false]).collect();
+    let data = single_code_scan(11, 0x00, &block);
+    let mut jpeg = Jpeg::new(&data).unwrap();
+    for (scale, ..) in SCALES {
+        jpeg.scale = scale;
+        assert_eq!(jpeg.decode(|_| {}), Ok(()));
+    }
+}
+
+#[test]
+fn oversized_ac_coefficient_is_corrupt() {
+    // no DC difference, then a 15 bit AC coefficient
+    let block: Vec<bool> = [false, false].into_iter().chain([true; 15]).collect();
+    let data = single_code_scan(0, 0x0F, &block);
+    assert_eq!(Jpeg::new(&data).unwrap().decode(|_| {}), Err(ImageError::Corrupt));
+}
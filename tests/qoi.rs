@@ -0,0 +1,60 @@
+//! Decoding the QOI images in `tests/data`, see `gen.py` there.
+
+use k210_lcd::color::Rgb565;
+use k210_lcd::constant::*;
+use k210_lcd::image::{ImageDecoder, ImageError, Qoi};
+
+const RGB: &[u8] = include_bytes!("data/qoi_rgb.qoi");
+const RGBA: &[u8] = include_bytes!("data/qoi_rgba.qoi");
+
+fn decode(image: &mut Qoi) -> Result<Vec<Rgb565>, ImageError> {
+    let mut pixels = vec![Rgb565::default(); image.width() as usize * image.height() as usize];
+    for row in pixels.chunks_mut(image.width() as usize) {
+        image.next_row(row)?;
+    }
+    Ok(pixels)
+}
+
+#[test]
+fn rgb() {
+    let mut image = Qoi::new(RGB).unwrap();
+    assert_eq!((image.width(), image.height()), (29, 19));
+    let expected: Vec<Rgb565> = include_bytes!("data/qoi_rgb.rgb").chunks(3).map(|p| Rgb565::from_rgb888(p[0], p[1], p[2])).collect();
+    assert!(decode(&mut image).unwrap() == expected);
+}
+
+#[test]
+fn rgba_is_blended_over_the_background() {
+    let rgba = include_bytes!("data/qoi_rgba.rgba");
+    for background in [BLACK, WHITE] {
+        let mut image = Qoi::new(RGBA).unwrap();
+        image.background = background;
+        let expected: Vec<Rgb565> = rgba
+            .chunks(4)
+            .map(|p| match p[3] {
+                0 => background,
+                a => Rgb565::from_rgb888(p[0], p[1], p[2]).blend(background, a),
+            })
+            .collect();
+        assert!(decode(&mut image).unwrap() == expected, "{:?}", background);
+    }
+}
+
+#[test]
+fn bad_header() {
+    assert_eq!(Qoi::new(b"qoix\0\0\0\x01\0\0\0\x01\x03\0").err(), Some(ImageError::BadHeader));
+    // five channels
+    let mut data = RGB.to_vec();
+    data[12] = 5;
+    assert_eq!(Qoi::new(&data).err(), Some(ImageError::BadHeader));
+}
+
+#[test]
+fn truncated_input() {
+    assert_eq!(Qoi::new(&RGB[..10]).err(), Some(ImageError::Truncated));
+    for data in [RGB, RGBA] {
+        // the 8 byte end marker is never read, cut into the pixels
+        let mut image = Qoi::new(&data[..data.len() - 8 - 20]).unwrap();
+        assert_eq!(decode(&mut image).err(), Some(ImageError::Truncated));
+    }
+}
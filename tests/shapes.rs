@@ -0,0 +1,222 @@
+use k210_lcd::constant::*;
+use k210_lcd::lcd::Lcd;
+use k210_lcd::sim::Simulator;
+
+fn lcd() -> Lcd<Simulator> {
+    Lcd::with_interface(Simulator::st7789(), 240, 320, Dir::XyRlud)
+}
+
+/// Every pixel not left white.
+fn inked(lcd: &Lcd<Simulator>) -> Vec<(u16, u16)> {
+    let sim = &lcd.interface;
+    (0..sim.height()).flat_map(|y| (0..sim.width()).map(move |x| (x, y))).filter(|&(x, y)| sim.pixel(x, y) != WHITE).collect()
+}
+
+/// The pixels of `x0..=x1` x `y0..=y1`, in the order `inked` lists them.
+fn rect(x0: u16, y0: u16, x1: u16, y1: u16) -> Vec<(u16, u16)> {
+    (y0..=y1).flat_map(|y| (x0..=x1).map(move |x| (x, y))).collect()
+}
+
+/// Leftmost and rightmost inked pixel of every row.
+fn extents(lcd: &Lcd<Simulator>) -> Vec<Option<(u16, u16)>> {
+    let mut rows = vec![None; lcd.interface.height() as usize];
+    for (x, y) in inked(lcd) {
+        let row: &mut Option<(u16, u16)> = &mut rows[y as usize];
+        *row = Some(row.map_or((x, x), |(l, r)| (l.min(x), r.max(x))));
+    }
+    rows
+}
+
+#[test]
+fn fill_circle_fills_draw_circle() {
+    for r in [0, 1, 2, 7, 30, 100, 119] {
+        let (mut outline, mut filled) = (lcd(), lcd());
+        outline.draw_circle(120, 160, r, RED).unwrap();
+        filled.fill_circle(120, 160, r, RED).unwrap();
+        assert_eq!(extents(&outline), extents(&filled), "r = {}", r);
+    }
+}
+
+#[test]
+fn fill_round_rect_fills_draw_round_rect() {
+    let (mut outline, mut filled) = (lcd(), lcd());
+    outline.draw_round_rect(10, 20, 200, 90, 25, RED).unwrap();
+    filled.fill_round_rect(10, 20, 200, 90, 25, RED).unwrap();
+    assert_eq!(extents(&outline), extents(&filled));
+}
+
+#[test]
+fn fill_ellipse_fills_draw_ellipse() {
+    let (mut outline, mut filled) = (lcd(), lcd());
+    outline.draw_ellipse(120, 160, 90, 40, RED).unwrap();
+    filled.fill_ellipse(120, 160, 90, 40, RED).unwrap();
+    assert_eq!(extents(&outline), extents(&filled));
+    // the same ellipse with its middle above the screen
+    let (mut outline, mut filled) = (lcd(), lcd());
+    outline.draw_ellipse(120, -20, 90, 40, RED).unwrap();
+    filled.fill_ellipse(120, -20, 90, 40, RED).unwrap();
+    assert_eq!(extents(&outline), extents(&filled));
+}
+
+#[test]
+fn huge_fills_cover_the_screen() {
+    let mut lcd = lcd();
+    lcd.fill_circle(120, 160, i32::MAX, RED).unwrap();
+    assert_eq!(inked(&lcd).len(), 240 * 320);
+    let mut lcd = self::lcd();
+    lcd.fill_ellipse(-5000, 160, 1 << 14, 1 << 14, RED).unwrap();
+    assert_eq!(inked(&lcd).len(), 240 * 320);
+    let mut lcd = self::lcd();
+    lcd.fill_round_rect(-1 << 30, -1 << 30, i32::MAX, i32::MAX, 1 << 29, RED).unwrap();
+    assert_eq!(inked(&lcd).len(), 240 * 320);
+}
+
+#[test]
+fn draw_arc_without_sweep_is_one_point() {
+    for (angle, point) in [(0, (150, 160)), (90, (120, 190)), (180, (90, 160)), (-90, (120, 130))] {
+        let mut lcd = lcd();
+        lcd.draw_arc(120, 160, 30, angle, angle, RED).unwrap();
+        assert_eq!(inked(&lcd), [point], "{} degrees", angle);
+        let mut lcd = self::lcd();
+        lcd.draw_arc(120, 160, 30, angle, angle - 360, RED).unwrap();
+        assert_eq!(inked(&lcd), [point], "{} degrees back", angle);
+    }
+}
+
+#[test]
+fn draw_line_is_clipped_to_the_screen() {
+    let mut lcd = lcd();
+    lcd.draw_line(i32::MIN, i32::MIN, i32::MAX, i32::MAX, RED).unwrap();
+    let diagonal: Vec<(u16, u16)> = (0..240).map(|i| (i, i)).collect();
+    assert_eq!(inked(&lcd), diagonal);
+
+    // one end on screen: the same pixels as the part of it that is, which
+    // leaves through the right edge at a whole pixel
+    let (mut long, mut short) = (self::lcd(), self::lcd());
+    long.draw_line(9, 10, 9 + 2 * 100_000, 10 + 100_000, RED).unwrap();
+    short.draw_line(9, 10, 239, 125, RED).unwrap();
+    assert_eq!(inked(&long), inked(&short));
+
+    let mut lcd = self::lcd();
+    lcd.draw_line(-100, -50, 500, -1, RED).unwrap();
+    lcd.draw_line(i32::MAX, 0, i32::MAX, 319, RED).unwrap();
+    assert!(inked(&lcd).is_empty());
+}
+
+#[test]
+fn fill_triangle_fills_draw_triangle() {
+    let triangles = [
+        ((120, 10), (230, 300), (5, 200)),
+        // flat top and flat bottom
+        ((20, 40), (200, 40), (110, 250)),
+        ((110, 40), (200, 250), (20, 250)),
+    ];
+    for (p0, p1, p2) in triangles {
+        let (mut outline, mut filled) = (lcd(), lcd());
+        outline.draw_triangle(p0, p1, p2, RED).unwrap();
+        filled.fill_triangle(p0, p1, p2, RED).unwrap();
+        assert_eq!(extents(&outline), extents(&filled), "{:?}", (p0, p1, p2));
+    }
+}
+
+#[test]
+fn fill_triangle_rows_are_solid() {
+    let triangles = [
+        ((120, 10), (230, 300), (5, 200)),
+        // hanging off the left and the bottom
+        ((-80, 100), (150, 200), (60, 400)),
+    ];
+    for (p0, p1, p2) in triangles {
+        let (mut outline, mut filled) = (lcd(), lcd());
+        outline.draw_triangle(p0, p1, p2, RED).unwrap();
+        filled.fill_triangle(p0, p1, p2, RED).unwrap();
+        let inked = inked(&filled);
+        assert!(self::inked(&outline).iter().all(|p| inked.contains(p)), "{:?}", (p0, p1, p2));
+        let solid: usize = extents(&filled).iter().flatten().map(|&(l, r)| (r - l + 1) as usize).sum();
+        assert_eq!(inked.len(), solid, "{:?}", (p0, p1, p2));
+    }
+    // where the left side is off screen, the fill runs up to the edge,
+    // which it leaves at row 271
+    let mut lcd = lcd();
+    lcd.fill_triangle((-80, 100), (150, 200), (60, 400), RED).unwrap();
+    assert!(extents(&lcd)[135..=270].iter().all(|row| row.unwrap().0 == 0));
+}
+
+#[test]
+fn fill_polygon_square_is_a_rectangle() {
+    let mut lcd = lcd();
+    lcd.fill_polygon(&[(10, 20), (59, 20), (59, 69), (10, 69)], RED).unwrap();
+    assert_eq!(inked(&lcd), rect(10, 20, 59, 69));
+    let mut lcd = self::lcd();
+    lcd.fill_polygon(&[], RED).unwrap();
+    assert!(inked(&lcd).is_empty());
+}
+
+#[test]
+fn fill_polygon_leaves_even_odd_holes() {
+    // a five pointed star drawn in one stroke, its middle crossed twice
+    let star = [(120, 60), (179, 241), (25, 129), (215, 129), (61, 241)];
+    let mut lcd = lcd();
+    lcd.fill_polygon(&star, RED).unwrap();
+    assert_eq!(lcd.interface.pixel(120, 165), WHITE);
+    for (x, y) in [(120, 80), (60, 132), (180, 132), (75, 230), (165, 230)] {
+        assert_ne!(lcd.interface.pixel(x, y), WHITE, "({}, {})", x, y);
+    }
+}
+
+#[test]
+fn huge_polygons_cover_the_screen() {
+    let mut lcd = lcd();
+    lcd.fill_triangle((i32::MIN, i32::MIN), (i32::MAX, i32::MIN), (0, i32::MAX), RED).unwrap();
+    assert_eq!(inked(&lcd).len(), 240 * 320);
+    let mut lcd = self::lcd();
+    lcd.fill_polygon(&[(i32::MIN, i32::MIN), (i32::MAX, i32::MIN), (i32::MAX, i32::MAX), (i32::MIN, i32::MAX)], RED).unwrap();
+    assert_eq!(inked(&lcd).len(), 240 * 320);
+}
+
+#[test]
+fn draw_thick_line_is_a_rectangle_along_the_axes() {
+    let mut lcd = lcd();
+    lcd.draw_thick_line(20, 50, 100, 50, 5, RED).unwrap();
+    assert_eq!(inked(&lcd), rect(20, 48, 100, 52));
+    let mut lcd = self::lcd();
+    lcd.draw_thick_line(70, 200, 70, 30, 5, RED).unwrap();
+    assert_eq!(inked(&lcd), rect(68, 30, 72, 200));
+    // no length leaves a square
+    let mut lcd = self::lcd();
+    lcd.draw_thick_line(100, 100, 100, 100, 5, RED).unwrap();
+    assert_eq!(inked(&lcd), rect(98, 98, 102, 102));
+    // as wide as a line is a line
+    let (mut thick, mut thin) = (self::lcd(), self::lcd());
+    thick.draw_thick_line(3, 7, 200, 311, 1, RED).unwrap();
+    thin.draw_line(3, 7, 200, 311, RED).unwrap();
+    assert_eq!(inked(&thick), inked(&thin));
+}
+
+#[test]
+fn draw_thick_line_across_the_whole_range() {
+    let mut lcd = lcd();
+    lcd.draw_thick_line(i32::MIN, 160, i32::MAX, 160, 11, RED).unwrap();
+    assert_eq!(inked(&lcd), rect(0, 155, 239, 165));
+    let mut lcd = self::lcd();
+    lcd.draw_thick_line(i32::MIN, i32::MIN, i32::MAX, i32::MAX, 9, RED).unwrap();
+    let inked = inked(&lcd);
+    assert!(inked.contains(&(0, 0)) && inked.contains(&(239, 239)));
+    assert!(inked.iter().all(|&(x, y)| (x as i32 - y as i32).abs() <= 7));
+}
+
+#[test]
+fn huge_outlines_stay_in_range() {
+    let mut lcd = lcd();
+    lcd.draw_rectangle(i32::MAX - 5, i32::MAX - 5, i32::MAX, i32::MAX, RED).unwrap();
+    lcd.draw_hline(i32::MAX, 0, i32::MAX, RED).unwrap();
+    lcd.draw_vline(0, i32::MAX, i32::MAX, RED).unwrap();
+    // boxes that end just short of the screen, too big to walk round
+    lcd.draw_circle(i32::MAX, i32::MAX, i32::MAX - 240, RED).unwrap();
+    lcd.draw_circle(i32::MIN, 0, i32::MAX, RED).unwrap();
+    lcd.draw_ellipse(i32::MAX, 0, i32::MAX - 240, 10, RED).unwrap();
+    lcd.draw_arc(i32::MAX, 0, 1 << 30, i32::MIN, i32::MAX, RED).unwrap();
+    assert!(inked(&lcd).is_empty());
+    lcd.draw_rectangle(-10, -10, 100, i32::MAX, RED).unwrap();
+    assert_eq!(inked(&lcd), rect(89, 0, 89, 319));
+}
@@ -0,0 +1,258 @@
+//! Golden image tests: draw through a whole `Lcd` into the simulator and
+//! compare its GRAM with the PNGs in `tests/golden`. After an intended
+//! change to the output, run with `UPDATE_GOLDEN=1` to write them afresh.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+use k210_lcd::color::Rgb565;
+use k210_lcd::constant::*;
+use k210_lcd::font::{FONT_12X24, FONT_8X16};
+use k210_lcd::image::Bmp;
+use k210_lcd::lcd::{panel, Lcd, LcdError, PixelFormat};
+use k210_lcd::sim::{Simulator, View};
+
+/// The four rotations, as MADCTL sets them.
+const DIRS: [(Dir, &str); 4] = [
+    (Dir::XyRlud, "0"),
+    (Dir::YxLrud, "90"),
+    (Dir::XyLrdu, "180"),
+    (Dir::YxRldu, "270"),
+];
+
+const FORMATS: [(PixelFormat, &str); 3] = [
+    (PixelFormat::Rgb444, "rgb444"),
+    (PixelFormat::Rgb565, "rgb565"),
+    (PixelFormat::Rgb666, "rgb666"),
+];
+
+fn lcd(dir: Dir, format: PixelFormat) -> Lcd<Simulator> {
+    let (w, h) = if dir.is_swapped() { (320, 240) } else { (240, 320) };
+    let mut lcd = Lcd::with_interface(Simulator::st7789(), w, h, dir);
+    lcd.set_pixel_format(format).unwrap();
+    lcd
+}
+
+/// Raw GRAM at native panel coordinates, as 8 bit RGB.
+fn gram(sim: &Simulator) -> Vec<u8> {
+    let mut rgb = Vec::new();
+    for y in 0..sim.height() {
+        for x in 0..sim.width() {
+            let (r, g, b) = sim.pixel(x, y).to_rgb888();
+            rgb.extend_from_slice(&[r, g, b]);
+        }
+    }
+    rgb
+}
+
+fn check_golden(name: &str, sim: &Simulator) -> Result<(), String> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(format!("{}.png", name));
+    let actual = gram(sim);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        let w = BufWriter::new(File::create(&path).unwrap());
+        let mut encoder = png::Encoder::new(w, sim.width() as u32, sim.height() as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header().unwrap().write_image_data(&actual).unwrap();
+        return Ok(());
+    }
+    let file = File::open(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut reader = png::Decoder::new(file).read_info().unwrap();
+    let mut expected = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut expected).unwrap();
+    if (info.width, info.height) != (sim.width() as u32, sim.height() as u32) {
+        return Err(format!("{}: golden is {}x{}", name, info.width, info.height));
+    }
+    let wrong: Vec<usize> = (0..actual.len() / 3).filter(|&i| actual[i * 3..i * 3 + 3] != expected[i * 3..i * 3 + 3]).collect();
+    match wrong.first() {
+        None => Ok(()),
+        Some(&i) => {
+            let (x, y) = (i % sim.width() as usize, i / sim.width() as usize);
+            Err(format!("{}: {} pixels differ, first at {},{}", name, wrong.len(), x, y))
+        }
+    }
+}
+
+/// Draw `scene` in every orientation and pixel format and compare each
+/// result with its golden image.
+fn golden(scene: &str, draw: fn(&mut Lcd<Simulator>)) {
+    let mut failures = Vec::new();
+    for (dir, dir_name) in DIRS {
+        for (format, format_name) in FORMATS {
+            let mut lcd = lcd(dir, format);
+            draw(&mut lcd);
+            if let Err(e) = check_golden(&format!("{}-{}-{}", scene, dir_name, format_name), &lcd.interface) {
+                failures.push(e);
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn fill_rectangle() {
+    golden("rect", |lcd| {
+        let (w, h) = (lcd.max_x, lcd.max_y);
+        lcd.fill_rectangle(0, 0, w, h, NAVY).unwrap();
+        lcd.fill_rectangle(5, 10, 45, 30, RED).unwrap();
+        // odd pixel count, and the far corner
+        lcd.fill_rectangle(50, 10, 53, 13, GREEN).unwrap();
+        lcd.fill_rectangle(w - 20, h - 8, w, h, YELLOW).unwrap();
+        lcd.lcd_draw_point(0, h - 1, WHITE).unwrap();
+    });
+}
+
+#[test]
+fn lcd_draw_string() {
+    golden("text", |lcd| {
+        lcd.lcd_draw_string(4, 4, "Hello, K210!", &FONT_8X16, BLACK, YELLOW).unwrap();
+        lcd.lcd_draw_string(4, 24, "gram", &FONT_12X24, WHITE, BLUE).unwrap();
+        lcd.lcd_draw_string_scaled(4, 52, "x2", &FONT_8X16, RED, WHITE, 2).unwrap();
+        // cut off at the right edge
+        let x = lcd.max_x - 20;
+        lcd.lcd_draw_string(x, 90, "edge", &FONT_8X16, BLACK, CYAN).unwrap();
+    });
+}
+
+#[test]
+fn lcd_draw_picture() {
+    golden("picture", |lcd| {
+        // 13 x 7 gradient, an odd pixel count in a half used last word
+        let pixels: Vec<Rgb565> = (0..13 * 7).map(|i| Rgb565::new((i % 13 * 2) as u8, (i / 13 * 9) as u8, 31 - (i % 13 * 2) as u8)).collect();
+        let mut data = vec![0u32; pixels.len().div_ceil(2)];
+        for (i, p) in pixels.iter().enumerate() {
+            data[i / 2] |= (p.0 as u32) << if i % 2 == 0 { 16 } else { 0 };
+        }
+        lcd.lcd_draw_picture(10, 20, 13, 7, &data).unwrap();
+        let (x, y) = (lcd.max_x - 13, lcd.max_y - 7);
+        lcd.lcd_draw_picture(x, y, 13, 7, &data).unwrap();
+        lcd.lcd_draw_picture(30, 40, 4, 2, &[0xF800_07E0, 0x001F_FFFF, 0x0000_F81F, 0xFFE0_07FF]).unwrap();
+    });
+}
+
+#[test]
+fn scrolling_wraps_inside_the_band() {
+    let mut lcd = lcd(Dir::XyRlud, PixelFormat::Rgb565);
+    lcd.fill_rectangle(0, 100, 240, 101, RED).unwrap();
+    lcd.define_scroll_area(20, 280, 20).unwrap();
+    lcd.scroll_to(120).unwrap();
+    let sim = &lcd.interface;
+    assert_eq!(sim.scrolled_row(10), 10);
+    assert_eq!(sim.scrolled_row(20), 120);
+    assert_eq!(sim.scrolled_row(299), 119);
+    assert_eq!(sim.scrolled_row(310), 310);
+    let (w, _, data) = sim.image(View::Panel);
+    assert_ne!(data[280 * w as usize], data[279 * w as usize]);
+}
+
+#[test]
+fn scrolled_row_takes_any_vscrdef() {
+    use k210_lcd::lcd::LcdInterface;
+    let mut sim = Simulator::st7789();
+    sim.command(0x33);
+    sim.data8(&[0xFF, 0xF0, 0x00, 0x20, 0x00, 0x00]);
+    sim.command(0x37);
+    sim.data8(&[0xFF, 0xFF]);
+    assert_eq!(sim.scrolled_row(0), 0);
+    assert_eq!(sim.scrolled_row(319), 319);
+    sim.command(0x33);
+    sim.data8(&[0x00, 0x10, 0xFF, 0xFF, 0x00, 0x00]);
+    // start 0xFFEF rows into the band, 303 rows down and wrapped
+    assert_eq!(sim.scrolled_row(319), 303);
+}
+
+#[test]
+fn sleep_blanks_the_glass_and_refuses_drawing() {
+    let mut lcd = lcd(Dir::XyRlud, PixelFormat::Rgb565);
+    lcd.fill_rectangle(0, 0, 240, 320, ORANGE).unwrap();
+    lcd.sleep().unwrap();
+    let commands = lcd.interface.commands.len();
+    assert_eq!(lcd.fill_rectangle(0, 0, 240, 320, BLUE), Err(LcdError::Asleep));
+    assert_eq!(lcd.lcd_set_area(0, 0, 9, 9), Err(LcdError::Asleep));
+    assert_eq!(lcd.fill_color(BLUE, 100), Err(LcdError::Asleep));
+    assert_eq!(lcd.send_pixels([BLUE; 100]), Err(LcdError::Asleep));
+    assert_eq!(lcd.interface.commands.len(), commands);
+    assert_eq!(lcd.interface.image(View::Panel).2[0], BLACK);
+    lcd.wake().unwrap();
+    assert_eq!(lcd.read_power_mode().unwrap(), lcd.power());
+    assert_eq!(lcd.interface.pixel(0, 0), ORANGE);
+}
+
+#[test]
+fn reset_refuses_commands_until_init() {
+    let mut lcd = lcd(Dir::XyRlud, PixelFormat::Rgb565);
+    lcd.reset().unwrap();
+    assert!(lcd.interface.sleeping);
+    let commands = lcd.interface.commands.len();
+    assert_eq!(lcd.fill_rectangle(0, 0, 10, 10, BLUE), Err(LcdError::NotInitialized));
+    assert_eq!(lcd.sleep(), Err(LcdError::NotInitialized));
+    assert_eq!(lcd.set_orientation(Dir::YxLrud), Err(LcdError::NotInitialized));
+    assert_eq!(lcd.set_pixel_format(PixelFormat::Rgb666), Err(LcdError::NotInitialized));
+    assert_eq!(lcd.command(0x21, &[]), Err(LcdError::NotInitialized));
+    assert_eq!(lcd.interface.commands.len(), commands);
+    lcd.init().unwrap();
+    assert!(!lcd.interface.sleeping && lcd.interface.display_on);
+    lcd.fill_rectangle(0, 0, 10, 10, BLUE).unwrap();
+    assert_eq!(lcd.interface.pixel(0, 0), BLUE);
+    assert_eq!(lcd.interface.pixel(10, 10), WHITE);
+}
+
+#[test]
+fn command_sends_raw_bytes_when_awake() {
+    let mut lcd = lcd(Dir::XyRlud, PixelFormat::Rgb565);
+    lcd.command(0xBB, &[0x2B]).unwrap();
+    assert_eq!(lcd.interface.commands.last(), Some(&0xBB));
+    lcd.sleep().unwrap();
+    let commands = lcd.interface.commands.len();
+    assert_eq!(lcd.command(0xBB, &[0x20]), Err(LcdError::Asleep));
+    assert_eq!(lcd.interface.commands.len(), commands);
+    // configuration is still taken while asleep
+    lcd.set_pixel_format(PixelFormat::Rgb444).unwrap();
+    assert_eq!(lcd.interface.commands.last(), Some(&0x3A));
+}
+
+#[test]
+fn detect_reads_the_id() {
+    let lcd = Lcd::detect(Simulator::st7789(), Dir::XyRlud).unwrap();
+    assert_eq!((lcd.max_x, lcd.max_y), (240, 320));
+    assert_eq!(lcd.interface.pixel(0, 0), WHITE);
+}
+
+#[test]
+fn detect_picks_ili9341_by_rdid4() {
+    let mut sim = Simulator::st7789();
+    // Ilitek parts leave RDDID unprogrammed
+    sim.id = [0, 0, 0];
+    sim.ic = 0x9341;
+    let mut lcd = Lcd::detect(sim, Dir::XyRlud).unwrap();
+    assert_eq!(lcd.panel.name, panel::ILI9341.name);
+    assert_eq!(lcd.read_id().unwrap().ic, 0x9341);
+}
+
+#[test]
+fn draw_image_clips_at_the_right_and_bottom() {
+    let rgb = include_bytes!("data/rgb24.rgb");
+    let expected = |x: usize, y: usize| {
+        let p = &rgb[(y * 29 + x) * 3..];
+        Rgb565::from_rgb888(p[0], p[1], p[2])
+    };
+    // stored bottom up and top down, the same pixels
+    for bmp in [&include_bytes!("data/rgb24.bmp")[..], include_bytes!("data/rgb24td.bmp")] {
+        let mut lcd = lcd(Dir::XyRlud, PixelFormat::Rgb565);
+        lcd.draw_image(5, 7, &mut Bmp::new(bmp).unwrap()).unwrap();
+        lcd.draw_image(225, 310, &mut Bmp::new(bmp).unwrap()).unwrap();
+        for y in 0..19 {
+            for x in 0..29 {
+                assert_eq!(lcd.interface.pixel(5 + x, 7 + y), expected(x as usize, y as usize), "{}, {}", x, y);
+            }
+        }
+        for y in 0..10 {
+            for x in 0..15 {
+                assert_eq!(lcd.interface.pixel(225 + x, 310 + y), expected(x as usize, y as usize), "{}, {}", x, y);
+            }
+        }
+        assert_eq!(lcd.interface.pixel(224, 315), WHITE);
+        assert_eq!(lcd.interface.pixel(230, 309), WHITE);
+    }
+}
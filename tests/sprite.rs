@@ -0,0 +1,165 @@
+use k210_lcd::color::Rgb565;
+use k210_lcd::constant::*;
+use k210_lcd::framebuffer::{FrameBuffer, Rect};
+use k210_lcd::lcd::LcdError;
+use k210_lcd::sprite::Sprite;
+
+// 3 x 2, row by row:
+//   a b c
+//   d e f
+const A: Rgb565 = Rgb565(0x0001);
+const B: Rgb565 = Rgb565(0x0002);
+const C: Rgb565 = Rgb565(0x0003);
+const D: Rgb565 = Rgb565(0x0004);
+const E: Rgb565 = Rgb565(0x0005);
+const F: Rgb565 = Rgb565(0x0006);
+const PIXELS: [Rgb565; 6] = [A, B, C, D, E, F];
+
+/// `sprite` as drawn at the origin of a buffer its own size, row by row.
+fn drawn(sprite: &Sprite) -> Vec<Vec<Rgb565>> {
+    let (w, h) = sprite.size();
+    let mut fb = FrameBuffer::new(w, h);
+    fb.draw_sprite(0, 0, sprite).unwrap();
+    (0..h).map(|y| (0..w).map(|x| fb.get_pixel(x, y).unwrap()).collect()).collect()
+}
+
+#[test]
+fn quarter_turns() {
+    let sprite = Sprite::new(3, 2, &PIXELS);
+    assert_eq!(sprite.size(), (3, 2));
+    assert_eq!(drawn(&sprite), [[A, B, C], [D, E, F]]);
+    assert_eq!(sprite.rotate_cw().size(), (2, 3));
+    assert_eq!(drawn(&sprite.rotate_cw()), [[D, A], [E, B], [F, C]]);
+    assert_eq!(sprite.rotate_ccw().size(), (2, 3));
+    assert_eq!(drawn(&sprite.rotate_ccw()), [[C, F], [B, E], [A, D]]);
+    assert_eq!(drawn(&sprite.rotate_180()), [[F, E, D], [C, B, A]]);
+}
+
+#[test]
+fn turns_add_up() {
+    let sprite = Sprite::new(3, 2, &PIXELS);
+    assert_eq!(sprite.rotate_cw().rotate_cw(), sprite.rotate_180());
+    assert_eq!(sprite.rotate_ccw().rotate_ccw(), sprite.rotate_180());
+    assert_eq!(sprite.rotate_cw().rotate_ccw(), sprite);
+    assert_eq!(sprite.rotate_cw().rotate_cw().rotate_cw(), sprite.rotate_ccw());
+    assert_eq!(sprite.rotate_180().rotate_180(), sprite);
+    // turning a mirror image one way is mirroring the image turned the
+    // other way
+    assert_eq!(drawn(&sprite.flip_horizontal().rotate_cw()), drawn(&sprite.rotate_ccw().flip_horizontal()));
+}
+
+#[test]
+fn flips() {
+    let sprite = Sprite::new(3, 2, &PIXELS);
+    assert_eq!(drawn(&sprite.flip_horizontal()), [[C, B, A], [F, E, D]]);
+    assert_eq!(drawn(&sprite.flip_vertical()), [[D, E, F], [A, B, C]]);
+    assert_eq!(sprite.flip_horizontal().flip_horizontal(), sprite);
+    // flipped after a turn, along the axes of the screen
+    assert_eq!(drawn(&sprite.rotate_cw().flip_horizontal()), [[A, D], [B, E], [C, F]]);
+    assert_eq!(drawn(&sprite.rotate_cw().flip_vertical()), [[F, C], [E, B], [D, A]]);
+}
+
+#[test]
+fn key_colour_lets_the_background_through() {
+    let sprite = Sprite::new(3, 2, &PIXELS).with_key(B);
+    let mut fb = FrameBuffer::new(3, 2);
+    fb.clear(RED);
+    fb.draw_sprite(0, 0, &sprite).unwrap();
+    assert_eq!(fb.get_pixel(0, 0), Some(A));
+    assert_eq!(fb.get_pixel(1, 0), Some(RED));
+    assert_eq!(fb.get_pixel(2, 0), Some(C));
+    // the key follows the pixel through a turn
+    let mut fb = FrameBuffer::new(2, 3);
+    fb.clear(RED);
+    fb.draw_sprite(0, 0, &sprite.rotate_cw()).unwrap();
+    assert_eq!(fb.get_pixel(1, 1), Some(RED));
+    assert_eq!(fb.get_pixel(0, 1), Some(E));
+}
+
+#[test]
+fn alpha_blends_over_the_background() {
+    let alpha = [255, 0, 128, 1, 254, 255];
+    let sprite = Sprite::new(3, 2, &PIXELS).with_alpha(&alpha);
+    let mut fb = FrameBuffer::new(3, 2);
+    fb.clear(RED);
+    fb.draw_sprite(0, 0, &sprite).unwrap();
+    assert_eq!(fb.get_pixel(0, 0), Some(A));
+    assert_eq!(fb.get_pixel(1, 0), Some(RED));
+    assert_eq!(fb.get_pixel(2, 0), Some(C.blend(RED, 128)));
+    assert_eq!(fb.get_pixel(0, 1), Some(D.blend(RED, 1)));
+    assert_eq!(fb.get_pixel(1, 1), Some(E.blend(RED, 254)));
+    assert_eq!(fb.get_pixel(2, 1), Some(F));
+    // the alpha plane turns with the pixels
+    let mut fb = FrameBuffer::new(3, 2);
+    fb.clear(RED);
+    fb.draw_sprite(0, 0, &sprite.rotate_180()).unwrap();
+    assert_eq!(fb.get_pixel(1, 1), Some(RED));
+    assert_eq!(fb.get_pixel(0, 0), Some(F));
+}
+
+#[test]
+fn draw_sprite_is_clipped_and_marks_what_it_covers() {
+    let sprite = Sprite::new(3, 2, &PIXELS);
+    let mut fb = FrameBuffer::new(4, 3);
+    fb.draw_sprite(-1, -1, &sprite).unwrap();
+    assert_eq!(fb.get_pixel(0, 0), Some(E));
+    assert_eq!(fb.get_pixel(1, 0), Some(F));
+    assert_eq!(fb.get_pixel(2, 0), Some(WHITE));
+    assert_eq!(fb.get_pixel(0, 1), Some(WHITE));
+    assert_eq!(fb.dirty(), &[Rect::new(0, 0, 2, 1)]);
+
+    let mut fb = FrameBuffer::new(4, 3);
+    fb.draw_sprite(2, 1, &sprite.rotate_cw()).unwrap();
+    assert_eq!(fb.get_pixel(2, 1), Some(D));
+    assert_eq!(fb.get_pixel(3, 2), Some(B));
+    assert_eq!(fb.dirty(), &[Rect::new(2, 1, 2, 2)]);
+
+    // entirely off the buffer, however far
+    let mut fb = FrameBuffer::new(4, 3);
+    for (x, y) in [(4, 0), (0, 3), (-3, 0), (i32::MAX - 1, 0), (0, i32::MAX), (i32::MIN, i32::MIN)] {
+        fb.draw_sprite(x, y, &sprite).unwrap();
+    }
+    assert!(!fb.is_dirty());
+    assert!(fb.data.iter().all(|&p| p == WHITE));
+}
+
+#[test]
+fn sprite_must_match_its_size() {
+    let alpha = [255; 6];
+    let sprites = [
+        Sprite::new(3, 2, &PIXELS[..5]),
+        Sprite::new(2, 2, &PIXELS),
+        Sprite::new(3, 2, &PIXELS).with_alpha(&alpha[..5]),
+        Sprite::new(3, 1, &PIXELS[..3]).with_alpha(&alpha),
+    ];
+    for sprite in sprites {
+        assert_eq!(sprite.check(), Err(LcdError::BufferSizeMismatch));
+        let mut fb = FrameBuffer::new(4, 3);
+        assert_eq!(fb.draw_sprite(0, 0, &sprite), Err(LcdError::BufferSizeMismatch));
+        // even where nothing would have been drawn
+        assert_eq!(fb.draw_sprite(100, 0, &sprite), Err(LcdError::BufferSizeMismatch));
+        assert!(!fb.is_dirty());
+        let mut buf = [0u32; 6];
+        assert_eq!(sprite.blit_packed(&mut buf, 4, 0, 0), Err(LcdError::BufferSizeMismatch));
+        assert_eq!(buf, [0; 6]);
+    }
+    assert_eq!(Sprite::new(3, 2, &PIXELS).with_alpha(&alpha).check(), Ok(()));
+}
+
+#[test]
+fn blit_packed_keeps_the_left_pixel_high() {
+    let sprite = Sprite::new(3, 2, &PIXELS).with_key(E);
+    // 4 x 3 pixels, the sprite one to the right
+    let mut buf = [0xFFFF_FFFF; 6];
+    sprite.blit_packed(&mut buf, 4, 1, 0).unwrap();
+    assert_eq!(buf, [0xFFFF_0001, 0x0002_0003, 0xFFFF_0004, 0xFFFF_0006, 0xFFFF_FFFF, 0xFFFF_FFFF]);
+    // clipped at the right and bottom, a whole number of rows only
+    let mut buf = [0; 5];
+    sprite.blit_packed(&mut buf, 4, 2, 1).unwrap();
+    assert_eq!(buf, [0, 0, 0, 0x0001_0002, 0]);
+    // the alpha plane blends with what the word held
+    let alpha = [128; 6];
+    let mut buf = [0xFFFF_FFFF; 2];
+    Sprite::new(3, 2, &PIXELS).with_alpha(&alpha).blit_packed(&mut buf, 4, 0, 0).unwrap();
+    assert_eq!(buf[0], (A.blend(WHITE, 128).0 as u32) << 16 | B.blend(WHITE, 128).0 as u32);
+}
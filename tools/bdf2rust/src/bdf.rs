@@ -0,0 +1,146 @@
+//! BDF bitmap fonts.
+
+use crate::{fail, Cell};
+
+struct Glyph {
+    encoding: u32,
+    width: usize,
+    /// BBX: width, height, x offset, y offset of the bitmap above the baseline
+    bbx: (usize, usize, i32, i32),
+    rows: Vec<Vec<u8>>,
+}
+
+pub struct Bdf {
+    pub ascent: i32,
+    pub descent: i32,
+    default_width: usize,
+    default_char: Option<u32>,
+    glyphs: Vec<Glyph>,
+}
+
+fn numbers(fields: &[&str]) -> Vec<i32> {
+    fields.iter().map(|f| f.parse().unwrap_or_else(|_| fail(&format!("bad number {:?}", f)))).collect()
+}
+
+fn hex_row(line: &str) -> Vec<u8> {
+    (0..line.len() / 2)
+        .map(|i| u8::from_str_radix(&line[i * 2..i * 2 + 2], 16).unwrap_or_else(|_| fail(&format!("bad bitmap row {:?}", line))))
+        .collect()
+}
+
+impl Bdf {
+    pub fn parse(text: &str) -> Bdf {
+        let mut bdf = Bdf { ascent: 0, descent: 0, default_width: 0, default_char: None, glyphs: Vec::new() };
+        let mut bbox_height = 0;
+        let mut glyph: Option<Glyph> = None;
+        let mut in_bitmap = false;
+
+        for line in text.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let Some((&key, args)) = fields.split_first() else { continue };
+            if in_bitmap {
+                if key == "ENDCHAR" {
+                    in_bitmap = false;
+                    bdf.glyphs.extend(glyph.take());
+                } else if let Some(g) = glyph.as_mut() {
+                    g.rows.push(hex_row(key));
+                }
+                continue;
+            }
+            match key {
+                "FONTBOUNDINGBOX" => {
+                    let n = numbers(args);
+                    bdf.default_width = n[0] as usize;
+                    bbox_height = n[1];
+                }
+                "FONT_ASCENT" => bdf.ascent = numbers(args)[0],
+                "FONT_DESCENT" => bdf.descent = numbers(args)[0],
+                "DEFAULT_CHAR" => bdf.default_char = numbers(args)[0].try_into().ok(),
+                "STARTCHAR" => {
+                    glyph = Some(Glyph { encoding: u32::MAX, width: bdf.default_width, bbx: (0, 0, 0, 0), rows: Vec::new() });
+                }
+                "ENCODING" => {
+                    if let Some(g) = glyph.as_mut() {
+                        // -1 marks glyphs without a standard code
+                        g.encoding = numbers(args)[0].try_into().unwrap_or(u32::MAX);
+                    }
+                }
+                "DWIDTH" => {
+                    if let Some(g) = glyph.as_mut() {
+                        g.width = numbers(args)[0] as usize;
+                    }
+                }
+                "BBX" => {
+                    if let Some(g) = glyph.as_mut() {
+                        let n = numbers(args);
+                        g.bbx = (n[0] as usize, n[1] as usize, n[2], n[3]);
+                    }
+                }
+                "BITMAP" => in_bitmap = true,
+                _ => {}
+            }
+        }
+        if bdf.ascent + bdf.descent <= 0 {
+            // no FONT_ASCENT/FONT_DESCENT, fall back to the bounding box
+            bdf.ascent = bbox_height;
+            bdf.descent = 0;
+        }
+        bdf
+    }
+
+    pub fn height(&self) -> usize {
+        (self.ascent + self.descent) as usize
+    }
+
+    pub fn cell(&self, code: u32) -> Option<Cell> {
+        let glyph = self.glyphs.iter().find(|g| g.encoding == code)?;
+        Some(self.render(glyph))
+    }
+
+    /// Empty cell of the font's default width.
+    pub fn blank(&self) -> Cell {
+        Cell { width: self.default_width, coverage: vec![0; self.default_width * self.height()] }
+    }
+
+    /// The DEFAULT_CHAR glyph, or else a hollow box sitting on the baseline.
+    pub fn fallback(&self) -> Cell {
+        if let Some(cell) = self.default_char.and_then(|code| self.cell(code)) {
+            return cell;
+        }
+        let height = self.height();
+        let (w, h) = (height.div_ceil(2).max(3), self.ascent.max(3) as usize);
+        let mut cell = Cell { width: w, coverage: vec![0; w * height] };
+        for y in 1..h {
+            for x in 1..w - 1 {
+                if y == 1 || y == h - 1 || x == 1 || x == w - 2 {
+                    cell.coverage[y * w + x] = 255;
+                }
+            }
+        }
+        cell
+    }
+
+    fn render(&self, glyph: &Glyph) -> Cell {
+        let height = self.height();
+        let mut cell = Cell { width: glyph.width, coverage: vec![0; glyph.width * height] };
+        let (bw, bh, bx, by) = glyph.bbx;
+        // top row of the bitmap, counted down from the top of the cell
+        let top = self.ascent - (by + bh as i32);
+        for (i, row) in glyph.rows.iter().enumerate().take(bh) {
+            let y = top + i as i32;
+            if y < 0 || y >= height as i32 {
+                continue;
+            }
+            for j in 0..bw {
+                let x = bx + j as i32;
+                if x < 0 || x >= glyph.width as i32 {
+                    continue;
+                }
+                if row.get(j / 8).is_some_and(|b| b & (0x80 >> (j % 8)) != 0) {
+                    cell.coverage[y as usize * glyph.width + x as usize] = 255;
+                }
+            }
+        }
+        cell
+    }
+}
@@ -0,0 +1,365 @@
+//! Turn a BDF bitmap font, or a TrueType font rasterized on the spot, into a
+//! Rust source file for `k210_lcd::font`.
+//!
+//!     bdf2rust [OPTIONS] FONT NAME [CODES] > ../../src/font/name.rs
+//!
+//! Every glyph is rendered into a cell of the font's ascent + descent rows
+//! and its advance width in columns, rows padded to whole bytes, leftmost
+//! pixel in the most significant bits, just like `ASCII0816`. If all
+//! glyphs come out the same width the font is written as monospace,
+//! otherwise with a width and data offset per glyph.
+//!
+//! CODES defaults to `0x20-0x7E`. A single range gives a `BitmapFont`, in
+//! which codes the font has no glyph for stay blank. A comma separated list
+//! of codes, ranges and `@FILE` items, the latter meaning every character
+//! in a UTF-8 text file, gives a `UnicodeFont` of just the glyphs the font
+//! has, plus a fallback glyph. BDF fonts have to be Unicode encoded
+//! (ISO10646-1), e.g. GNU Unifont or the WenQuanYi bitmap fonts for CJK;
+//! GB2312 encoded fonts need converting first.
+//!
+//!     bdf2rust wenquanyi_12pt.bdf cjk_16 0x20-0x7E,@ui-strings.txt
+//!
+//! `.ttf` files are rasterized without hinting at `--size` pixels per line.
+//! `--width` makes the font monospace with cells that wide, and `--bpp 2`
+//! or `--bpp 4` keeps the coverage of edge pixels for anti-aliased text
+//! instead of cutting it to one bit.
+//!
+//!     bdf2rust --size 24 --bpp 4 DejaVuSans.ttf sans_24_aa
+//!
+//! `--license FILE` names the file holding the font's license in the
+//! header of the output, for fonts whose license asks to travel with them.
+
+mod bdf;
+mod ttf;
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use bdf::Bdf;
+use ttf::Ttf;
+
+pub fn fail(msg: &str) -> ! {
+    eprintln!("bdf2rust: {}", msg);
+    process::exit(1);
+}
+
+/// One glyph before packing: `width` x font height coverage values, 0 for
+/// background to 255 for fully inked.
+pub struct Cell {
+    pub width: usize,
+    pub coverage: Vec<u8>,
+}
+
+/// Where the glyphs come from.
+enum Source<'a> {
+    Bdf(Bdf),
+    Ttf { font: Ttf<'a>, height: usize, width: Option<usize>, scale: f32, baseline: i32 },
+}
+
+impl<'a> Source<'a> {
+    fn ttf(font: Ttf<'a>, height: usize, width: Option<usize>) -> Self {
+        let (ascender, descender) = (font.ascender as f32, font.descender as f32);
+        let scale = match width {
+            // monospace fonts share one advance, 'M' is as good as any
+            Some(width) => width as f32 / font.advance(font.glyph_index('M').unwrap_or(0)) as f32,
+            None => height as f32 / (ascender - descender),
+        };
+        // centre the font's line in the cell
+        let line = (ascender - descender) * scale;
+        let baseline = ((height as f32 - line) / 2.0 + ascender * scale).round() as i32;
+        Source::Ttf { font, height, width, scale, baseline }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            Source::Bdf(bdf) => bdf.height(),
+            Source::Ttf { height, .. } => *height,
+        }
+    }
+
+    fn baseline(&self) -> i32 {
+        match self {
+            Source::Bdf(bdf) => bdf.ascent,
+            Source::Ttf { baseline, .. } => *baseline,
+        }
+    }
+
+    fn cell(&self, code: u32) -> Option<Cell> {
+        match self {
+            Source::Bdf(bdf) => bdf.cell(code),
+            Source::Ttf { font, .. } => Some(self.rasterize(font.glyph_index(char::from_u32(code)?)?)),
+        }
+    }
+
+    fn blank(&self) -> Cell {
+        match self {
+            Source::Bdf(bdf) => bdf.blank(),
+            Source::Ttf { font, .. } => {
+                let mut cell = self.rasterize(font.glyph_index(' ').unwrap_or(0));
+                cell.coverage.fill(0);
+                cell
+            }
+        }
+    }
+
+    fn fallback(&self) -> Cell {
+        match self {
+            Source::Bdf(bdf) => bdf.fallback(),
+            // glyph 0 is the font's own missing glyph
+            Source::Ttf { .. } => self.rasterize(0),
+        }
+    }
+
+    fn rasterize(&self, glyph: u16) -> Cell {
+        let Source::Ttf { font, height, width, scale, baseline } = self else { unreachable!() };
+        let advance = width.unwrap_or_else(|| (font.advance(glyph) as f32 * scale).round() as usize);
+        let outline: Vec<Vec<ttf::Point>> = font
+            .outline(glyph, 0.2 / scale)
+            .into_iter()
+            .map(|contour| contour.into_iter().map(|p| ttf::Point { x: p.x * scale, y: *baseline as f32 - p.y * scale }).collect())
+            .collect();
+        let coverage = ttf::rasterize(&outline, advance, *height);
+        Cell { width: advance, coverage: coverage.iter().map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8).collect() }
+    }
+}
+
+fn parse_code(s: &str) -> u32 {
+    let parsed = match s.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => s.parse(),
+    };
+    parsed.unwrap_or_else(|_| fail(&format!("bad character code {:?}", s)))
+}
+
+/// Which characters to take from the font.
+enum Selection {
+    /// One run of codes, missing glyphs left blank.
+    Range(u32, u32),
+    /// Any set of codes, missing glyphs left out.
+    Sparse(BTreeSet<u32>),
+}
+
+fn parse_selection(spec: &str) -> Selection {
+    let mut codes = BTreeSet::new();
+    let items: Vec<&str> = spec.split(',').collect();
+    for item in &items {
+        if let Some(file) = item.strip_prefix('@') {
+            let text = fs::read_to_string(file).unwrap_or_else(|e| fail(&format!("{}: {}", file, e)));
+            codes.extend(text.chars().filter(|c| !c.is_control()).map(|c| c as u32));
+            continue;
+        }
+        let (first, last) = match item.split_once('-') {
+            Some((a, b)) => (parse_code(a), parse_code(b)),
+            None => (parse_code(item), parse_code(item)),
+        };
+        if last < first {
+            fail(&format!("range {} runs backwards", item));
+        }
+        if items.len() == 1 {
+            return Selection::Range(first, last);
+        }
+        codes.extend(first..=last);
+    }
+    Selection::Sparse(codes)
+}
+
+/// Glyph tables as both font types store them.
+struct Tables {
+    bpp: usize,
+    widths: Vec<u8>,
+    offsets: Vec<usize>,
+    data: Vec<u8>,
+}
+
+impl Tables {
+    fn new(bpp: usize) -> Self {
+        Tables { bpp, widths: Vec::new(), offsets: Vec::new(), data: Vec::new() }
+    }
+
+    fn push(&mut self, code: u32, cell: &Cell, height: usize) {
+        if cell.width > 255 {
+            fail(&format!("glyph {} is wider than 255 pixels", code));
+        }
+        self.offsets.push(self.data.len());
+        self.widths.push(cell.width as u8);
+
+        let max = (1u32 << self.bpp) - 1;
+        let per_byte = 8 / self.bpp;
+        for y in 0..height {
+            let mut bytes = vec![0u8; cell.width.div_ceil(per_byte)];
+            for x in 0..cell.width {
+                let c = cell.coverage[y * cell.width + x] as u32;
+                let level = if self.bpp == 1 {
+                    // a bit under half keeps thin rasterized stems from
+                    // dropping out
+                    (c >= 102) as u32
+                } else {
+                    (c * max + 127) / 255
+                };
+                let shift = 8 - self.bpp * (x % per_byte + 1);
+                bytes[x / per_byte] |= (level << shift) as u8;
+            }
+            self.data.extend(bytes);
+        }
+    }
+
+    fn monospace(&self) -> bool {
+        self.widths.iter().all(|&w| w == self.widths[0])
+    }
+
+    /// The `bpp`, `widths`, `offsets` and `data` fields of the font.
+    fn write_fields(&self, out: &mut String, name: &str) {
+        writeln!(out, "    bpp: {},", self.bpp).unwrap();
+        if self.monospace() {
+            writeln!(out, "    widths: &[{}],", self.widths[0]).unwrap();
+            writeln!(out, "    offsets: &[],").unwrap();
+        } else {
+            writeln!(out, "    widths: &{}_WIDTHS,", name).unwrap();
+            writeln!(out, "    offsets: &{}_OFFSETS,", name).unwrap();
+        }
+        writeln!(out, "    data: &{}_DATA,", name).unwrap();
+    }
+
+    fn write_tables(&self, out: &mut String, name: &str, offset_type: &str) {
+        if !self.monospace() {
+            writeln!(out).unwrap();
+            write_table(out, &format!("{}_WIDTHS", name), "u8", &self.widths, |w| format!("{},", w));
+            writeln!(out).unwrap();
+            write_table(out, &format!("{}_OFFSETS", name), offset_type, &self.offsets, |o| format!("{},", o));
+        }
+        writeln!(out).unwrap();
+        write_table(out, &format!("{}_DATA", name), "u8", &self.data, |b| format!("0x{:02X},", b));
+    }
+}
+
+fn main() {
+    let mut bpp = 1;
+    let mut size = None;
+    let mut width = None;
+    let mut license = None;
+    let mut positional = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| -> usize {
+            let v = args.next().unwrap_or_else(|| fail(&format!("{} needs a value", flag)));
+            v.parse().unwrap_or_else(|_| fail(&format!("bad {} {:?}", flag, v)))
+        };
+        match arg.as_str() {
+            "--bpp" => bpp = value("--bpp"),
+            "--size" => size = Some(value("--size")),
+            "--width" => width = Some(value("--width")),
+            "--license" => license = Some(args.next().unwrap_or_else(|| fail("--license needs a value"))),
+            _ => positional.push(arg),
+        }
+    }
+    if positional.len() < 2 {
+        fail("usage: bdf2rust [--size PIXELS] [--width PIXELS] [--bpp 1|2|4] [--license FILE] FONT NAME [CODES]");
+    }
+    if ![1, 2, 4].contains(&bpp) {
+        fail("--bpp must be 1, 2 or 4");
+    }
+    let path = Path::new(&positional[0]);
+    let name = positional[1].to_uppercase();
+    let selection = match positional.get(2) {
+        Some(spec) => parse_selection(spec),
+        None => Selection::Range(0x20, 0x7E),
+    };
+
+    let bytes = fs::read(path).unwrap_or_else(|e| fail(&format!("{}: {}", path.display(), e)));
+    let is_ttf = path.extension().is_some_and(|e| e.eq_ignore_ascii_case("ttf"));
+    let source = if is_ttf {
+        let font = Ttf::parse(&bytes).unwrap_or_else(|e| fail(&format!("{}: {}", path.display(), e)));
+        let height = size.unwrap_or_else(|| fail("TrueType fonts need --size"));
+        Source::ttf(font, height, width)
+    } else {
+        if bpp != 1 {
+            fail("BDF fonts only have one bit per pixel");
+        }
+        Source::Bdf(Bdf::parse(&String::from_utf8_lossy(&bytes)))
+    };
+    let height = source.height();
+    if height == 0 || height > 255 || source.baseline() < 0 {
+        fail("font height out of range");
+    }
+
+    let mut out = String::new();
+    let file = path.file_name().unwrap_or_default().to_string_lossy();
+    writeln!(out, "// Generated by tools/bdf2rust from {}, do not edit.", file).unwrap();
+    if let Some(license) = &license {
+        writeln!(out, "// The font's license is in {}.", license).unwrap();
+    }
+    writeln!(out).unwrap();
+    let mut tables = Tables::new(bpp);
+    match selection {
+        Selection::Range(first, last) => {
+            for code in first..=last {
+                let cell = source.cell(code).unwrap_or_else(|| source.blank());
+                tables.push(code, &cell, height);
+            }
+            if !tables.monospace() && tables.data.len() > u16::MAX as usize {
+                fail("too much bitmap data for 16 bit offsets, pick several ranges for a UnicodeFont");
+            }
+            writeln!(out, "use super::BitmapFont;").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "pub const {}: BitmapFont = BitmapFont {{", name).unwrap();
+            writeln!(out, "    height: {},", height).unwrap();
+            writeln!(out, "    baseline: {},", source.baseline()).unwrap();
+            writeln!(out, "    first: {},", first).unwrap();
+            writeln!(out, "    count: {},", tables.widths.len()).unwrap();
+            tables.write_fields(&mut out, &name);
+            writeln!(out, "}};").unwrap();
+            tables.write_tables(&mut out, &name, "u16");
+        }
+        Selection::Sparse(codes) => {
+            // runs of consecutive codes the font has glyphs for
+            let mut ranges: Vec<(u32, usize, usize)> = Vec::new();
+            for code in codes {
+                let Some(cell) = source.cell(code) else { continue };
+                match ranges.last_mut() {
+                    Some((first, count, _)) if *first + *count as u32 == code => *count += 1,
+                    _ => ranges.push((code, 1, tables.widths.len())),
+                }
+                tables.push(code, &cell, height);
+            }
+            if tables.widths.is_empty() {
+                fail("the font has none of the requested characters");
+            }
+            let fallback_index = tables.widths.len();
+            tables.push(u32::MAX, &source.fallback(), height);
+            if tables.widths.len() > u16::MAX as usize {
+                fail("more glyphs than 16 bit indices can address");
+            }
+            writeln!(out, "use super::{{GlyphRange, UnicodeFont}};").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "pub const {}: UnicodeFont = UnicodeFont {{", name).unwrap();
+            writeln!(out, "    height: {},", height).unwrap();
+            writeln!(out, "    baseline: {},", source.baseline()).unwrap();
+            writeln!(out, "    ranges: &{}_RANGES,", name).unwrap();
+            tables.write_fields(&mut out, &name);
+            writeln!(out, "    fallback: Some({}),", fallback_index).unwrap();
+            writeln!(out, "}};").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "pub const {}_RANGES: [GlyphRange; {}] = [", name, ranges.len()).unwrap();
+            for (first, count, start) in &ranges {
+                writeln!(out, "    GlyphRange {{ first: 0x{:04X}, count: {}, start: {} }},", first, count, start).unwrap();
+            }
+            writeln!(out, "];").unwrap();
+            tables.write_tables(&mut out, &name, "u32");
+        }
+    }
+    print!("{}", out);
+}
+
+/// `pub const NAME: [ty; N] = [...]`, twelve items to a line.
+fn write_table<T>(out: &mut String, name: &str, ty: &str, items: &[T], item: impl Fn(&T) -> String) {
+    writeln!(out, "pub const {}: [{}; {}] = [", name, ty, items.len()).unwrap();
+    for line in items.chunks(12) {
+        let line: Vec<String> = line.iter().map(&item).collect();
+        writeln!(out, "    {}", line.join(" ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
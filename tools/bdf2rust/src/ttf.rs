@@ -0,0 +1,377 @@
+//! Just enough TrueType to rasterize glyph outlines: cmap format 4 and 12,
+//! simple and composite glyf outlines, horizontal metrics. No hinting.
+
+pub struct Ttf<'a> {
+    data: &'a [u8],
+    long_loca: bool,
+    num_glyphs: u16,
+    loca: usize,
+    glyf: usize,
+    hmtx: usize,
+    num_hmetrics: u16,
+    cmap: usize,
+    pub ascender: i16,
+    pub descender: i16,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Closed polylines in font units, y up.
+pub type Outline = Vec<Vec<Point>>;
+
+fn u16_at(d: &[u8], o: usize) -> u16 {
+    u16::from_be_bytes([d[o], d[o + 1]])
+}
+
+fn i16_at(d: &[u8], o: usize) -> i16 {
+    u16_at(d, o) as i16
+}
+
+fn u32_at(d: &[u8], o: usize) -> u32 {
+    u32::from_be_bytes([d[o], d[o + 1], d[o + 2], d[o + 3]])
+}
+
+impl<'a> Ttf<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self, String> {
+        if data.len() < 12 {
+            return Err("file too short".into());
+        }
+        let num_tables = u16_at(data, 4) as usize;
+        let table = |tag: &[u8; 4]| -> Result<usize, String> {
+            (0..num_tables)
+                .map(|i| 12 + i * 16)
+                .find(|&r| &data[r..r + 4] == tag)
+                .map(|r| u32_at(data, r + 8) as usize)
+                .ok_or_else(|| format!("no {} table", String::from_utf8_lossy(tag)))
+        };
+        let head = table(b"head")?;
+        let maxp = table(b"maxp")?;
+        let hhea = table(b"hhea")?;
+        let cmap_table = table(b"cmap")?;
+
+        // prefer a full unicode subtable, then the BMP one
+        let count = u16_at(data, cmap_table + 2) as usize;
+        let mut cmap = None;
+        for want in [(3, 10), (0, 4), (3, 1), (0, 3)] {
+            for i in 0..count {
+                let r = cmap_table + 4 + i * 8;
+                if (u16_at(data, r), u16_at(data, r + 2)) == want {
+                    cmap = Some(cmap_table + u32_at(data, r + 4) as usize);
+                    break;
+                }
+            }
+            if cmap.is_some() {
+                break;
+            }
+        }
+        let cmap = cmap.ok_or("no unicode cmap")?;
+        let format = u16_at(data, cmap);
+        if format != 4 && format != 12 {
+            return Err(format!("cmap format {} not supported", format));
+        }
+
+        Ok(Ttf {
+            data,
+            long_loca: i16_at(data, head + 50) != 0,
+            num_glyphs: u16_at(data, maxp + 4),
+            loca: table(b"loca")?,
+            glyf: table(b"glyf")?,
+            hmtx: table(b"hmtx")?,
+            num_hmetrics: u16_at(data, hhea + 34),
+            cmap,
+            ascender: i16_at(data, hhea + 4),
+            descender: i16_at(data, hhea + 6),
+        })
+    }
+
+    pub fn glyph_index(&self, c: char) -> Option<u16> {
+        let d = self.data;
+        let c = c as u32;
+        let index = if u16_at(d, self.cmap) == 4 {
+            if c > 0xFFFF {
+                return None;
+            }
+            let c = c as u16;
+            let segs = u16_at(d, self.cmap + 6) as usize / 2;
+            let ends = self.cmap + 14;
+            let starts = ends + segs * 2 + 2;
+            let deltas = starts + segs * 2;
+            let ranges = deltas + segs * 2;
+            let seg = (0..segs).find(|&i| u16_at(d, ends + i * 2) >= c)?;
+            let start = u16_at(d, starts + seg * 2);
+            if start > c {
+                return None;
+            }
+            let delta = u16_at(d, deltas + seg * 2);
+            let range = u16_at(d, ranges + seg * 2) as usize;
+            if range == 0 {
+                c.wrapping_add(delta)
+            } else {
+                let at = ranges + seg * 2 + range + (c - start) as usize * 2;
+                let g = u16_at(d, at);
+                if g == 0 { 0 } else { g.wrapping_add(delta) }
+            }
+        } else {
+            let groups = u32_at(d, self.cmap + 12) as usize;
+            let mut found = 0;
+            for i in 0..groups {
+                let g = self.cmap + 16 + i * 12;
+                let (start, end) = (u32_at(d, g), u32_at(d, g + 4));
+                if start <= c && c <= end {
+                    found = (u32_at(d, g + 8) + c - start) as u16;
+                    break;
+                }
+            }
+            found
+        };
+        if index == 0 || index >= self.num_glyphs { None } else { Some(index) }
+    }
+
+    pub fn advance(&self, glyph: u16) -> u16 {
+        let i = glyph.min(self.num_hmetrics - 1) as usize;
+        u16_at(self.data, self.hmtx + i * 4)
+    }
+
+    fn glyph_range(&self, glyph: u16) -> (usize, usize) {
+        let d = self.data;
+        let g = glyph as usize;
+        let (a, b) = if self.long_loca {
+            (u32_at(d, self.loca + g * 4) as usize, u32_at(d, self.loca + g * 4 + 4) as usize)
+        } else {
+            (u16_at(d, self.loca + g * 2) as usize * 2, u16_at(d, self.loca + g * 2 + 2) as usize * 2)
+        };
+        (self.glyf + a, self.glyf + b)
+    }
+
+    /// Outline flattened into polylines, `tolerance` in font units.
+    pub fn outline(&self, glyph: u16, tolerance: f32) -> Outline {
+        let mut out = Vec::new();
+        self.add_outline(glyph, [1.0, 0.0, 0.0, 1.0, 0.0, 0.0], tolerance, &mut out, 0);
+        out
+    }
+
+    fn add_outline(&self, glyph: u16, m: [f32; 6], tol: f32, out: &mut Outline, depth: u32) {
+        let d = self.data;
+        let (start, end) = self.glyph_range(glyph);
+        if start == end || depth > 8 {
+            return;
+        }
+        let contours = i16_at(d, start);
+        if contours < 0 {
+            self.add_composite(start + 10, m, tol, out, depth);
+            return;
+        }
+        let contours = contours as usize;
+        let mut ends = Vec::with_capacity(contours);
+        for i in 0..contours {
+            ends.push(u16_at(d, start + 10 + i * 2) as usize);
+        }
+        let points = ends.last().map_or(0, |&e| e + 1);
+        let mut o = start + 10 + contours * 2;
+        o += 2 + u16_at(d, o) as usize;
+
+        let mut flags = Vec::with_capacity(points);
+        while flags.len() < points {
+            let f = d[o];
+            o += 1;
+            flags.push(f);
+            if f & 8 != 0 {
+                let n = d[o];
+                o += 1;
+                for _ in 0..n {
+                    flags.push(f);
+                }
+            }
+        }
+        let coords = |short: u8, same: u8, o: &mut usize| -> Vec<i32> {
+            let mut v = 0i32;
+            let mut vals = Vec::with_capacity(points);
+            for &f in &flags {
+                if f & short != 0 {
+                    let dv = d[*o] as i32;
+                    *o += 1;
+                    v += if f & same != 0 { dv } else { -dv };
+                } else if f & same == 0 {
+                    v += i16_at(d, *o) as i32;
+                    *o += 2;
+                }
+                vals.push(v);
+            }
+            vals
+        };
+        let xs = coords(0x02, 0x10, &mut o);
+        let ys = coords(0x04, 0x20, &mut o);
+
+        let map = |x: f32, y: f32| Point { x: m[0] * x + m[2] * y + m[4], y: m[1] * x + m[3] * y + m[5] };
+        let mut first = 0;
+        for &last in &ends {
+            let pts: Vec<(Point, bool)> = (first..=last)
+                .map(|i| (map(xs[i] as f32, ys[i] as f32), flags[i] & 1 != 0))
+                .collect();
+            first = last + 1;
+            if pts.len() < 2 {
+                continue;
+            }
+            out.push(flatten(&pts, tol));
+        }
+    }
+
+    fn add_composite(&self, mut o: usize, m: [f32; 6], tol: f32, out: &mut Outline, depth: u32) {
+        let d = self.data;
+        loop {
+            let flags = u16_at(d, o);
+            let glyph = u16_at(d, o + 2);
+            o += 4;
+            let (dx, dy) = if flags & 1 != 0 {
+                let v = (i16_at(d, o) as f32, i16_at(d, o + 2) as f32);
+                o += 4;
+                v
+            } else {
+                let v = (d[o] as i8 as f32, d[o + 1] as i8 as f32);
+                o += 2;
+                v
+            };
+            let f2dot14 = |o: usize| i16_at(d, o) as f32 / 16384.0;
+            let (mut a, mut b, mut c, mut e) = (1.0, 0.0, 0.0, 1.0);
+            if flags & 0x08 != 0 {
+                a = f2dot14(o);
+                e = a;
+                o += 2;
+            } else if flags & 0x40 != 0 {
+                a = f2dot14(o);
+                e = f2dot14(o + 2);
+                o += 4;
+            } else if flags & 0x80 != 0 {
+                a = f2dot14(o);
+                b = f2dot14(o + 2);
+                c = f2dot14(o + 4);
+                e = f2dot14(o + 6);
+                o += 8;
+            }
+            // point matching offsets (ARGS_ARE_XY_VALUES clear) are not supported
+            let (dx, dy) = if flags & 2 != 0 { (dx, dy) } else { (0.0, 0.0) };
+            let inner = [a, b, c, e, dx, dy];
+            let composed = [
+                m[0] * inner[0] + m[2] * inner[1],
+                m[1] * inner[0] + m[3] * inner[1],
+                m[0] * inner[2] + m[2] * inner[3],
+                m[1] * inner[2] + m[3] * inner[3],
+                m[0] * inner[4] + m[2] * inner[5] + m[4],
+                m[1] * inner[4] + m[3] * inner[5] + m[5],
+            ];
+            self.add_outline(glyph, composed, tol, out, depth + 1);
+            if flags & 0x20 == 0 {
+                break;
+            }
+        }
+    }
+}
+
+/// Turn one contour of on/off curve points into a closed polyline.
+fn flatten(pts: &[(Point, bool)], tol: f32) -> Vec<Point> {
+    let n = pts.len();
+    let mid = |a: Point, b: Point| Point { x: (a.x + b.x) / 2.0, y: (a.y + b.y) / 2.0 };
+    // start on an on-curve point, or the implied one between two off points
+    let start_i = pts.iter().position(|p| p.1);
+    let (start, offset) = match start_i {
+        Some(i) => (pts[i].0, i),
+        None => (mid(pts[0].0, pts[1].0), 0),
+    };
+    let mut poly = vec![start];
+    let mut control: Option<Point> = None;
+    let mut cur = start;
+    for k in 1..=n {
+        let (p, on) = pts[(offset + k) % n];
+        if on {
+            match control.take() {
+                Some(c) => quad(&mut poly, cur, c, p, tol),
+                None => poly.push(p),
+            }
+            cur = p;
+        } else {
+            if let Some(c) = control {
+                let m = mid(c, p);
+                quad(&mut poly, cur, c, m, tol);
+                cur = m;
+            }
+            control = Some(p);
+        }
+    }
+    if let Some(c) = control {
+        quad(&mut poly, cur, c, start, tol);
+    }
+    poly
+}
+
+fn quad(poly: &mut Vec<Point>, a: Point, c: Point, b: Point, tol: f32) {
+    let dx = a.x - 2.0 * c.x + b.x;
+    let dy = a.y - 2.0 * c.y + b.y;
+    let dev = (dx * dx + dy * dy).sqrt();
+    let steps = ((dev / (4.0 * tol)).sqrt().ceil() as usize).clamp(1, 64);
+    for i in 1..=steps {
+        let t = i as f32 / steps as f32;
+        let u = 1.0 - t;
+        poly.push(Point {
+            x: u * u * a.x + 2.0 * u * t * c.x + t * t * b.x,
+            y: u * u * a.y + 2.0 * u * t * c.y + t * t * b.y,
+        });
+    }
+}
+
+/// Coverage 0.0..=1.0 for a `width` x `height` pixel grid. The outline is
+/// already in pixel units with y down.
+pub fn rasterize(outline: &[Vec<Point>], width: usize, height: usize) -> Vec<f32> {
+    const SUB: usize = 16;
+    let mut cov = vec![0.0f32; width * height];
+    let mut xs: Vec<(f32, i32)> = Vec::new();
+    for row in 0..height {
+        for s in 0..SUB {
+            let y = row as f32 + (s as f32 + 0.5) / SUB as f32;
+            xs.clear();
+            for contour in outline {
+                for i in 0..contour.len() {
+                    let a = contour[i];
+                    let b = contour[(i + 1) % contour.len()];
+                    if (a.y <= y) == (b.y <= y) {
+                        continue;
+                    }
+                    let x = a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y);
+                    xs.push((x, if b.y > a.y { 1 } else { -1 }));
+                }
+            }
+            xs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            let mut winding = 0;
+            for i in 0..xs.len() {
+                winding += xs[i].1;
+                if winding != 0 && i + 1 < xs.len() {
+                    add_span(&mut cov[row * width..(row + 1) * width], xs[i].0, xs[i + 1].0, 1.0 / SUB as f32);
+                }
+            }
+        }
+    }
+    cov
+}
+
+fn add_span(row: &mut [f32], x0: f32, x1: f32, weight: f32) {
+    let width = row.len() as f32;
+    let (x0, x1) = (x0.clamp(0.0, width), x1.clamp(0.0, width));
+    if x1 <= x0 {
+        return;
+    }
+    let (p0, p1) = (x0.floor() as usize, x1.floor() as usize);
+    if p0 == p1 {
+        row[p0.min(row.len() - 1)] += (x1 - x0) * weight;
+        return;
+    }
+    row[p0] += (p0 as f32 + 1.0 - x0) * weight;
+    for cov in &mut row[p0 + 1..p1] {
+        *cov += weight;
+    }
+    if p1 < row.len() {
+        row[p1] += (x1 - p1 as f32) * weight;
+    }
+}
@@ -0,0 +1,101 @@
+//! Write the JPEG test images in `tests/data`, with what an independent
+//! decoder makes of each at every scale `k210_lcd::image::Jpeg` offers.
+//!
+//!     cargo run -- ../../tests/data
+//!
+//! For every layout NAME there is `jpeg_NAME.jpg` and `jpeg_NAME_N.rgb`,
+//! the image decoded at 1/N scale as 8 bit RGB, top row first. The
+//! progressive file has no references, the decoder has to refuse it.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use jpeg_encoder::{ColorType, Encoder, SamplingFactor};
+
+// not a multiple of any MCU size, so edge blocks are cut off
+const WIDTH: u16 = 61;
+const HEIGHT: u16 = 45;
+
+const QUALITY: u8 = 90;
+
+/// Smooth colour gradients with hard edges in brightness only, a disc and
+/// fine stripes. Decoders may upsample subsampled chroma differently, but
+/// with smooth chroma that only moves a pixel by a few steps.
+fn source() -> Vec<u8> {
+    let (w, h) = (WIDTH as i32, HEIGHT as i32);
+    let mut rgb = Vec::with_capacity((w * h * 3) as usize);
+    for y in 0..h {
+        for x in 0..w {
+            // 70..=185, room for the detail on either side
+            let (r, g) = (70 + x * 115 / (w - 1), 70 + y * 115 / (h - 1));
+            let b = 255 - r;
+            // the same for all channels, so only luma changes
+            let detail = if (x - 20) * (x - 20) + (y - 22) * (y - 22) < 144 {
+                60
+            } else if x >= 40 && (8..36).contains(&y) {
+                if x % 2 == 0 { 50 } else { -50 }
+            } else {
+                0
+            };
+            rgb.extend_from_slice(&[(r + detail) as u8, (g + detail) as u8, (b + detail) as u8]);
+        }
+    }
+    rgb
+}
+
+fn encode(rgb: &[u8], sampling: SamplingFactor, restart: u16, progressive: bool) -> Vec<u8> {
+    let mut jpeg = Vec::new();
+    let mut encoder = Encoder::new(&mut jpeg, QUALITY);
+    encoder.set_sampling_factor(sampling);
+    if restart > 0 {
+        encoder.set_restart_interval(restart);
+    }
+    encoder.set_progressive(progressive);
+    encoder.encode(rgb, WIDTH, HEIGHT, ColorType::Rgb).unwrap_or_else(|e| fail(&e.to_string()));
+    jpeg
+}
+
+/// The image decoded at 1/`n` scale.
+fn reference(jpeg: &[u8], n: u16) -> Vec<u8> {
+    let mut decoder = jpeg_decoder::Decoder::new(jpeg);
+    decoder.read_info().unwrap_or_else(|e| fail(&e.to_string()));
+    decoder.scale(WIDTH.div_ceil(n), HEIGHT.div_ceil(n)).unwrap_or_else(|e| fail(&e.to_string()));
+    let rgb = decoder.decode().unwrap_or_else(|e| fail(&e.to_string()));
+    let info = decoder.info().unwrap();
+    if (info.width, info.height) != (WIDTH.div_ceil(n), HEIGHT.div_ceil(n)) {
+        fail(&format!("1/{} scale came out {}x{}", n, info.width, info.height));
+    }
+    rgb
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("jpegref: {}", msg);
+    process::exit(1);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() != 1 {
+        fail("usage: jpegref DIR");
+    }
+    let dir = Path::new(&args[0]);
+    let write = |name: &str, data: &[u8]| fs::write(dir.join(name), data).unwrap_or_else(|e| fail(&format!("{}: {}", name, e)));
+
+    let rgb = source();
+    let layouts = [
+        ("444", SamplingFactor::R_4_4_4, 0),
+        ("422", SamplingFactor::R_4_2_2, 0),
+        ("420", SamplingFactor::R_4_2_0, 0),
+        ("420_restart", SamplingFactor::R_4_2_0, 3),
+    ];
+    for (name, sampling, restart) in layouts {
+        let jpeg = encode(&rgb, sampling, restart, false);
+        write(&format!("jpeg_{}.jpg", name), &jpeg);
+        for n in [1, 2, 4, 8] {
+            write(&format!("jpeg_{}_{}.rgb", name, n), &reference(&jpeg, n));
+        }
+    }
+    write("jpeg_progressive.jpg", &encode(&rgb, SamplingFactor::R_4_2_0, 0, true));
+}